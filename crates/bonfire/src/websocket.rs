@@ -8,7 +8,7 @@ use revolt_quark::{
         server::ClientMessage,
         state::{State, SubscriptionStateChange},
     },
-    models::{user::UserHint, User},
+    models::{user::UserHint, Member, User},
     redis_kiss, Database,
 };
 
@@ -165,6 +165,17 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                                                         {
                                                             break;
                                                         }
+
+                                                        // Drop the connection once the account is locked out.
+                                                        if let EventV1::UserUpdate { id, data, .. } = &event {
+                                                            if id == &user_id
+                                                                && data.flags.is_some_and(|flags| {
+                                                                    User::check_flags(flags).is_err()
+                                                                })
+                                                            {
+                                                                break;
+                                                            }
+                                                        }
                                                     } else {
                                                         warn!("Failed to deserialise an event for {channel}!");
                                                     }
@@ -244,6 +255,14 @@ pub fn spawn_client(db: &'static Database, stream: TcpStream, addr: SocketAddr)
                             // If this was the last session, notify other users that we just went offline.
                             if last_session {
                                 state.broadcast_presence_change(false).await;
+
+                                if let Err(err) =
+                                    Member::remove_temporary_memberships(db, &user_id).await
+                                {
+                                    error!(
+                                        "Failed to remove temporary memberships of {user_id}: {err:?}"
+                                    );
+                                }
                             }
                         }
                         Err(err) => {
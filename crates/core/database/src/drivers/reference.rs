@@ -3,26 +3,42 @@ use std::{collections::HashMap, sync::Arc};
 use futures::lock::Mutex;
 
 use crate::{
-    Bot, Channel, ChannelCompositeKey, ChannelUnread, Emoji, File, Invite, Member,
-    MemberCompositeKey, Message, Server, ServerBan, User, UserSettings, Webhook,
+    AccountStrike, AuditLogEntry, AutoRoleRule, AutomodRule, Bot, Channel, ChannelCompositeKey,
+    ChannelExport, ChannelExportPart, ChannelFollower, ChannelUnread, ContentFilter, Emoji, File,
+    Invite, InviteUse, Member, MemberCompositeKey, Message, RaidProtection, RoleJob, Server,
+    ServerBan, ServerEvent, ServerScreening, ServerTemplate, User, UserSettings, Webhook,
 };
 
 database_derived!(
     /// Reference implementation
     #[derive(Default)]
     pub struct ReferenceDb {
+        pub account_strikes: Arc<Mutex<HashMap<String, AccountStrike>>>,
         pub bots: Arc<Mutex<HashMap<String, Bot>>>,
         pub channels: Arc<Mutex<HashMap<String, Channel>>>,
+        pub channel_exports: Arc<Mutex<HashMap<String, ChannelExport>>>,
+        pub channel_export_parts: Arc<Mutex<HashMap<String, ChannelExportPart>>>,
+        pub channel_followers: Arc<Mutex<HashMap<String, ChannelFollower>>>,
         pub channel_invites: Arc<Mutex<HashMap<String, Invite>>>,
+        pub channel_invite_uses: Arc<Mutex<HashMap<String, InviteUse>>>,
         pub channel_unreads: Arc<Mutex<HashMap<ChannelCompositeKey, ChannelUnread>>>,
         pub channel_webhooks: Arc<Mutex<HashMap<String, Webhook>>>,
         pub emojis: Arc<Mutex<HashMap<String, Emoji>>>,
         pub files: Arc<Mutex<HashMap<String, File>>>,
         pub messages: Arc<Mutex<HashMap<String, Message>>>,
+        pub user_content_filters: Arc<Mutex<HashMap<String, ContentFilter>>>,
         pub user_settings: Arc<Mutex<HashMap<String, UserSettings>>>,
         pub users: Arc<Mutex<HashMap<String, User>>>,
+        pub server_audit_log: Arc<Mutex<HashMap<String, AuditLogEntry>>>,
+        pub server_auto_roles: Arc<Mutex<HashMap<String, AutoRoleRule>>>,
+        pub server_automod: Arc<Mutex<HashMap<String, AutomodRule>>>,
         pub server_bans: Arc<Mutex<HashMap<MemberCompositeKey, ServerBan>>>,
+        pub server_events: Arc<Mutex<HashMap<String, ServerEvent>>>,
         pub server_members: Arc<Mutex<HashMap<MemberCompositeKey, Member>>>,
+        pub server_raid_protection: Arc<Mutex<HashMap<String, RaidProtection>>>,
+        pub server_role_jobs: Arc<Mutex<HashMap<String, RoleJob>>>,
+        pub server_screening: Arc<Mutex<HashMap<String, ServerScreening>>>,
+        pub server_templates: Arc<Mutex<HashMap<String, ServerTemplate>>>,
         pub servers: Arc<Mutex<HashMap<String, Server>>>,
         pub safety_reports: Arc<Mutex<HashMap<String, ()>>>,
         pub safety_snapshots: Arc<Mutex<HashMap<String, ()>>>,
@@ -2,10 +2,11 @@ use authifier::AuthifierEvent;
 use serde::{Deserialize, Serialize};
 
 use revolt_models::v0::{
-    AppendMessage, Channel, Emoji, FieldsChannel, FieldsMember, FieldsRole, FieldsServer,
-    FieldsUser, FieldsWebhook, Interaction, MemberCompositeKey, Message, PartialChannel,
-    PartialMember, PartialMessage, PartialRole, PartialServer, PartialUser, PartialWebhook, Server,
-    User, UserSettings, Webhook,
+    AppendMessage, Channel, ContentFilter, Emoji, ExportStatus, FieldsChannel, FieldsMember,
+    FieldsMessage, FieldsRole, FieldsServer, FieldsServerEvent, FieldsUser, FieldsWebhook,
+    Interaction, MemberCompositeKey, Message, PartialChannel, PartialMember, PartialMessage,
+    PartialRole, PartialServer, PartialServerEvent, PartialUser, PartialWebhook, RoleJobStatus,
+    Server, ServerEvent, User, UserSettings, Webhook,
 };
 use revolt_result::Error;
 
@@ -77,6 +78,8 @@ pub enum EventV1 {
         id: String,
         channel: String,
         data: PartialMessage,
+        #[serde(default)]
+        clear: Vec<FieldsMessage>,
     },
 
     /// Append information to existing message
@@ -145,6 +148,12 @@ pub enum EventV1 {
     /// User left server
     ServerMemberLeave { id: String, user: String },
 
+    /// User ban lifted
+    ServerMemberUnban { id: String, user: String },
+
+    /// Member submitted screening which awaits moderator approval
+    ServerMemberScreeningSubmit { id: String, user: String },
+
     /// Server role created or updated
     ServerRoleUpdate {
         id: String,
@@ -156,6 +165,28 @@ pub enum EventV1 {
     /// Server role deleted
     ServerRoleDelete { id: String, role_id: String },
 
+    /// Server event scheduled
+    ServerEventCreate(ServerEvent),
+
+    /// Server event updated or started
+    ServerEventUpdate {
+        id: String,
+        event_id: String,
+        data: PartialServerEvent,
+        clear: Vec<FieldsServerEvent>,
+    },
+
+    /// Member changed whether they are attending a server event
+    ServerEventRsvp {
+        id: String,
+        event_id: String,
+        user: String,
+        attending: bool,
+    },
+
+    /// Server event deleted
+    ServerEventDelete { id: String, event_id: String },
+
     /// Update existing user
     UserUpdate {
         id: String,
@@ -169,6 +200,9 @@ pub enum EventV1 {
     /// Settings updated remotely
     UserSettingsUpdate { id: String, update: UserSettings },
 
+    /// Content filter updated
+    UserContentFilterUpdate { id: String, filter: ContentFilter },
+
     /// User has been platform banned or deleted their account
     ///
     /// Clients should remove the following associated data:
@@ -219,6 +253,23 @@ pub enum EventV1 {
         message_id: String,
     },
 
+    /// Channel export progressed
+    ChannelExportUpdate {
+        id: String,
+        channel: String,
+        status: ExportStatus,
+        processed: u32,
+    },
+
+    /// Bulk role assignment job progressed
+    RoleJobUpdate {
+        id: String,
+        server: String,
+        status: RoleJobStatus,
+        processed: u32,
+        affected: u32,
+    },
+
     /// New webhook
     WebhookCreate(Webhook),
 
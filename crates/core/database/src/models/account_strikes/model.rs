@@ -0,0 +1,287 @@
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::{AccountStanding, AppealStatus, StrikeAction, StrikeAppeal, UserFlags};
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{util::expiry::expires_in, Database, PartialUser};
+
+auto_derived_partial!(
+    /// Account Strike
+    pub struct AccountStrike {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the user this strike was issued to
+        pub user: String,
+        /// Id of the moderator who issued this strike
+        pub moderator: String,
+        /// Reason given to the user
+        pub reason: String,
+        /// Action taken against the account
+        pub action: StrikeAction,
+        /// Time at which the suspension is lifted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires: Option<Timestamp>,
+        /// Whether the action is still in effect
+        pub active: bool,
+        /// Appeal submitted by the user
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub appeal: Option<StrikeAppeal>,
+    },
+    "PartialAccountStrike"
+);
+
+#[allow(clippy::disallowed_methods)]
+impl AccountStrike {
+    /// Issue a new strike and apply its action to the account
+    pub async fn issue(
+        db: &Database,
+        user: String,
+        moderator: String,
+        reason: String,
+        action: StrikeAction,
+        duration: Option<u64>,
+    ) -> Result<AccountStrike> {
+        let target = db.fetch_user(&user).await?;
+        if target.privileged {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let expires = match action {
+            StrikeAction::Suspension => duration.map(expires_in).transpose()?,
+            _ => None,
+        };
+
+        let strike = AccountStrike {
+            id: Ulid::new().to_string(),
+            user,
+            moderator,
+            reason,
+            expires,
+            active: action != StrikeAction::Warning,
+            action,
+            appeal: None,
+        };
+
+        db.insert_strike(&strike).await?;
+        Self::sync_user_flags(db, &strike.user, None).await?;
+        Ok(strike)
+    }
+
+    /// Check whether this strike has passed its expiry
+    pub fn is_expired(&self) -> bool {
+        if let Some(expires) = self.expires {
+            *expires <= *Timestamp::now_utc()
+        } else {
+            false
+        }
+    }
+
+    /// Submit an appeal against this strike
+    pub async fn submit_appeal(&mut self, db: &Database, content: String) -> Result<()> {
+        if self.appeal.is_some() {
+            return Err(create_error!(NoEffect));
+        }
+
+        let appeal = StrikeAppeal {
+            content,
+            status: AppealStatus::Pending,
+            response: None,
+        };
+
+        self.appeal = Some(appeal.clone());
+        db.update_strike(
+            &self.id,
+            &PartialAccountStrike {
+                appeal: Some(appeal),
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Accept or reject a pending appeal, accepting lifts the strike
+    pub async fn respond_to_appeal(
+        &mut self,
+        db: &Database,
+        accept: bool,
+        response: Option<String>,
+    ) -> Result<()> {
+        let Some(appeal) = &mut self.appeal else {
+            return Err(create_error!(NotFound));
+        };
+
+        if appeal.status != AppealStatus::Pending {
+            return Err(create_error!(NoEffect));
+        }
+
+        appeal.status = if accept {
+            AppealStatus::Accepted
+        } else {
+            AppealStatus::Rejected
+        };
+        appeal.response = response;
+
+        let mut partial = PartialAccountStrike {
+            appeal: Some(appeal.clone()),
+            ..Default::default()
+        };
+
+        if !accept {
+            return db.update_strike(&self.id, &partial).await;
+        }
+
+        self.active = false;
+        partial.active = Some(false);
+
+        db.update_strike(&self.id, &partial).await?;
+        Self::sync_user_flags(db, &self.user, Some(&self.action)).await
+    }
+
+    /// Stop enforcing this strike
+    pub async fn lift(&mut self, db: &Database) -> Result<()> {
+        self.active = false;
+        db.update_strike(
+            &self.id,
+            &PartialAccountStrike {
+                active: Some(false),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        Self::sync_user_flags(db, &self.user, Some(&self.action)).await
+    }
+
+    /// Work out the standing of an account from its strikes
+    pub async fn fetch_standing(db: &Database, user: &str) -> Result<AccountStanding> {
+        let strikes = db.fetch_strikes(user).await?;
+
+        let suspensions: Vec<&AccountStrike> = strikes
+            .iter()
+            .filter(|strike| strike.active && strike.action == StrikeAction::Suspension)
+            .collect();
+
+        Ok(AccountStanding {
+            suspended: !suspensions.is_empty(),
+            suspended_until: if suspensions.iter().any(|strike| strike.expires.is_none()) {
+                None
+            } else {
+                suspensions
+                    .iter()
+                    .filter_map(|strike| strike.expires)
+                    .max_by_key(|expires| **expires)
+            },
+            banned: strikes
+                .iter()
+                .any(|strike| strike.active && strike.action == StrikeAction::Ban),
+            strikes: strikes.into_iter().map(|strike| strike.into()).collect(),
+        })
+    }
+
+    /// Bring the suspended and banned flags of a user in line with their active strikes
+    ///
+    /// Flags are only cleared when the strike that was just lifted applied them,
+    /// so a flag set on the account by other means is left alone.
+    async fn sync_user_flags(
+        db: &Database,
+        user: &str,
+        lifted: Option<&StrikeAction>,
+    ) -> Result<()> {
+        let standing = Self::fetch_standing(db, user).await?;
+        let mut user = db.fetch_user(user).await?;
+
+        let current = user.flags.unwrap_or_default();
+        let mut flags = current;
+
+        for (action, flag, enforced) in [
+            (
+                StrikeAction::Suspension,
+                UserFlags::Suspended,
+                standing.suspended,
+            ),
+            (StrikeAction::Ban, UserFlags::Banned, standing.banned),
+        ] {
+            if enforced {
+                flags |= flag as i32;
+            } else if lifted == Some(&action) {
+                flags &= !(flag as i32);
+            }
+        }
+
+        if flags == current {
+            return Ok(());
+        }
+
+        user.update(
+            db,
+            PartialUser {
+                flags: Some(flags),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_models::v0::{StrikeAction, UserFlags};
+
+    use crate::{AccountStrike, PartialUser, User};
+
+    #[async_std::test]
+    async fn sync_user_flags() {
+        database_test!(|db| async move {
+            let user = User::create(
+                &db,
+                "User".to_string(),
+                None,
+                Some(PartialUser {
+                    flags: Some(UserFlags::Banned as i32),
+                    ..Default::default()
+                }),
+            )
+            .await
+            .unwrap();
+
+            let mut strike = AccountStrike::issue(
+                &db,
+                user.id.clone(),
+                "Moderator".to_string(),
+                "Reason".to_string(),
+                StrikeAction::Suspension,
+                Some(3600),
+            )
+            .await
+            .unwrap();
+
+            let flags = db.fetch_user(&user.id).await.unwrap().flags.unwrap();
+            assert_eq!(
+                flags,
+                UserFlags::Banned as i32 | UserFlags::Suspended as i32
+            );
+
+            // Lifting the suspension leaves the ban which was set by hand in place
+            strike.lift(&db).await.unwrap();
+            let flags = db.fetch_user(&user.id).await.unwrap().flags.unwrap();
+            assert_eq!(flags, UserFlags::Banned as i32);
+
+            let mut warning = AccountStrike::issue(
+                &db,
+                user.id.clone(),
+                "Moderator".to_string(),
+                "Reason".to_string(),
+                StrikeAction::Warning,
+                None,
+            )
+            .await
+            .unwrap();
+
+            warning.lift(&db).await.unwrap();
+            let flags = db.fetch_user(&user.id).await.unwrap().flags.unwrap();
+            assert_eq!(flags, UserFlags::Banned as i32);
+        });
+    }
+}
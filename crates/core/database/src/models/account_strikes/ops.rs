@@ -0,0 +1,27 @@
+use revolt_result::Result;
+
+use crate::{AccountStrike, PartialAccountStrike};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractAccountStrikes: Sync + Send {
+    /// Insert a new strike into the database
+    async fn insert_strike(&self, strike: &AccountStrike) -> Result<()>;
+
+    /// Fetch a strike by its id
+    async fn fetch_strike(&self, id: &str) -> Result<AccountStrike>;
+
+    /// Fetch all strikes issued to a user, newest first
+    async fn fetch_strikes(&self, user_id: &str) -> Result<Vec<AccountStrike>>;
+
+    /// Fetch strikes with an appeal waiting for review, oldest first
+    async fn fetch_strikes_pending_appeal(&self) -> Result<Vec<AccountStrike>>;
+
+    /// Fetch active suspensions which have passed their expiry
+    async fn fetch_expired_strikes(&self) -> Result<Vec<AccountStrike>>;
+
+    /// Update a strike with new information
+    async fn update_strike(&self, id: &str, partial: &PartialAccountStrike) -> Result<()>;
+}
@@ -0,0 +1,79 @@
+use bson::to_bson;
+use iso8601_timestamp::Timestamp;
+use mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{AccountStrike, PartialAccountStrike};
+
+use super::AbstractAccountStrikes;
+
+static COL: &str = "account_strikes";
+
+#[async_trait]
+impl AbstractAccountStrikes for MongoDb {
+    /// Insert a new strike into the database
+    async fn insert_strike(&self, strike: &AccountStrike) -> Result<()> {
+        query!(self, insert_one, COL, &strike).map(|_| ())
+    }
+
+    /// Fetch a strike by its id
+    async fn fetch_strike(&self, id: &str) -> Result<AccountStrike> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all strikes issued to a user, newest first
+    async fn fetch_strikes(&self, user_id: &str) -> Result<Vec<AccountStrike>> {
+        self.find_with_options(
+            COL,
+            doc! {
+                "user": user_id
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Fetch strikes with an appeal waiting for review, oldest first
+    async fn fetch_strikes_pending_appeal(&self) -> Result<Vec<AccountStrike>> {
+        self.find_with_options(
+            COL,
+            doc! {
+                "appeal.status": "Pending"
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "_id": 1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Fetch active suspensions which have passed their expiry
+    async fn fetch_expired_strikes(&self) -> Result<Vec<AccountStrike>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "active": true,
+                "expires": {
+                    "$lte": to_bson(&Timestamp::now_utc())
+                        .map_err(|_| create_database_error!("to_bson", "timestamp"))?
+                }
+            }
+        )
+    }
+
+    /// Update a strike with new information
+    async fn update_strike(&self, id: &str, partial: &PartialAccountStrike) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+}
@@ -0,0 +1,82 @@
+use revolt_models::v0::AppealStatus;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{AccountStrike, PartialAccountStrike};
+
+use super::AbstractAccountStrikes;
+
+#[async_trait]
+impl AbstractAccountStrikes for ReferenceDb {
+    /// Insert a new strike into the database
+    async fn insert_strike(&self, strike: &AccountStrike) -> Result<()> {
+        let mut account_strikes = self.account_strikes.lock().await;
+        if account_strikes.contains_key(&strike.id) {
+            Err(create_database_error!("insert", "account_strike"))
+        } else {
+            account_strikes.insert(strike.id.to_string(), strike.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a strike by its id
+    async fn fetch_strike(&self, id: &str) -> Result<AccountStrike> {
+        let account_strikes = self.account_strikes.lock().await;
+        account_strikes
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all strikes issued to a user, newest first
+    async fn fetch_strikes(&self, user_id: &str) -> Result<Vec<AccountStrike>> {
+        let account_strikes = self.account_strikes.lock().await;
+        let mut strikes: Vec<AccountStrike> = account_strikes
+            .values()
+            .filter(|strike| strike.user == user_id)
+            .cloned()
+            .collect();
+
+        strikes.sort_by(|a, b| b.id.cmp(&a.id));
+        Ok(strikes)
+    }
+
+    /// Fetch strikes with an appeal waiting for review, oldest first
+    async fn fetch_strikes_pending_appeal(&self) -> Result<Vec<AccountStrike>> {
+        let account_strikes = self.account_strikes.lock().await;
+        let mut strikes: Vec<AccountStrike> = account_strikes
+            .values()
+            .filter(|strike| {
+                strike
+                    .appeal
+                    .as_ref()
+                    .is_some_and(|appeal| appeal.status == AppealStatus::Pending)
+            })
+            .cloned()
+            .collect();
+
+        strikes.sort_by(|a, b| a.id.cmp(&b.id));
+        Ok(strikes)
+    }
+
+    /// Fetch active suspensions which have passed their expiry
+    async fn fetch_expired_strikes(&self) -> Result<Vec<AccountStrike>> {
+        let account_strikes = self.account_strikes.lock().await;
+        Ok(account_strikes
+            .values()
+            .filter(|strike| strike.active && strike.is_expired())
+            .cloned()
+            .collect())
+    }
+
+    /// Update a strike with new information
+    async fn update_strike(&self, id: &str, partial: &PartialAccountStrike) -> Result<()> {
+        let mut account_strikes = self.account_strikes.lock().await;
+        if let Some(strike) = account_strikes.get_mut(id) {
+            strike.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
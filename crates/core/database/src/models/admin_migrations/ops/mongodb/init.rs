@@ -36,10 +36,54 @@ pub async fn create_database(db: &MongoDb) {
         .await
         .expect("Failed to create server_bans collection.");
 
+    db.create_collection("server_automod", None)
+        .await
+        .expect("Failed to create server_automod collection.");
+
+    db.create_collection("server_audit_log", None)
+        .await
+        .expect("Failed to create server_audit_log collection.");
+
+    db.create_collection("server_raid_protection", None)
+        .await
+        .expect("Failed to create server_raid_protection collection.");
+
+    db.create_collection("server_screening", None)
+        .await
+        .expect("Failed to create server_screening collection.");
+
+    db.create_collection("server_events", None)
+        .await
+        .expect("Failed to create server_events collection.");
+
+    db.create_collection("server_auto_roles", None)
+        .await
+        .expect("Failed to create server_auto_roles collection.");
+
+    db.create_collection("server_role_jobs", None)
+        .await
+        .expect("Failed to create server_role_jobs collection.");
+
+    db.create_collection("server_templates", None)
+        .await
+        .expect("Failed to create server_templates collection.");
+
+    db.create_collection("user_content_filters", None)
+        .await
+        .expect("Failed to create user_content_filters collection.");
+
+    db.create_collection("account_strikes", None)
+        .await
+        .expect("Failed to create account_strikes collection.");
+
     db.create_collection("channel_invites", None)
         .await
         .expect("Failed to create channel_invites collection.");
 
+    db.create_collection("channel_invite_uses", None)
+        .await
+        .expect("Failed to create channel_invite_uses collection.");
+
     db.create_collection("channel_unreads", None)
         .await
         .expect("Failed to create channel_unreads collection.");
@@ -48,6 +92,18 @@ pub async fn create_database(db: &MongoDb) {
         .await
         .expect("Failed to create channel_webhooks collection.");
 
+    db.create_collection("channel_exports", None)
+        .await
+        .expect("Failed to create channel_exports collection.");
+
+    db.create_collection("channel_export_parts", None)
+        .await
+        .expect("Failed to create channel_export_parts collection.");
+
+    db.create_collection("channel_followers", None)
+        .await
+        .expect("Failed to create channel_followers collection.");
+
     db.create_collection("migrations", None)
         .await
         .expect("Failed to create migrations collection.");
@@ -146,6 +202,13 @@ pub async fn create_database(db: &MongoDb) {
                         "author": 1_i32
                     },
                     "name": "author"
+                },
+                {
+                    "key": {
+                        "forwarded.id": 1_i32
+                    },
+                    "name": "forwarded_id",
+                    "sparse": true
                 }
             ]
         },
@@ -154,6 +217,262 @@ pub async fn create_database(db: &MongoDb) {
     .await
     .expect("Failed to create message index.");
 
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_exports",
+            "indexes": [
+                {
+                    "key": {
+                        "status": 1_i32,
+                        "user": 1_i32
+                    },
+                    "name": "status_user"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel export index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_export_parts",
+            "indexes": [
+                {
+                    "key": {
+                        "export": 1_i32,
+                        "index": 1_i32
+                    },
+                    "name": "export_index"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel export part index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_automod",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32
+                    },
+                    "name": "server"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server automod index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_audit_log",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "server_id_compound"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server audit log index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "account_strikes",
+            "indexes": [
+                {
+                    "key": {
+                        "user": 1_i32,
+                        "_id": -1_i32
+                    },
+                    "name": "user_id_compound"
+                },
+                {
+                    "key": {
+                        "expires": 1_i32
+                    },
+                    "name": "expires",
+                    "sparse": true
+                },
+                {
+                    "key": {
+                        "appeal.status": 1_i32
+                    },
+                    "name": "appeal_status",
+                    "sparse": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create account strikes index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_bans",
+            "indexes": [
+                {
+                    "key": {
+                        "expires": 1_i32
+                    },
+                    "name": "expires",
+                    "sparse": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server ban expiry index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_invites",
+            "indexes": [
+                {
+                    "key": {
+                        "expires_at": 1_i32
+                    },
+                    "name": "expires_at",
+                    "sparse": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel_invites index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_invite_uses",
+            "indexes": [
+                {
+                    "key": {
+                        "code": 1_i32
+                    },
+                    "name": "code"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel_invite_uses index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_templates",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32
+                    },
+                    "name": "server"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_templates index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "channel_followers",
+            "indexes": [
+                {
+                    "key": {
+                        "channel": 1_i32
+                    },
+                    "name": "channel"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channel_followers index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "channels",
+            "indexes": [
+                {
+                    "key": {
+                        "forum": 1_i32
+                    },
+                    "name": "forum",
+                    "sparse": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create channels index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_events",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32
+                    },
+                    "name": "server"
+                },
+                {
+                    "key": {
+                        "status": 1_i32,
+                        "starts_at": 1_i32
+                    },
+                    "name": "status_starts_at"
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_events index.");
+
+    db.run_command(
+        doc! {
+            "createIndexes": "server_auto_roles",
+            "indexes": [
+                {
+                    "key": {
+                        "server": 1_i32
+                    },
+                    "name": "server"
+                },
+                {
+                    "key": {
+                        "trigger.message": 1_i32
+                    },
+                    "name": "trigger_message",
+                    "sparse": true
+                }
+            ]
+        },
+        None,
+    )
+    .await
+    .expect("Failed to create server_auto_roles index.");
+
     db.run_command(
         doc! {
             "createIndexes": "channel_unreads",
@@ -194,6 +513,32 @@ pub async fn create_database(db: &MongoDb) {
                         "_id.user": 1_i32,
                     },
                     "name": "user_id"
+                },
+                {
+                    "key": {
+                        "timeout": 1_i32
+                    },
+                    "name": "timeout",
+                    "sparse": true
+                },
+                {
+                    "key": {
+                        "_id.server": 1_i32,
+                        "pending.submitted_at": 1_i32
+                    },
+                    "name": "pending_screening",
+                    "partialFilterExpression": {
+                        "pending.submitted_at": {
+                            "$exists": true
+                        }
+                    }
+                },
+                {
+                    "key": {
+                        "_id.server": 1_i32,
+                        "roles": 1_i32
+                    },
+                    "name": "server_roles"
                 }
             ]
         },
@@ -1,14 +1,20 @@
-use std::{collections::HashSet, ops::BitXor, time::Duration};
+use std::{
+    collections::HashSet,
+    ops::BitXor,
+    time::{Duration, SystemTime},
+};
 
 use crate::{
     mongodb::{
-        bson::{doc, from_bson, from_document, to_document, Bson, DateTime, Document},
+        bson::{doc, from_bson, from_document, to_bson, to_document, Bson, DateTime, Document},
         options::FindOptions,
     },
     MongoDb, DISCRIMINATOR_SEARCH_SPACE,
 };
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
 use rand::seq::SliceRandom;
+use revolt_models::v0::AutoRoleTrigger;
 use revolt_permissions::DEFAULT_WEBHOOK_PERMISSIONS;
 use serde::{Deserialize, Serialize};
 use unicode_segmentation::UnicodeSegmentation;
@@ -19,7 +25,7 @@ struct MigrationInfo {
     revision: i32,
 }
 
-pub const LATEST_REVISION: i32 = 26;
+pub const LATEST_REVISION: i32 = 45;
 
 pub async fn migrate_database(db: &MongoDb) {
     let migrations = db.col::<Document>("migrations");
@@ -983,6 +989,562 @@ pub async fn run_migrations(db: &MongoDb, revision: i32) -> i32 {
             .expect("Failed to create ratelimit_events index.");
     }
 
+    if revision <= 26 {
+        info!("Running migration [revision 26 / 18-10-2026]: Add forwarded message index.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "messages",
+                    "indexes": [
+                        {
+                            "key": {
+                                "forwarded.id": 1_i32
+                            },
+                            "name": "forwarded_id",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create forwarded message index.");
+    }
+
+    if revision <= 27 {
+        info!("Running migration [revision 27 / 18-10-2026]: Add channel export collections.");
+
+        db.db()
+            .create_collection("channel_exports", None)
+            .await
+            .expect("Failed to create channel_exports collection.");
+
+        db.db()
+            .create_collection("channel_export_parts", None)
+            .await
+            .expect("Failed to create channel_export_parts collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_export_parts",
+                    "indexes": [
+                        {
+                            "key": {
+                                "export": 1_i32,
+                                "index": 1_i32
+                            },
+                            "name": "export_index"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create channel export part index.");
+    }
+
+    if revision <= 28 {
+        info!("Running migration [revision 28 / 18-10-2026]: Add server automod collection.");
+
+        db.db()
+            .create_collection("server_automod", None)
+            .await
+            .expect("Failed to create server_automod collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_automod",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32
+                            },
+                            "name": "server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server automod index.");
+    }
+
+    if revision <= 29 {
+        info!("Running migration [revision 29 / 18-10-2026]: Add server audit log collection.");
+
+        db.db()
+            .create_collection("server_audit_log", None)
+            .await
+            .expect("Failed to create server_audit_log collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_audit_log",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "server_id_compound"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server audit log index.");
+    }
+
+    if revision <= 30 {
+        info!("Running migration [revision 30 / 18-10-2026]: Add index for ban expiry.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_bans",
+                    "indexes": [
+                        {
+                            "key": {
+                                "expires": 1_i32
+                            },
+                            "name": "expires",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server ban expiry index.");
+    }
+
+    if revision <= 31 {
+        info!("Running migration [revision 31 / 18-10-2026]: Add index for member timeouts.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_members",
+                    "indexes": [
+                        {
+                            "key": {
+                                "timeout": 1_i32
+                            },
+                            "name": "timeout",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create member timeout index.");
+    }
+
+    if revision <= 32 {
+        info!("Running migration [revision 32 / 18-10-2026]: Add account strikes collection.");
+
+        db.db()
+            .create_collection("account_strikes", None)
+            .await
+            .expect("Failed to create account_strikes collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "account_strikes",
+                    "indexes": [
+                        {
+                            "key": {
+                                "user": 1_i32,
+                                "_id": -1_i32
+                            },
+                            "name": "user_id_compound"
+                        },
+                        {
+                            "key": {
+                                "expires": 1_i32
+                            },
+                            "name": "expires",
+                            "sparse": true
+                        },
+                        {
+                            "key": {
+                                "appeal.status": 1_i32
+                            },
+                            "name": "appeal_status",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create account strikes index.");
+    }
+
+    if revision <= 33 {
+        info!("Running migration [revision 33 / 18-10-2026]: Add raid protection collection.");
+
+        db.db()
+            .create_collection("server_raid_protection", None)
+            .await
+            .expect("Failed to create server_raid_protection collection.");
+    }
+
+    if revision <= 34 {
+        info!("Running migration [revision 34 / 18-10-2026]: Add membership screening.");
+
+        db.db()
+            .create_collection("server_screening", None)
+            .await
+            .expect("Failed to create server_screening collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_members",
+                    "indexes": [
+                        {
+                            "key": {
+                                "_id.server": 1_i32,
+                                "pending.submitted_at": 1_i32
+                            },
+                            "name": "pending_screening",
+                            "partialFilterExpression": {
+                                "pending.submitted_at": {
+                                    "$exists": true
+                                }
+                            }
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create member screening index.");
+    }
+
+    if revision <= 35 {
+        info!("Running migration [revision 35 / 18-10-2026]: Add user content filters.");
+
+        db.db()
+            .create_collection("user_content_filters", None)
+            .await
+            .expect("Failed to create user_content_filters collection.");
+    }
+
+    if revision <= 36 {
+        info!("Running migration [revision 36 / 18-10-2026]: Add invite expiry and usage.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_invites",
+                    "indexes": [
+                        {
+                            "key": {
+                                "expires_at": 1_i32
+                            },
+                            "name": "expires_at",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create invite expiry index.");
+
+        db.db()
+            .create_collection("channel_invite_uses", None)
+            .await
+            .expect("Failed to create channel_invite_uses collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_invite_uses",
+                    "indexes": [
+                        {
+                            "key": {
+                                "code": 1_i32
+                            },
+                            "name": "code"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create invite uses index.");
+    }
+
+    if revision <= 37 {
+        info!("Running migration [revision 37 / 18-10-2026]: Add server templates.");
+
+        db.db()
+            .create_collection("server_templates", None)
+            .await
+            .expect("Failed to create server_templates collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_templates",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32
+                            },
+                            "name": "server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_templates index.");
+    }
+
+    if revision <= 38 {
+        info!("Running migration [revision 38 / 18-10-2026]: Add announcement channel followers.");
+
+        db.db()
+            .create_collection("channel_followers", None)
+            .await
+            .expect("Failed to create channel_followers collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_followers",
+                    "indexes": [
+                        {
+                            "key": {
+                                "channel": 1_i32
+                            },
+                            "name": "channel"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create channel_followers index.");
+    }
+
+    if revision <= 39 {
+        info!("Running migration [revision 39 / 18-10-2026]: Add forum posts index to channels.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channels",
+                    "indexes": [
+                        {
+                            "key": {
+                                "forum": 1_i32
+                            },
+                            "name": "forum",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create channels index.");
+    }
+
+    if revision <= 40 {
+        info!("Running migration [revision 40 / 18-10-2026]: Add scheduled server events.");
+
+        db.db()
+            .create_collection("server_events", None)
+            .await
+            .expect("Failed to create server_events collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_events",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32
+                            },
+                            "name": "server"
+                        },
+                        {
+                            "key": {
+                                "status": 1_i32,
+                                "starts_at": 1_i32
+                            },
+                            "name": "status_starts_at"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_events index.");
+    }
+
+    if revision <= 41 {
+        info!("Running migration [revision 41 / 18-10-2026]: Add role index to server members.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_members",
+                    "indexes": [
+                        {
+                            "key": {
+                                "_id.server": 1_i32,
+                                "roles": 1_i32
+                            },
+                            "name": "server_roles"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_members index.");
+    }
+
+    if revision <= 42 {
+        info!("Running migration [revision 42 / 18-10-2026]: Add auto role rules and role jobs.");
+
+        db.db()
+            .create_collection("server_auto_roles", None)
+            .await
+            .expect("Failed to create server_auto_roles collection.");
+
+        db.db()
+            .create_collection("server_role_jobs", None)
+            .await
+            .expect("Failed to create server_role_jobs collection.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_auto_roles",
+                    "indexes": [
+                        {
+                            "key": {
+                                "server": 1_i32
+                            },
+                            "name": "server"
+                        },
+                        {
+                            "key": {
+                                "trigger.message": 1_i32
+                            },
+                            "name": "trigger_message",
+                            "sparse": true
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_auto_roles index.");
+    }
+
+    if revision <= 43 {
+        info!("Running migration [revision 43 / 18-10-2026]: Add channel export status index.");
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "channel_exports",
+                    "indexes": [
+                        {
+                            "key": {
+                                "status": 1_i32,
+                                "user": 1_i32
+                            },
+                            "name": "status_user"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create channel_exports index.");
+    }
+
+    if revision <= 44 {
+        info!("Running migration [revision 44 / 18-10-2026]: Add auto role rule creators and role job status index.");
+
+        let rules = db.col::<Document>("server_auto_roles");
+        let mut cursor = rules.find(doc! {}, None).await.unwrap();
+
+        while let Some(Ok(document)) = cursor.next().await {
+            let id = document.get_str("_id").unwrap().to_string();
+            let server = document.get_str("server").unwrap();
+
+            // Rules could only be made by members able to assign roles, the owner is the closest match
+            let owner = db
+                .col::<Document>("servers")
+                .find_one(doc! { "_id": server }, None)
+                .await
+                .unwrap()
+                .and_then(|server| server.get_str("owner").ok().map(str::to_string));
+
+            let Some(owner) = owner else {
+                rules.delete_one(doc! { "_id": id }, None).await.unwrap();
+                continue;
+            };
+
+            let mut update = doc! {
+                "creator": owner
+            };
+
+            // Tenure rules have already been run, only members reaching the tenure from now on are new
+            if let Some(Ok(AutoRoleTrigger::Tenure { days })) = document
+                .get("trigger")
+                .map(|trigger| from_bson::<AutoRoleTrigger>(trigger.clone()))
+            {
+                let cutoff =
+                    Timestamp::from(SystemTime::now() - Duration::from_secs(days as u64 * 86_400));
+
+                update.insert("applied_until", to_bson(&cutoff).unwrap());
+            }
+
+            rules
+                .update_one(doc! { "_id": id }, doc! { "$set": update }, None)
+                .await
+                .unwrap();
+        }
+
+        db.db()
+            .run_command(
+                doc! {
+                    "createIndexes": "server_role_jobs",
+                    "indexes": [
+                        {
+                            "key": {
+                                "status": 1_i32,
+                                "server": 1_i32
+                            },
+                            "name": "status_server"
+                        }
+                    ]
+                },
+                None,
+            )
+            .await
+            .expect("Failed to create server_role_jobs index.");
+    }
+
     // Need to migrate fields on attachments, change `user_id`, `object_id`, etc to `parent`.
 
     // Reminder to update LATEST_REVISION when adding new migrations.
@@ -266,6 +266,8 @@ impl Bot {
                 default_permissions: None,
                 role_permissions: HashMap::new(),
                 nsfw: false,
+                slowmode_seconds: 0,
+                announcement: false,
             };
 
             channel.create(db).await?;
@@ -291,8 +293,13 @@ impl Bot {
         let mut invite_code: Option<String> = None;
         let mut default_server: Option<String> = None;
 
-        if let Invite::Server { code, server, .. } =
-            Invite::create_channel_invite(db, bot.owner.clone(), channels.first().unwrap()).await?
+        if let Invite::Server { code, server, .. } = Invite::create_channel_invite(
+            db,
+            bot.owner.clone(),
+            channels.first().unwrap(),
+            Default::default(),
+        )
+        .await?
         {
             invite_code = Some(code);
             default_server = Some(server)
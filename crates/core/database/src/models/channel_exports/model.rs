@@ -0,0 +1,555 @@
+use std::collections::HashMap;
+
+use futures::{
+    stream::{self, BoxStream},
+    StreamExt,
+};
+use iso8601_timestamp::Timestamp;
+use revolt_config::config;
+use revolt_models::v0::{ExportFormat, ExportStatus};
+use revolt_result::{ErrorType, Result};
+use serde_json::json;
+use ulid::Ulid;
+
+use crate::{events::client::EventV1, tasks, Database, Message, User};
+
+auto_derived_partial!(
+    /// Channel Export
+    pub struct ChannelExport {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the channel being exported
+        pub channel: String,
+        /// Id of the user who requested this export
+        pub user: String,
+        /// Format the archive is produced in
+        pub format: ExportFormat,
+        /// Current state of the export
+        pub status: ExportStatus,
+        /// Number of messages processed so far
+        pub processed: u32,
+        /// Time at which the export finished
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub completed_at: Option<Timestamp>,
+    },
+    "PartialChannelExport"
+);
+
+auto_derived!(
+    /// Rendered chunk of a channel export
+    pub struct ChannelExportPart {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the export this part belongs to
+        pub export: String,
+        /// Position of this part within the archive
+        pub index: u32,
+        /// Rendered messages
+        pub data: String,
+    }
+);
+
+/// Stylesheet embedded into HTML exports so they render without any external resources
+const HTML_STYLE: &str =
+    "body{font-family:sans-serif;background:#191919;color:#ddd;margin:0;padding:24px}\
+h1{font-size:20px}.message{padding:6px 0;border-bottom:1px solid #2a2a2a}\
+.author{font-weight:bold;margin-right:8px}.time{color:#888;font-size:12px}\
+.content{white-space:pre-wrap;margin-top:4px}.system{color:#999;font-style:italic}\
+.attachment,.embed{margin-top:4px;font-size:14px}a{color:#fd6671}";
+
+/// Maximum number of exports a user may have waiting or running at once
+const MAX_UNFINISHED_EXPORTS: usize = 1;
+
+#[allow(clippy::disallowed_methods)]
+impl ChannelExport {
+    /// Create and queue a new channel export
+    pub async fn create(
+        db: &Database,
+        channel: String,
+        user: String,
+        format: ExportFormat,
+    ) -> Result<ChannelExport> {
+        if db
+            .fetch_unfinished_channel_exports(Some(&user))
+            .await?
+            .len()
+            >= MAX_UNFINISHED_EXPORTS
+        {
+            return Err(create_error!(TooManyPendingExports {
+                max: MAX_UNFINISHED_EXPORTS
+            }));
+        }
+
+        let export = ChannelExport {
+            id: Ulid::new().to_string(),
+            channel,
+            user,
+            format,
+            status: ExportStatus::Pending,
+            processed: 0,
+            completed_at: None,
+        };
+
+        db.insert_channel_export(&export).await?;
+        tasks::channel_export::queue(export.id.clone()).await;
+
+        Ok(export)
+    }
+
+    /// Update export progress and notify the requesting user
+    pub async fn update(&mut self, db: &Database, partial: PartialChannelExport) -> Result<()> {
+        self.apply_options(partial.clone());
+        db.update_channel_export(&self.id, &partial).await?;
+
+        EventV1::ChannelExportUpdate {
+            id: self.id.clone(),
+            channel: self.channel.clone(),
+            status: self.status.clone(),
+            processed: self.processed,
+        }
+        .private(self.user.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Render a page of messages in this export's format
+    pub async fn render_page(&self, db: &Database, messages: &[Message]) -> Result<String> {
+        let author_ids: Vec<String> = messages
+            .iter()
+            .filter(|message| message.webhook.is_none())
+            .map(|message| message.author.clone())
+            .collect();
+
+        let users: HashMap<String, User> = db
+            .fetch_users(&author_ids)
+            .await?
+            .into_iter()
+            .map(|user| (user.id.clone(), user))
+            .collect();
+
+        let autumn = config().await.hosts.autumn;
+        let entries: Vec<String> = messages
+            .iter()
+            .map(|message| {
+                let author = author_name(message, &users);
+                let timestamp = message_timestamp(&message.id);
+                let attachments: Vec<String> = message
+                    .attachments
+                    .iter()
+                    .flatten()
+                    .map(|file| format!("{autumn}/{}/{}", file.tag, file.id))
+                    .collect();
+
+                match self.format {
+                    ExportFormat::Json => json!({
+                        "id": message.id,
+                        "timestamp": timestamp,
+                        "author": {
+                            "id": message.author,
+                            "name": author,
+                        },
+                        "content": message.content,
+                        "system": message.system,
+                        "attachments": message
+                            .attachments
+                            .iter()
+                            .flatten()
+                            .zip(&attachments)
+                            .map(|(file, url)| json!({
+                                "filename": file.filename,
+                                "content_type": file.content_type,
+                                "size": file.size,
+                                "url": url,
+                            }))
+                            .collect::<Vec<_>>(),
+                        "embeds": message.embeds,
+                        "replies": message.replies,
+                        "edited": message.edited,
+                    })
+                    .to_string(),
+                    ExportFormat::Html => {
+                        let mut html = format!(
+                            "<div class=\"message\" id=\"{}\"><span class=\"author\">{}</span><span class=\"time\">{}</span>",
+                            message.id,
+                            escape_html(&author),
+                            timestamp
+                        );
+
+                        if let Some(system) = &message.system {
+                            html.push_str(&format!(
+                                "<div class=\"content system\">{}</div>",
+                                escape_html(&system_summary(system))
+                            ));
+                        }
+
+                        if let Some(content) = &message.content {
+                            html.push_str(&format!(
+                                "<div class=\"content\">{}</div>",
+                                escape_html(content)
+                            ));
+                        }
+
+                        for url in &attachments {
+                            let url = escape_html(url);
+                            html.push_str(&format!(
+                                "<div class=\"attachment\"><a href=\"{url}\">{url}</a></div>"
+                            ));
+                        }
+
+                        for embed in message.embeds.iter().flatten() {
+                            if let Some(summary) = embed_summary(embed) {
+                                html.push_str(&format!(
+                                    "<div class=\"embed\">{}</div>",
+                                    escape_html(&summary)
+                                ));
+                            }
+                        }
+
+                        html.push_str("</div>");
+                        html
+                    }
+                    ExportFormat::PlainText => {
+                        let mut text = format!("[{timestamp}] {author}:");
+
+                        if let Some(system) = &message.system {
+                            text.push_str(&format!(" * {}", system_summary(system)));
+                        }
+
+                        if let Some(content) = &message.content {
+                            text.push(' ');
+                            text.push_str(content);
+                        }
+
+                        for url in &attachments {
+                            text.push_str(&format!("\n    [attachment] {url}"));
+                        }
+
+                        for embed in message.embeds.iter().flatten() {
+                            if let Some(summary) = embed_summary(embed) {
+                                text.push_str(&format!("\n    [embed] {summary}"));
+                            }
+                        }
+
+                        text
+                    }
+                }
+            })
+            .collect();
+
+        Ok(match self.format {
+            ExportFormat::Json => entries.join(","),
+            ExportFormat::Html | ExportFormat::PlainText => entries.join("\n"),
+        })
+    }
+
+    /// Delete this export along with its rendered parts
+    pub async fn delete(self, db: &Database) -> Result<()> {
+        db.delete_channel_export_parts(&self.id).await?;
+        db.delete_channel_export(&self.id).await
+    }
+
+    /// Stream the final archive, reading rendered parts one at a time
+    pub fn assemble(&self, db: Database, title: &str) -> Result<BoxStream<'static, String>> {
+        if self.status != ExportStatus::Completed {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let exported_at = self
+            .completed_at
+            .map(|timestamp| json!(timestamp))
+            .unwrap_or_default();
+
+        let (header, separator, footer) = match self.format {
+            ExportFormat::Json => (
+                format!(
+                    "{{\"channel\":{},\"title\":{},\"exported_at\":{exported_at},\"messages\":[",
+                    json!(self.channel),
+                    json!(title)
+                ),
+                ",",
+                "]}",
+            ),
+            ExportFormat::Html => (
+                format!(
+                    "<!DOCTYPE html><html><head><meta charset=\"utf-8\"><title>{title}</title><style>{HTML_STYLE}</style></head><body><h1>{title}</h1>\n",
+                    title = escape_html(title)
+                ),
+                "\n",
+                "\n</body></html>",
+            ),
+            ExportFormat::PlainText => (
+                format!(
+                    "{title}\nExported at {}\n\n",
+                    exported_at.as_str().unwrap_or_default()
+                ),
+                "\n",
+                "\n",
+            ),
+        };
+
+        let parts = stream::unfold(
+            (db, self.id.clone(), 0, true),
+            move |(db, export, mut index, first)| async move {
+                loop {
+                    let part = match db.fetch_channel_export_part(&export, index).await {
+                        Ok(part) => part,
+                        Err(err) => {
+                            if err.error_type != ErrorType::NotFound {
+                                error!("Failed to read part {index} of export {export}: {err:?}");
+                            }
+
+                            return None;
+                        }
+                    };
+
+                    index += 1;
+                    if !part.data.is_empty() {
+                        let data = if first {
+                            part.data
+                        } else {
+                            format!("{separator}{}", part.data)
+                        };
+
+                        return Some((data, (db, export, index, false)));
+                    }
+                }
+            },
+        );
+
+        Ok(stream::once(async move { header })
+            .chain(parts)
+            .chain(stream::once(async move { footer.to_string() }))
+            .boxed())
+    }
+}
+
+/// Resolve the name shown next to a message
+fn author_name(message: &Message, users: &HashMap<String, User>) -> String {
+    if let Some(name) = message
+        .masquerade
+        .as_ref()
+        .and_then(|masquerade| masquerade.name.clone())
+    {
+        return name;
+    }
+
+    if let Some(webhook) = &message.webhook {
+        return webhook.name.clone();
+    }
+
+    users
+        .get(&message.author)
+        .map(|user| {
+            user.display_name
+                .clone()
+                .unwrap_or_else(|| format!("{}#{}", user.username, user.discriminator))
+        })
+        .unwrap_or_else(|| message.author.clone())
+}
+
+/// Derive the creation time of a message from its id
+fn message_timestamp(id: &str) -> String {
+    Ulid::from_string(id)
+        .map(|ulid| Timestamp::from(ulid.datetime()).format().to_string())
+        .unwrap_or_default()
+}
+
+/// Describe a system message in a human readable way
+fn system_summary(system: &crate::SystemMessage) -> String {
+    if let crate::SystemMessage::Text { content } = system {
+        return content.clone();
+    }
+
+    serde_json::to_value(system)
+        .ok()
+        .and_then(|value| value["type"].as_str().map(|kind| kind.replace('_', " ")))
+        .unwrap_or_default()
+}
+
+/// Summarise an embed as its title and / or URL
+fn embed_summary(embed: &revolt_models::v0::Embed) -> Option<String> {
+    let value = serde_json::to_value(embed).ok()?;
+    let fields: Vec<&str> = ["title", "url"]
+        .iter()
+        .filter_map(|key| value[key].as_str())
+        .collect();
+
+    if fields.is_empty() {
+        None
+    } else {
+        Some(fields.join(" - "))
+    }
+}
+
+/// Escape text for inclusion in an HTML document
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::StreamExt;
+    use iso8601_timestamp::Timestamp;
+    use revolt_models::v0::{ExportFormat, ExportStatus};
+    use revolt_result::ErrorType;
+    use ulid::Ulid;
+
+    use super::escape_html;
+    use crate::{ChannelExport, ChannelExportPart, Message, User};
+
+    fn export(format: ExportFormat, status: ExportStatus) -> ChannelExport {
+        ChannelExport {
+            id: Ulid::new().to_string(),
+            channel: Ulid::new().to_string(),
+            user: Ulid::new().to_string(),
+            format,
+            status,
+            processed: 0,
+            completed_at: None,
+        }
+    }
+
+    #[test]
+    fn escape() {
+        assert_eq!(
+            escape_html("<a href=\"#\">Tom & Jerry's</a>"),
+            "&lt;a href=&quot;#&quot;&gt;Tom &amp; Jerry&#39;s&lt;/a&gt;"
+        );
+    }
+
+    #[async_std::test]
+    async fn render_page() {
+        database_test!(|db| async move {
+            let user = User::create(&db, "User".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let messages = vec![Message {
+                id: Ulid::new().to_string(),
+                author: user.id.clone(),
+                content: Some("<b>Hello</b>".to_string()),
+                ..Default::default()
+            }];
+
+            let name = format!("{}#{}", user.username, user.discriminator);
+
+            let html = export(ExportFormat::Html, ExportStatus::Running)
+                .render_page(&db, &messages)
+                .await
+                .unwrap();
+
+            assert!(html.contains("&lt;b&gt;Hello&lt;/b&gt;"));
+            assert!(html.contains(&escape_html(&name)));
+
+            let text = export(ExportFormat::PlainText, ExportStatus::Running)
+                .render_page(&db, &messages)
+                .await
+                .unwrap();
+
+            assert!(text.ends_with(&format!("{name}: <b>Hello</b>")));
+
+            let json = export(ExportFormat::Json, ExportStatus::Running)
+                .render_page(&db, &messages)
+                .await
+                .unwrap();
+
+            let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+            assert_eq!(value["content"], "<b>Hello</b>");
+            assert_eq!(value["author"]["id"], user.id);
+            assert_eq!(value["author"]["name"], name);
+        });
+    }
+
+    #[async_std::test]
+    async fn assemble() {
+        database_test!(|db| async move {
+            let pending = export(ExportFormat::Json, ExportStatus::Pending);
+            let error = pending.assemble(db.clone(), "Title").err().unwrap();
+            assert_eq!(error.error_type, ErrorType::InvalidOperation);
+
+            let mut completed = export(ExportFormat::Json, ExportStatus::Completed);
+            completed.completed_at = Some(Timestamp::now_utc());
+
+            // Empty parts are skipped without leaving a dangling separator
+            for (index, data) in ["{\"n\":1}", "", "{\"n\":2}"].into_iter().enumerate() {
+                db.insert_channel_export_part(&ChannelExportPart {
+                    id: Ulid::new().to_string(),
+                    export: completed.id.clone(),
+                    index: index as u32,
+                    data: data.to_string(),
+                })
+                .await
+                .unwrap();
+            }
+
+            let archive = completed
+                .assemble(db.clone(), "Title")
+                .unwrap()
+                .collect::<Vec<String>>()
+                .await
+                .concat();
+
+            let value: serde_json::Value = serde_json::from_str(&archive).unwrap();
+            assert_eq!(value["title"], "Title");
+            assert_eq!(value["channel"], completed.channel);
+            assert_eq!(
+                value["messages"],
+                serde_json::json!([{ "n": 1 }, { "n": 2 }])
+            );
+
+            let html = export(ExportFormat::Html, ExportStatus::Completed)
+                .assemble(db.clone(), "<Title>")
+                .unwrap()
+                .collect::<Vec<String>>()
+                .await
+                .concat();
+
+            assert!(html.starts_with("<!DOCTYPE html>"));
+            assert!(html.contains("<h1>&lt;Title&gt;</h1>"));
+            assert!(html.ends_with("</body></html>"));
+
+            // Once parts are pruned nothing is left to download
+            db.delete_channel_export_parts(&completed.id).await.unwrap();
+            assert!(db
+                .fetch_channel_export_part(&completed.id, 0)
+                .await
+                .is_err());
+        });
+    }
+
+    #[async_std::test]
+    async fn limit_unfinished_exports() {
+        database_test!(|db| async move {
+            let user = Ulid::new().to_string();
+            let channel = Ulid::new().to_string();
+
+            ChannelExport::create(&db, channel.clone(), user.clone(), ExportFormat::Json)
+                .await
+                .unwrap();
+
+            let error = ChannelExport::create(&db, channel, user, ExportFormat::Json)
+                .await
+                .unwrap_err();
+
+            assert_eq!(
+                error.error_type,
+                ErrorType::TooManyPendingExports { max: 1 }
+            );
+        });
+    }
+}
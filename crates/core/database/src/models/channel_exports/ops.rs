@@ -0,0 +1,43 @@
+use revolt_result::Result;
+
+use crate::{ChannelExport, ChannelExportPart, PartialChannelExport};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractChannelExports: Sync + Send {
+    /// Insert a new channel export into the database
+    async fn insert_channel_export(&self, export: &ChannelExport) -> Result<()>;
+
+    /// Fetch a channel export by its id
+    async fn fetch_channel_export(&self, id: &str) -> Result<ChannelExport>;
+
+    /// Update a channel export with new information
+    async fn update_channel_export(&self, id: &str, partial: &PartialChannelExport) -> Result<()>;
+
+    /// Insert a rendered part of a channel export
+    async fn insert_channel_export_part(&self, part: &ChannelExportPart) -> Result<()>;
+
+    /// Fetch a single rendered part of a channel export by its position
+    async fn fetch_channel_export_part(
+        &self,
+        export: &str,
+        index: u32,
+    ) -> Result<ChannelExportPart>;
+
+    /// Fetch all exports which are still pending or running, optionally only those of a user
+    async fn fetch_unfinished_channel_exports(
+        &self,
+        user: Option<&str>,
+    ) -> Result<Vec<ChannelExport>>;
+
+    /// Fetch all finished exports created before the given id
+    async fn fetch_stale_channel_exports(&self, before: &str) -> Result<Vec<ChannelExport>>;
+
+    /// Delete a channel export
+    async fn delete_channel_export(&self, id: &str) -> Result<()>;
+
+    /// Delete all rendered parts of a channel export
+    async fn delete_channel_export_parts(&self, export: &str) -> Result<()>;
+}
@@ -0,0 +1,105 @@
+use bson::Document;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{ChannelExport, ChannelExportPart, PartialChannelExport};
+
+use super::AbstractChannelExports;
+
+static COL: &str = "channel_exports";
+static PARTS_COL: &str = "channel_export_parts";
+
+#[async_trait]
+impl AbstractChannelExports for MongoDb {
+    /// Insert a new channel export into the database
+    async fn insert_channel_export(&self, export: &ChannelExport) -> Result<()> {
+        query!(self, insert_one, COL, &export).map(|_| ())
+    }
+
+    /// Fetch a channel export by its id
+    async fn fetch_channel_export(&self, id: &str) -> Result<ChannelExport> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Update a channel export with new information
+    async fn update_channel_export(&self, id: &str, partial: &PartialChannelExport) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+
+    /// Insert a rendered part of a channel export
+    async fn insert_channel_export_part(&self, part: &ChannelExportPart) -> Result<()> {
+        query!(self, insert_one, PARTS_COL, &part).map(|_| ())
+    }
+
+    /// Fetch a single rendered part of a channel export by its position
+    async fn fetch_channel_export_part(
+        &self,
+        export: &str,
+        index: u32,
+    ) -> Result<ChannelExportPart> {
+        query!(
+            self,
+            find_one,
+            PARTS_COL,
+            doc! {
+                "export": export,
+                "index": index
+            }
+        )?
+        .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all exports which are still pending or running, optionally only those of a user
+    async fn fetch_unfinished_channel_exports(
+        &self,
+        user: Option<&str>,
+    ) -> Result<Vec<ChannelExport>> {
+        let mut filter = doc! {
+            "status": {
+                "$in": ["Pending", "Running"]
+            }
+        };
+
+        if let Some(user) = user {
+            filter.insert("user", user);
+        }
+
+        query!(self, find, COL, filter)
+    }
+
+    /// Fetch all finished exports created before the given id
+    async fn fetch_stale_channel_exports(&self, before: &str) -> Result<Vec<ChannelExport>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "_id": {
+                    "$lt": before
+                },
+                "status": {
+                    "$in": ["Completed", "Failed"]
+                }
+            }
+        )
+    }
+
+    /// Delete a channel export
+    async fn delete_channel_export(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+
+    /// Delete all rendered parts of a channel export
+    async fn delete_channel_export_parts(&self, export: &str) -> Result<()> {
+        self.col::<Document>(PARTS_COL)
+            .delete_many(
+                doc! {
+                    "export": export
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", PARTS_COL))
+    }
+}
@@ -0,0 +1,118 @@
+use revolt_models::v0::ExportStatus;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{ChannelExport, ChannelExportPart, PartialChannelExport};
+
+use super::AbstractChannelExports;
+
+#[async_trait]
+impl AbstractChannelExports for ReferenceDb {
+    /// Insert a new channel export into the database
+    async fn insert_channel_export(&self, export: &ChannelExport) -> Result<()> {
+        let mut channel_exports = self.channel_exports.lock().await;
+        if channel_exports.contains_key(&export.id) {
+            Err(create_database_error!("insert", "channel_export"))
+        } else {
+            channel_exports.insert(export.id.to_string(), export.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a channel export by its id
+    async fn fetch_channel_export(&self, id: &str) -> Result<ChannelExport> {
+        let channel_exports = self.channel_exports.lock().await;
+        channel_exports
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Update a channel export with new information
+    async fn update_channel_export(&self, id: &str, partial: &PartialChannelExport) -> Result<()> {
+        let mut channel_exports = self.channel_exports.lock().await;
+        if let Some(export) = channel_exports.get_mut(id) {
+            export.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Insert a rendered part of a channel export
+    async fn insert_channel_export_part(&self, part: &ChannelExportPart) -> Result<()> {
+        let mut channel_export_parts = self.channel_export_parts.lock().await;
+        if channel_export_parts.contains_key(&part.id) {
+            Err(create_database_error!("insert", "channel_export_part"))
+        } else {
+            channel_export_parts.insert(part.id.to_string(), part.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a single rendered part of a channel export by its position
+    async fn fetch_channel_export_part(
+        &self,
+        export: &str,
+        index: u32,
+    ) -> Result<ChannelExportPart> {
+        let channel_export_parts = self.channel_export_parts.lock().await;
+        channel_export_parts
+            .values()
+            .find(|part| part.export == export && part.index == index)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all exports which are still pending or running, optionally only those of a user
+    async fn fetch_unfinished_channel_exports(
+        &self,
+        user: Option<&str>,
+    ) -> Result<Vec<ChannelExport>> {
+        let channel_exports = self.channel_exports.lock().await;
+        Ok(channel_exports
+            .values()
+            .filter(|export| {
+                matches!(export.status, ExportStatus::Pending | ExportStatus::Running)
+                    && user.map_or(true, |user| export.user == user)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch all finished exports created before the given id
+    async fn fetch_stale_channel_exports(&self, before: &str) -> Result<Vec<ChannelExport>> {
+        let channel_exports = self.channel_exports.lock().await;
+        Ok(channel_exports
+            .values()
+            .filter(|export| {
+                export.id.as_str() < before
+                    && matches!(
+                        export.status,
+                        ExportStatus::Completed | ExportStatus::Failed
+                    )
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Delete a channel export
+    async fn delete_channel_export(&self, id: &str) -> Result<()> {
+        let mut channel_exports = self.channel_exports.lock().await;
+        if channel_exports.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete all rendered parts of a channel export
+    async fn delete_channel_export_parts(&self, export: &str) -> Result<()> {
+        self.channel_export_parts
+            .lock()
+            .await
+            .retain(|_, part| part.export != export);
+
+        Ok(())
+    }
+}
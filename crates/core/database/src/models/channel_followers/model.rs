@@ -0,0 +1,259 @@
+use revolt_permissions::DEFAULT_WEBHOOK_PERMISSIONS;
+use revolt_result::{create_error, Result};
+use ulid::Ulid;
+
+use crate::{Channel, Database, Server, Webhook};
+
+auto_derived!(
+    /// Subscription delivering messages published in an announcement channel to another channel
+    pub struct ChannelFollower {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the announcement channel being followed
+        pub channel: String,
+        /// Id of the channel receiving published messages
+        pub target: String,
+        /// Id of the server the receiving channel belongs to
+        pub target_server: String,
+        /// Id of the webhook used to deliver messages
+        pub webhook: String,
+        /// Id of the user who followed the channel
+        pub creator: String,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ChannelFollower {
+    /// Follow an announcement channel from a text channel in another server
+    ///
+    /// Messages are delivered through a webhook without a token,
+    /// so it can only ever be used for published messages.
+    pub async fn create(
+        db: &Database,
+        source: &Channel,
+        server: &Server,
+        target: &Channel,
+        creator_id: String,
+    ) -> Result<ChannelFollower> {
+        if !matches!(
+            source,
+            Channel::TextChannel {
+                announcement: true,
+                ..
+            }
+        ) {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let target_server = if let Channel::TextChannel { server, .. } = target {
+            server.clone()
+        } else {
+            return Err(create_error!(InvalidOperation));
+        };
+
+        if target_server == server.id {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let target_id = target.id();
+        if db
+            .fetch_channel_followers(&source.id())
+            .await?
+            .iter()
+            .any(|follower| follower.target == target_id)
+        {
+            return Err(create_error!(NoEffect));
+        }
+
+        let webhook = Webhook {
+            id: Ulid::new().to_string(),
+            name: server.name.chars().take(32).collect(),
+            avatar: None,
+            channel_id: target_id.clone(),
+            permissions: *DEFAULT_WEBHOOK_PERMISSIONS,
+            token: None,
+        };
+
+        webhook.create(db).await?;
+
+        let follower = ChannelFollower {
+            id: Ulid::new().to_string(),
+            channel: source.id(),
+            target: target_id,
+            target_server,
+            webhook: webhook.id,
+            creator: creator_id,
+        };
+
+        db.insert_channel_follower(&follower).await?;
+        Ok(follower)
+    }
+
+    /// Stop following and remove the webhook used for delivery
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        if let Ok(webhook) = db.fetch_webhook(&self.webhook).await {
+            webhook.delete(db).await?;
+        }
+
+        db.delete_channel_follower(&self.id).await
+    }
+
+    /// Remove every follower of the given announcement channels
+    pub async fn delete_for_channels(db: &Database, channels: &[String]) -> Result<()> {
+        for channel in channels {
+            for follower in db.fetch_channel_followers(channel).await? {
+                follower.delete(db).await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use revolt_models::v0::{self, DataMessageSend};
+    use revolt_result::ErrorType;
+    use ulid::Ulid;
+
+    use crate::{
+        util::idempotency::IdempotencyKey, Channel, ChannelFollower, Message, Server, User,
+    };
+
+    fn text_channel(server: &Server, announcement: bool) -> Channel {
+        Channel::TextChannel {
+            id: Ulid::new().to_string(),
+            server: server.id.clone(),
+            name: "Channel".to_string(),
+            description: None,
+            icon: None,
+            last_message_id: None,
+            default_permissions: None,
+            role_permissions: HashMap::new(),
+            nsfw: false,
+            slowmode_seconds: 0,
+            announcement,
+        }
+    }
+
+    fn server(name: &str) -> Server {
+        Server {
+            id: Ulid::new().to_string(),
+            owner: "owner".to_string(),
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[async_std::test]
+    async fn follow() {
+        database_test!(|db| async move {
+            let source_server = server("Source");
+            let target_server = server("Target");
+            source_server.create(&db).await.unwrap();
+            target_server.create(&db).await.unwrap();
+
+            let source = text_channel(&source_server, true);
+            let plain = text_channel(&source_server, false);
+            let target = text_channel(&target_server, false);
+            for channel in [&source, &plain, &target] {
+                channel.create(&db).await.unwrap();
+            }
+
+            // Only announcement channels can be followed, and only from another server
+            let error =
+                ChannelFollower::create(&db, &plain, &source_server, &target, "owner".to_string())
+                    .await
+                    .unwrap_err();
+            assert_eq!(error.error_type, ErrorType::InvalidOperation);
+
+            let error =
+                ChannelFollower::create(&db, &source, &source_server, &plain, "owner".to_string())
+                    .await
+                    .unwrap_err();
+            assert_eq!(error.error_type, ErrorType::InvalidOperation);
+
+            let follower =
+                ChannelFollower::create(&db, &source, &source_server, &target, "owner".to_string())
+                    .await
+                    .unwrap();
+
+            let error =
+                ChannelFollower::create(&db, &source, &source_server, &target, "owner".to_string())
+                    .await
+                    .unwrap_err();
+            assert_eq!(error.error_type, ErrorType::NoEffect);
+
+            // Deleting the announcement channel takes its followers and their webhooks along
+            source.delete(&db).await.unwrap();
+            assert!(db
+                .fetch_channel_followers(&source.id())
+                .await
+                .unwrap()
+                .is_empty());
+            assert!(db.fetch_webhook(&follower.webhook).await.is_err());
+        });
+    }
+
+    #[async_std::test]
+    async fn publish_drops_missing_followers() {
+        database_test!(|db| async move {
+            let user = User::create(&db, "User".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let source_server = server("Source");
+            let target_server = server("Target");
+            source_server.create(&db).await.unwrap();
+            target_server.create(&db).await.unwrap();
+
+            let source = text_channel(&source_server, true);
+            let kept = text_channel(&target_server, false);
+            let removed = text_channel(&target_server, false);
+            for channel in [&source, &kept, &removed] {
+                channel.create(&db).await.unwrap();
+            }
+
+            for target in [&kept, &removed] {
+                ChannelFollower::create(&db, &source, &source_server, target, user.id.clone())
+                    .await
+                    .unwrap();
+            }
+
+            db.delete_channel(&removed).await.unwrap();
+
+            let author: v0::User = user.clone().into(None).await;
+            let mut message = Message::create_from_api(
+                &db,
+                source.clone(),
+                DataMessageSend {
+                    nonce: None,
+                    content: Some("Announcement".to_string()),
+                    attachments: None,
+                    replies: None,
+                    embeds: None,
+                    masquerade: None,
+                    interactions: None,
+                    components: None,
+                    session_id: None,
+                    is_stream: None,
+                    ephemeral: None,
+                },
+                v0::MessageAuthor::User(&author),
+                IdempotencyKey::unchecked_from_string(Ulid::new().to_string()),
+                false,
+            )
+            .await
+            .unwrap();
+
+            message.publish(&db, &source_server).await.unwrap();
+
+            let followers = db.fetch_channel_followers(&source.id()).await.unwrap();
+            assert_eq!(followers.len(), 1);
+            assert_eq!(followers[0].target, kept.id());
+        });
+    }
+}
@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::ChannelFollower;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractChannelFollowers: Sync + Send {
+    /// Insert a new channel follower into the database
+    async fn insert_channel_follower(&self, follower: &ChannelFollower) -> Result<()>;
+
+    /// Fetch a channel follower by its id
+    async fn fetch_channel_follower(&self, id: &str) -> Result<ChannelFollower>;
+
+    /// Fetch all followers of an announcement channel
+    async fn fetch_channel_followers(&self, channel_id: &str) -> Result<Vec<ChannelFollower>>;
+
+    /// Delete a channel follower by its id
+    async fn delete_channel_follower(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,38 @@
+use revolt_result::Result;
+
+use crate::ChannelFollower;
+use crate::MongoDb;
+
+use super::AbstractChannelFollowers;
+
+static COL: &str = "channel_followers";
+
+#[async_trait]
+impl AbstractChannelFollowers for MongoDb {
+    /// Insert a new channel follower into the database
+    async fn insert_channel_follower(&self, follower: &ChannelFollower) -> Result<()> {
+        query!(self, insert_one, COL, &follower).map(|_| ())
+    }
+
+    /// Fetch a channel follower by its id
+    async fn fetch_channel_follower(&self, id: &str) -> Result<ChannelFollower> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all followers of an announcement channel
+    async fn fetch_channel_followers(&self, channel_id: &str) -> Result<Vec<ChannelFollower>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "channel": channel_id
+            }
+        )
+    }
+
+    /// Delete a channel follower by its id
+    async fn delete_channel_follower(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
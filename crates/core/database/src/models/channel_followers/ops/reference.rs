@@ -0,0 +1,49 @@
+use revolt_result::Result;
+
+use crate::ChannelFollower;
+use crate::ReferenceDb;
+
+use super::AbstractChannelFollowers;
+
+#[async_trait]
+impl AbstractChannelFollowers for ReferenceDb {
+    /// Insert a new channel follower into the database
+    async fn insert_channel_follower(&self, follower: &ChannelFollower) -> Result<()> {
+        let mut channel_followers = self.channel_followers.lock().await;
+        if channel_followers.contains_key(&follower.id) {
+            Err(create_database_error!("insert", "channel_follower"))
+        } else {
+            channel_followers.insert(follower.id.to_string(), follower.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a channel follower by its id
+    async fn fetch_channel_follower(&self, id: &str) -> Result<ChannelFollower> {
+        let channel_followers = self.channel_followers.lock().await;
+        channel_followers
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all followers of an announcement channel
+    async fn fetch_channel_followers(&self, channel_id: &str) -> Result<Vec<ChannelFollower>> {
+        let channel_followers = self.channel_followers.lock().await;
+        Ok(channel_followers
+            .values()
+            .filter(|follower| follower.channel == channel_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Delete a channel follower by its id
+    async fn delete_channel_follower(&self, id: &str) -> Result<()> {
+        let mut channel_followers = self.channel_followers.lock().await;
+        if channel_followers.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
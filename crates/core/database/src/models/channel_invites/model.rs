@@ -1,6 +1,11 @@
+use std::time::{Duration, SystemTime};
+
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::DataCreateInvite;
 use revolt_result::{create_error, Result};
+use ulid::Ulid;
 
-use crate::{Channel, Database};
+use crate::{Channel, Database, RatelimitEvent, RatelimitEventType, Server};
 
 static ALPHABET: [char; 54] = [
     '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'J',
@@ -8,6 +13,29 @@ static ALPHABET: [char; 54] = [
     'g', 'h', 'j', 'k', 'm', 'n', 'p', 'q', 'r', 's', 't', 'v', 'w', 'x', 'y', 'z',
 ];
 
+/// Codes which may not be claimed as a vanity invite
+static RESERVED_VANITY_CODES: [&str; 16] = [
+    "admin",
+    "api",
+    "app",
+    "bot",
+    "discover",
+    "help",
+    "invite",
+    "login",
+    "moderator",
+    "official",
+    "revolt",
+    "security",
+    "settings",
+    "staff",
+    "support",
+    "system",
+];
+
+/// How many times a server may change its vanity invite within a day
+const VANITY_CHANGES_PER_DAY: usize = 2;
+
 auto_derived!(
     /// Invite
     #[serde(tag = "type")]
@@ -23,6 +51,25 @@ auto_derived!(
             creator: String,
             /// Id of the server channel this invite points to
             channel: String,
+            /// Time at which this invite stops working
+            #[serde(skip_serializing_if = "Option::is_none")]
+            expires_at: Option<Timestamp>,
+            /// Maximum number of times this invite may be used
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_uses: Option<u32>,
+            /// Number of times this invite has been used
+            #[serde(skip_serializing_if = "crate::if_zero_u32", default)]
+            uses: u32,
+            /// Whether members who join through this invite are removed
+            /// once they go offline, unless they have since been given a role
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            temporary: bool,
+            /// Roles given to members who join through this invite
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            roles: Vec<String>,
+            /// Whether this is the server's custom vanity invite
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            vanity: bool,
         },
         /// Invite to a group channel
         Group {
@@ -33,11 +80,33 @@ auto_derived!(
             creator: String,
             /// Id of the group channel this invite points to
             channel: String,
+            /// Time at which this invite stops working
+            #[serde(skip_serializing_if = "Option::is_none")]
+            expires_at: Option<Timestamp>,
+            /// Maximum number of times this invite may be used
+            #[serde(skip_serializing_if = "Option::is_none")]
+            max_uses: Option<u32>,
+            /// Number of times this invite has been used
+            #[serde(skip_serializing_if = "crate::if_zero_u32", default)]
+            uses: u32,
         }, /* User {
                code: String,
                user: String
            } */
     }
+
+    /// Record of a user joining through an invite
+    pub struct InviteUse {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Invite code
+        pub code: String,
+        /// Id of the user who used the invite
+        pub user: String,
+        /// Time at which the invite was used
+        pub used_at: Timestamp,
+    }
 );
 
 #[allow(clippy::disallowed_methods)]
@@ -56,25 +125,78 @@ impl Invite {
         }
     }
 
+    /// Get the time at which this invite stops working
+    pub fn expires_at(&self) -> Option<&Timestamp> {
+        match self {
+            Invite::Server { expires_at, .. } | Invite::Group { expires_at, .. } => {
+                expires_at.as_ref()
+            }
+        }
+    }
+
+    /// Check whether this invite has run its course
+    pub fn is_expired(&self) -> bool {
+        if let Some(expires_at) = self.expires_at() {
+            **expires_at <= *Timestamp::now_utc()
+        } else {
+            false
+        }
+    }
+
+    /// Check whether this invite has been used as many times as it allows
+    pub fn is_used_up(&self) -> bool {
+        match self {
+            Invite::Server { max_uses, uses, .. } | Invite::Group { max_uses, uses, .. } => {
+                max_uses.is_some_and(|max_uses| *uses >= max_uses)
+            }
+        }
+    }
+
     /// Create a new invite from given information
     pub async fn create_channel_invite(
         db: &Database,
         creator_id: String,
         target: &Channel,
+        options: DataCreateInvite,
     ) -> Result<Invite> {
         let code = nanoid::nanoid!(8, &ALPHABET);
+        let expires_at = options
+            .max_age
+            .map(|max_age| Timestamp::from(SystemTime::now() + Duration::from_secs(max_age)));
+
         let invite = match &target {
-            Channel::Group { id, .. } => Ok(Invite::Group {
-                code,
-                creator: creator_id.clone(),
-                channel: id.clone(),
-            }),
-            Channel::TextChannel { id, server, .. } | Channel::VoiceChannel { id, server, .. } => {
+            Channel::Group { id, .. } => {
+                if options.temporary || !options.roles.is_empty() {
+                    return Err(create_error!(InvalidOperation));
+                }
+
+                Ok(Invite::Group {
+                    code,
+                    creator: creator_id.clone(),
+                    channel: id.clone(),
+                    expires_at,
+                    max_uses: options.max_uses,
+                    uses: 0,
+                })
+            }
+            Channel::TextChannel { id, server, .. }
+            | Channel::VoiceChannel { id, server, .. }
+            | Channel::ForumChannel { id, server, .. } => {
+                if options.temporary && !options.roles.is_empty() {
+                    return Err(create_error!(InvalidOperation));
+                }
+
                 Ok(Invite::Server {
                     code,
                     creator: creator_id.clone(),
                     server: server.clone(),
                     channel: id.clone(),
+                    expires_at,
+                    max_uses: options.max_uses,
+                    uses: 0,
+                    temporary: options.temporary,
+                    roles: options.roles,
+                    vanity: false,
                 })
             }
             _ => Err(create_error!(InvalidOperation)),
@@ -86,7 +208,12 @@ impl Invite {
 
     /// Resolve an invite by its ID or by a public server ID
     pub async fn find(db: &Database, code: &str) -> Result<Invite> {
-        if let Ok(invite) = db.fetch_invite(code).await {
+        if let Some(invite) = db
+            .fetch_invite(code)
+            .await
+            .ok()
+            .filter(|invite| !invite.is_expired())
+        {
             return Ok(invite);
         } else if let Ok(server) = db.fetch_server(code).await {
             if server.discoverable {
@@ -96,6 +223,12 @@ impl Invite {
                         server: server.id,
                         creator: server.owner,
                         channel,
+                        expires_at: None,
+                        max_uses: None,
+                        uses: 0,
+                        temporary: false,
+                        roles: vec![],
+                        vanity: false,
                     });
                 }
             }
@@ -103,4 +236,190 @@ impl Invite {
 
         Err(create_error!(NotFound))
     }
+
+    /// Fetch the vanity invite of a server
+    pub async fn fetch_vanity(db: &Database, server_id: &str) -> Result<Invite> {
+        db.fetch_invites_for_server(server_id)
+            .await?
+            .into_iter()
+            .find(|invite| matches!(invite, Invite::Server { vanity: true, .. }))
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Claim a vanity invite code for a server, replacing any it had before
+    pub async fn set_vanity(
+        db: &Database,
+        server: &Server,
+        creator_id: String,
+        code: String,
+        channel: String,
+    ) -> Result<Invite> {
+        if RESERVED_VANITY_CODES.contains(&code.as_str()) {
+            return Err(create_error!(VanityCodeTaken));
+        }
+
+        if !server.channels.contains(&channel) {
+            return Err(create_error!(UnknownChannel));
+        }
+
+        let previous = Invite::fetch_vanity(db, &server.id).await.ok();
+        if let Some(Invite::Server {
+            code: previous_code,
+            channel: previous_channel,
+            ..
+        }) = &previous
+        {
+            if previous_code == &code && previous_channel == &channel {
+                return Err(create_error!(NoEffect));
+            }
+        }
+
+        if previous.as_ref().map(|invite| invite.code()) != Some(code.as_str())
+            && db.fetch_invite(&code).await.is_ok()
+        {
+            return Err(create_error!(VanityCodeTaken));
+        }
+
+        if db
+            .has_ratelimited(
+                &server.id,
+                RatelimitEventType::VanityInviteChange,
+                Duration::from_secs(60 * 60 * 24),
+                VANITY_CHANGES_PER_DAY,
+            )
+            .await?
+        {
+            return Err(create_error!(VanityCodeRatelimited));
+        }
+
+        let invite = Invite::Server {
+            code,
+            server: server.id.clone(),
+            creator: creator_id,
+            channel,
+            expires_at: None,
+            max_uses: None,
+            uses: 0,
+            temporary: false,
+            roles: vec![],
+            vanity: true,
+        };
+
+        if let Some(previous) = previous {
+            db.delete_invite(previous.code()).await?;
+        }
+
+        db.insert_invite(&invite)
+            .await
+            .map_err(|_| create_error!(VanityCodeTaken))?;
+
+        RatelimitEvent::create(
+            db,
+            server.id.clone(),
+            RatelimitEventType::VanityInviteChange,
+        )
+        .await?;
+
+        Ok(invite)
+    }
+
+    /// Record a user joining through this invite
+    ///
+    /// Fails if the invite has expired or has been used up.
+    pub async fn record_use(&self, db: &Database, user_id: &str) -> Result<()> {
+        if self.is_expired() {
+            return Err(create_error!(NotFound));
+        }
+
+        let max_uses = match self {
+            Invite::Server { max_uses, .. } | Invite::Group { max_uses, .. } => *max_uses,
+        };
+
+        db.increment_invite_uses(self.code(), max_uses).await?;
+        db.insert_invite_use(&InviteUse {
+            id: Ulid::new().to_string(),
+            code: self.code().to_string(),
+            user: user_id.to_string(),
+            used_at: Timestamp::now_utc(),
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_result::{ErrorType, Result};
+    use ulid::Ulid;
+
+    use crate::{Database, Invite, Server};
+
+    async fn set(db: &Database, server: &Server, code: &str, channel: &str) -> Result<Invite> {
+        Invite::set_vanity(
+            db,
+            server,
+            "owner".to_string(),
+            code.to_string(),
+            channel.to_string(),
+        )
+        .await
+    }
+
+    #[async_std::test]
+    async fn set_vanity() {
+        database_test!(|db| async move {
+            let server = Server {
+                id: Ulid::new().to_string(),
+                owner: "owner".to_string(),
+                name: "Server".to_string(),
+                channels: vec!["general".to_string(), "rules".to_string()],
+                ..Default::default()
+            };
+
+            server.create(&db).await.unwrap();
+
+            let other = Server {
+                id: Ulid::new().to_string(),
+                owner: "owner".to_string(),
+                name: "Other".to_string(),
+                channels: vec!["lobby".to_string()],
+                ..Default::default()
+            };
+
+            other.create(&db).await.unwrap();
+
+            let error = set(&db, &server, "admin", "general").await.unwrap_err();
+            assert_eq!(error.error_type, ErrorType::VanityCodeTaken);
+
+            let error = set(&db, &server, "community", "lobby").await.unwrap_err();
+            assert_eq!(error.error_type, ErrorType::UnknownChannel);
+
+            set(&db, &server, "community", "general").await.unwrap();
+            assert_eq!(
+                Invite::fetch_vanity(&db, &server.id).await.unwrap().code(),
+                "community"
+            );
+
+            let error = set(&db, &server, "community", "general").await.unwrap_err();
+            assert_eq!(error.error_type, ErrorType::NoEffect);
+
+            // Codes belong to one server at a time
+            let error = set(&db, &other, "community", "lobby").await.unwrap_err();
+            assert_eq!(error.error_type, ErrorType::VanityCodeTaken);
+
+            // Moving the vanity invite to another channel keeps the code
+            set(&db, &server, "community", "rules").await.unwrap();
+            assert_eq!(
+                db.fetch_invites_for_server(&server.id).await.unwrap().len(),
+                1
+            );
+
+            // Servers may only change their code a couple of times a day
+            let error = set(&db, &server, "renamed", "general").await.unwrap_err();
+            assert_eq!(error.error_type, ErrorType::VanityCodeRatelimited);
+
+            // The old code keeps working until it is replaced
+            assert!(db.fetch_invite("community").await.is_ok());
+            assert!(db.fetch_invite("renamed").await.is_err());
+        });
+    }
 }
@@ -1,6 +1,6 @@
 use revolt_result::Result;
 
-use crate::Invite;
+use crate::{Invite, InviteUse};
 
 mod mongodb;
 mod reference;
@@ -16,6 +16,18 @@ pub trait AbstractChannelInvites: Sync + Send {
     /// Fetch all invites for a server
     async fn fetch_invites_for_server(&self, server_id: &str) -> Result<Vec<Invite>>;
 
+    /// Fetch all invites which have expired
+    async fn fetch_expired_invites(&self) -> Result<Vec<Invite>>;
+
+    /// Count a use of an invite, failing if it has reached its maximum number of uses
+    async fn increment_invite_uses(&self, code: &str, max_uses: Option<u32>) -> Result<()>;
+
+    /// Insert a record of an invite being used
+    async fn insert_invite_use(&self, invite_use: &InviteUse) -> Result<()>;
+
+    /// Fetch everyone who used an invite
+    async fn fetch_invite_uses(&self, code: &str) -> Result<Vec<InviteUse>>;
+
     /// Delete an invite by its id
     async fn delete_invite(&self, code: &str) -> Result<()>;
 }
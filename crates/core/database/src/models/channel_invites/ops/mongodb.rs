@@ -1,12 +1,15 @@
+use bson::to_bson;
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
-use crate::Invite;
 use crate::MongoDb;
+use crate::{Invite, InviteUse};
 
 use super::AbstractChannelInvites;
 
 static COL: &str = "channel_invites";
+static USES_COL: &str = "channel_invite_uses";
 
 #[async_trait]
 impl AbstractChannelInvites for MongoDb {
@@ -43,8 +46,81 @@ impl AbstractChannelInvites for MongoDb {
             .await)
     }
 
+    /// Fetch all invites which have expired
+    async fn fetch_expired_invites(&self) -> Result<Vec<Invite>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "expires_at": {
+                    "$lte": to_bson(&Timestamp::now_utc())
+                        .map_err(|_| create_database_error!("to_bson", "timestamp"))?
+                }
+            }
+        )
+    }
+
+    /// Count a use of an invite, failing if it has reached its maximum number of uses
+    async fn increment_invite_uses(&self, code: &str, max_uses: Option<u32>) -> Result<()> {
+        let mut filter = doc! {
+            "_id": code
+        };
+
+        if let Some(max_uses) = max_uses {
+            filter.insert("uses", doc! { "$lt": max_uses as i64 });
+        }
+
+        let result = self
+            .col::<Invite>(COL)
+            .update_one(
+                filter,
+                doc! {
+                    "$inc": {
+                        "uses": 1_i32
+                    }
+                },
+                None,
+            )
+            .await
+            .map_err(|_| create_database_error!("update_one", COL))?;
+
+        if max_uses.is_some() && result.matched_count == 0 {
+            Err(create_error!(NotFound))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Insert a record of an invite being used
+    async fn insert_invite_use(&self, invite_use: &InviteUse) -> Result<()> {
+        query!(self, insert_one, USES_COL, &invite_use).map(|_| ())
+    }
+
+    /// Fetch everyone who used an invite
+    async fn fetch_invite_uses(&self, code: &str) -> Result<Vec<InviteUse>> {
+        query!(
+            self,
+            find,
+            USES_COL,
+            doc! {
+                "code": code
+            }
+        )
+    }
+
     /// Delete an invite by its code
     async fn delete_invite(&self, code: &str) -> Result<()> {
-        query!(self, delete_one_by_id, COL, code).map(|_| ())
+        query!(self, delete_one_by_id, COL, code)?;
+        self.col::<InviteUse>(USES_COL)
+            .delete_many(
+                doc! {
+                    "code": code
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", USES_COL))
     }
 }
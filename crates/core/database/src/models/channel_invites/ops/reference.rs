@@ -1,7 +1,7 @@
 use revolt_result::Result;
 
-use crate::Invite;
 use crate::ReferenceDb;
+use crate::{Invite, InviteUse};
 
 use super::AbstractChannelInvites;
 
@@ -40,10 +40,65 @@ impl AbstractChannelInvites for ReferenceDb {
             .collect())
     }
 
+    /// Fetch all invites which have expired
+    async fn fetch_expired_invites(&self) -> Result<Vec<Invite>> {
+        let invites = self.channel_invites.lock().await;
+        Ok(invites
+            .values()
+            .filter(|invite| invite.is_expired())
+            .cloned()
+            .collect())
+    }
+
+    /// Count a use of an invite, failing if it has reached its maximum number of uses
+    async fn increment_invite_uses(&self, code: &str, max_uses: Option<u32>) -> Result<()> {
+        let mut invites = self.channel_invites.lock().await;
+        if let Some(Invite::Server { uses, .. } | Invite::Group { uses, .. }) =
+            invites.get_mut(code)
+        {
+            if max_uses.is_some_and(|max_uses| *uses >= max_uses) {
+                return Err(create_error!(NotFound));
+            }
+
+            *uses += 1;
+            Ok(())
+        } else if max_uses.is_some() {
+            Err(create_error!(NotFound))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Insert a record of an invite being used
+    async fn insert_invite_use(&self, invite_use: &InviteUse) -> Result<()> {
+        let mut invite_uses = self.channel_invite_uses.lock().await;
+        if invite_uses.contains_key(&invite_use.id) {
+            Err(create_database_error!("insert", "invite_use"))
+        } else {
+            invite_uses.insert(invite_use.id.to_string(), invite_use.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch everyone who used an invite
+    async fn fetch_invite_uses(&self, code: &str) -> Result<Vec<InviteUse>> {
+        let invite_uses = self.channel_invite_uses.lock().await;
+        Ok(invite_uses
+            .values()
+            .filter(|invite_use| invite_use.code == code)
+            .cloned()
+            .collect())
+    }
+
     /// Delete an invite by its code
     async fn delete_invite(&self, code: &str) -> Result<()> {
         let mut invites = self.channel_invites.lock().await;
         if invites.remove(code).is_some() {
+            self.channel_invite_uses
+                .lock()
+                .await
+                .retain(|_, invite_use| invite_use.code != code);
+
             Ok(())
         } else {
             Err(create_error!(NotFound))
@@ -1,12 +1,19 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime},
+};
 
 use revolt_config::config;
-use revolt_models::v0::{self, MessageAuthor};
+use revolt_models::v0::{self, ForumSortOrder, ForumTag, MessageAuthor, MessageSort};
 use revolt_permissions::OverrideField;
 use revolt_result::Result;
 use serde::{Deserialize, Serialize};
+use ulid::Ulid;
 
-use crate::{events::client::EventV1, Database, File, IntoDocumentPath, SystemMessage, User};
+use crate::{
+    events::client::EventV1, ChannelFollower, Database, File, IntoDocumentPath, Message,
+    MessageFilter, MessageQuery, MessageTimePeriod, SystemMessage, User,
+};
 
 auto_derived!(
     #[serde(tag = "channel_type")]
@@ -32,6 +39,9 @@ auto_derived!(
             /// Id of the last message sent in this channel
             #[serde(skip_serializing_if = "Option::is_none")]
             last_message_id: Option<String>,
+            /// Id of the user who started this conversation, present while it is a message request
+            #[serde(skip_serializing_if = "Option::is_none")]
+            requested_by: Option<String>,
         },
         /// Group channel between 1 or more participants
         Group {
@@ -99,6 +109,14 @@ auto_derived!(
             /// Whether this channel is marked as not safe for work
             #[serde(skip_serializing_if = "crate::if_false", default)]
             nsfw: bool,
+
+            /// Seconds a member has to wait between sending messages
+            #[serde(skip_serializing_if = "crate::if_zero_u32", default)]
+            slowmode_seconds: u32,
+
+            /// Whether messages in this channel can be published to followers
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            announcement: bool,
         },
         /// Voice channel belonging to a server
         VoiceChannel {
@@ -131,6 +149,65 @@ auto_derived!(
             #[serde(skip_serializing_if = "crate::if_false", default)]
             nsfw: bool,
         },
+        /// Forum channel belonging to a server
+        ForumChannel {
+            /// Unique Id
+            #[serde(rename = "_id")]
+            id: String,
+            /// Id of the server this channel belongs to
+            server: String,
+
+            /// Display name of the channel
+            name: String,
+            /// Channel description
+            #[serde(skip_serializing_if = "Option::is_none")]
+            description: Option<String>,
+            /// Custom icon attachment
+            #[serde(skip_serializing_if = "Option::is_none")]
+            icon: Option<File>,
+
+            /// Tags which can be applied to posts
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            available_tags: Vec<ForumTag>,
+            /// Order posts are listed in by default
+            #[serde(default)]
+            default_sort_order: ForumSortOrder,
+
+            /// Default permissions assigned to users in this channel
+            #[serde(skip_serializing_if = "Option::is_none")]
+            default_permissions: Option<OverrideField>,
+            /// Permissions assigned based on role to this channel
+            #[serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )]
+            role_permissions: HashMap<String, OverrideField>,
+
+            /// Whether this channel is marked as not safe for work
+            #[serde(skip_serializing_if = "crate::if_false", default)]
+            nsfw: bool,
+        },
+        /// Post within a forum channel
+        ForumPost {
+            /// Unique Id
+            #[serde(rename = "_id")]
+            id: String,
+            /// Id of the server this post belongs to
+            server: String,
+            /// Id of the forum channel this post belongs to
+            forum: String,
+            /// Id of the user who created this post
+            author: String,
+
+            /// Title of the post
+            name: String,
+            /// Ids of the forum tags applied to this post
+            #[serde(skip_serializing_if = "Vec::is_empty", default)]
+            tags: Vec<String>,
+            /// Id of the last message sent in this post
+            #[serde(skip_serializing_if = "Option::is_none")]
+            last_message_id: Option<String>,
+        },
     }
 );
 
@@ -157,6 +234,28 @@ auto_derived!(
         pub default_permissions: Option<OverrideField>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub last_message_id: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub slowmode_seconds: Option<u32>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub announcement: Option<bool>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub available_tags: Option<Vec<ForumTag>>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_sort_order: Option<ForumSortOrder>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub tags: Option<Vec<String>>,
+    }
+
+    /// Forum Post Query
+    pub struct ForumPostQuery {
+        /// Maximum number of posts to fetch
+        pub limit: i64,
+        /// Post before which posts should be fetched, relative to the sort order
+        pub before: Option<String>,
+        /// Only include posts with this tag
+        pub tag: Option<String>,
+        /// Order to list posts in
+        pub sort: ForumSortOrder,
     }
 
     /// Optional fields on channel object
@@ -164,6 +263,7 @@ auto_derived!(
         Description,
         Icon,
         DefaultPermissions,
+        RequestedBy,
     }
 );
 
@@ -181,7 +281,10 @@ impl Channel {
                     event.clone().private(recipient.clone()).await;
                 }
             }
-            Self::TextChannel { server, .. } | Self::VoiceChannel { server, .. } => {
+            Self::TextChannel { server, .. }
+            | Self::VoiceChannel { server, .. }
+            | Self::ForumChannel { server, .. }
+            | Self::ForumPost { server, .. } => {
                 event.p(server.clone()).await;
             }
         }
@@ -289,6 +392,34 @@ impl Channel {
         matches!(self, Channel::DirectMessage { .. })
     }
 
+    /// Check whether this is a message request awaiting a response from the given user
+    pub fn is_message_request_for(&self, user_id: &str) -> bool {
+        match self {
+            Channel::DirectMessage {
+                recipients,
+                requested_by: Some(requested_by),
+                ..
+            } => requested_by != user_id && recipients.iter().any(|id| id == user_id),
+            _ => false,
+        }
+    }
+
+    /// Accept a message request, turning it into a regular conversation
+    pub async fn accept_message_request(&mut self, db: &Database) -> Result<()> {
+        if !matches!(
+            self,
+            Channel::DirectMessage {
+                requested_by: Some(_),
+                ..
+            }
+        ) {
+            return Err(create_error!(NoEffect));
+        }
+
+        self.update(db, Default::default(), vec![FieldsChannel::RequestedBy])
+            .await
+    }
+
     /// Check whether has a user as a recipient
     pub fn contains_user(&self, user_id: &str) -> bool {
         match self {
@@ -305,6 +436,68 @@ impl Channel {
         }
     }
 
+    /// Throw an error if the given user is still on cooldown in this channel
+    pub async fn throw_if_in_slowmode(&self, db: &Database, user_id: &str) -> Result<()> {
+        if let Channel::TextChannel {
+            id,
+            slowmode_seconds,
+            ..
+        } = self
+        {
+            if *slowmode_seconds == 0 {
+                return Ok(());
+            }
+
+            let period = Duration::from_secs(*slowmode_seconds as u64);
+            let messages = db
+                .fetch_messages(MessageQuery {
+                    limit: Some(1),
+                    filter: MessageFilter {
+                        channel: Some(id.to_string()),
+                        author: Some(user_id.to_string()),
+                        query: None,
+                    },
+                    time_period: MessageTimePeriod::Absolute {
+                        before: None,
+                        after: Some(Ulid::from_datetime(SystemTime::now() - period).to_string()),
+                        sort: Some(MessageSort::Latest),
+                    },
+                })
+                .await?;
+
+            if let Some(message) = messages.first() {
+                let sent_at = Ulid::from_string(&message.id)
+                    .map_err(|_| create_error!(InternalError))?
+                    .datetime();
+
+                let elapsed = SystemTime::now()
+                    .duration_since(sent_at)
+                    .unwrap_or_default();
+
+                return Err(create_error!(InSlowMode {
+                    retry_after: period.saturating_sub(elapsed).as_secs().max(1),
+                }));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Throw an error if the given user is timed out in this channel's server
+    pub async fn throw_if_timed_out(&self, db: &Database, user_id: &str) -> Result<()> {
+        if let Channel::TextChannel { server, .. }
+        | Channel::VoiceChannel { server, .. }
+        | Channel::ForumChannel { server, .. }
+        | Channel::ForumPost { server, .. } = self
+        {
+            if let Ok(member) = db.fetch_member(server, user_id).await {
+                member.throw_if_in_timeout()?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Clone this channel's id
     pub fn id(&self) -> String {
         match self {
@@ -312,7 +505,25 @@ impl Channel {
             | Channel::Group { id, .. }
             | Channel::SavedMessages { id, .. }
             | Channel::TextChannel { id, .. }
-            | Channel::VoiceChannel { id, .. } => id.clone(),
+            | Channel::VoiceChannel { id, .. }
+            | Channel::ForumChannel { id, .. }
+            | Channel::ForumPost { id, .. } => id.clone(),
+        }
+    }
+
+    /// Ensure every given tag exists on this forum
+    pub fn throw_if_unknown_forum_tags(&self, tags: &[String]) -> Result<()> {
+        if let Channel::ForumChannel { available_tags, .. } = self {
+            if tags
+                .iter()
+                .all(|tag| available_tags.iter().any(|available| &available.id == tag))
+            {
+                return Ok(());
+            }
+
+            Err(create_error!(InvalidProperty))
+        } else {
+            Err(create_error!(InvalidOperation))
         }
     }
 
@@ -335,6 +546,12 @@ impl Channel {
                 server,
                 role_permissions,
                 ..
+            }
+            | Channel::ForumChannel {
+                id,
+                server,
+                role_permissions,
+                ..
             } => {
                 db.set_channel_role_permission(id, role_id, permissions)
                     .await?;
@@ -381,7 +598,10 @@ impl Channel {
             clear: remove.into_iter().map(|v| v.into()).collect(),
         }
         .p(match self {
-            Self::TextChannel { server, .. } | Self::VoiceChannel { server, .. } => server.clone(),
+            Self::TextChannel { server, .. }
+            | Self::VoiceChannel { server, .. }
+            | Self::ForumChannel { server, .. }
+            | Self::ForumPost { server, .. } => server.clone(),
             _ => id,
         })
         .await;
@@ -395,7 +615,8 @@ impl Channel {
             FieldsChannel::Description => match self {
                 Self::Group { description, .. }
                 | Self::TextChannel { description, .. }
-                | Self::VoiceChannel { description, .. } => {
+                | Self::VoiceChannel { description, .. }
+                | Self::ForumChannel { description, .. } => {
                     description.take();
                 }
                 _ => {}
@@ -403,7 +624,8 @@ impl Channel {
             FieldsChannel::Icon => match self {
                 Self::Group { icon, .. }
                 | Self::TextChannel { icon, .. }
-                | Self::VoiceChannel { icon, .. } => {
+                | Self::VoiceChannel { icon, .. }
+                | Self::ForumChannel { icon, .. } => {
                     icon.take();
                 }
                 _ => {}
@@ -416,11 +638,20 @@ impl Channel {
                 | Self::VoiceChannel {
                     default_permissions,
                     ..
+                }
+                | Self::ForumChannel {
+                    default_permissions,
+                    ..
                 } => {
                     default_permissions.take();
                 }
                 _ => {}
             },
+            FieldsChannel::RequestedBy => {
+                if let Self::DirectMessage { requested_by, .. } = self {
+                    requested_by.take();
+                }
+            }
         }
     }
 
@@ -433,6 +664,36 @@ impl Channel {
 
     /// Apply partial channel to channel
     pub fn apply_options(&mut self, partial: PartialChannel) {
+        if let Self::TextChannel {
+            slowmode_seconds,
+            announcement,
+            ..
+        } = self
+        {
+            if let Some(v) = partial.slowmode_seconds {
+                *slowmode_seconds = v;
+            }
+
+            if let Some(v) = partial.announcement {
+                *announcement = v;
+            }
+        }
+
+        if let Self::ForumChannel {
+            available_tags,
+            default_sort_order,
+            ..
+        } = self
+        {
+            if let Some(v) = partial.available_tags {
+                *available_tags = v;
+            }
+
+            if let Some(v) = partial.default_sort_order {
+                *default_sort_order = v;
+            }
+        }
+
         match self {
             Self::SavedMessages { .. } => {}
             Self::DirectMessage { active, .. } => {
@@ -490,6 +751,15 @@ impl Channel {
                 default_permissions,
                 role_permissions,
                 ..
+            }
+            | Self::ForumChannel {
+                name,
+                description,
+                icon,
+                nsfw,
+                default_permissions,
+                role_permissions,
+                ..
             } => {
                 if let Some(v) = partial.name {
                     *name = v;
@@ -515,6 +785,15 @@ impl Channel {
                     default_permissions.replace(v);
                 }
             }
+            Self::ForumPost { name, tags, .. } => {
+                if let Some(v) = partial.name {
+                    *name = v;
+                }
+
+                if let Some(v) = partial.tags {
+                    *tags = v;
+                }
+            }
         }
     }
 
@@ -610,8 +889,12 @@ impl Channel {
     /// Delete a channel
     pub async fn delete(&self, db: &Database) -> Result<()> {
         let id = self.id().to_string();
-        EventV1::ChannelDelete { id: id.clone() }.p(id).await;
-        db.delete_channel(self).await
+        EventV1::ChannelDelete { id: id.clone() }
+            .p(id.clone())
+            .await;
+        db.delete_channel(self).await?;
+        ChannelFollower::delete_for_channels(db, &[id.clone()]).await?;
+        Message::detach_channel_forwards(db, &[id]).await
     }
 }
 
@@ -620,7 +903,205 @@ impl IntoDocumentPath for FieldsChannel {
         Some(match self {
             FieldsChannel::Description => "description",
             FieldsChannel::Icon => "icon",
+            FieldsChannel::RequestedBy => "requested_by",
             FieldsChannel::DefaultPermissions => "default_permissions",
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use revolt_models::v0::{ForumSortOrder, ForumTag};
+    use revolt_permissions::{
+        calculate_channel_permissions, ChannelPermission, Override, DEFAULT_PERMISSION_SERVER,
+    };
+    use revolt_result::ErrorType;
+    use ulid::Ulid;
+
+    use crate::{
+        util::permissions::DatabasePermissionQuery, Channel, ForumPostQuery, Member, Server, User,
+    };
+
+    fn forum(server: &Server) -> Channel {
+        Channel::ForumChannel {
+            id: Ulid::new().to_string(),
+            server: server.id.clone(),
+            name: "Forum".to_string(),
+            description: None,
+            icon: None,
+            available_tags: vec![ForumTag {
+                id: "news".to_string(),
+                name: "News".to_string(),
+                emoji: None,
+            }],
+            default_sort_order: ForumSortOrder::LatestActivity,
+            default_permissions: Some(
+                Override {
+                    allow: 0,
+                    deny: ChannelPermission::SendMessage as u64,
+                }
+                .into(),
+            ),
+            role_permissions: HashMap::new(),
+            nsfw: false,
+        }
+    }
+
+    fn post(
+        forum: &Channel,
+        id: String,
+        tags: Vec<String>,
+        last_message_id: Option<String>,
+    ) -> Channel {
+        let Channel::ForumChannel {
+            id: forum, server, ..
+        } = forum
+        else {
+            unreachable!()
+        };
+
+        Channel::ForumPost {
+            id,
+            server: server.clone(),
+            forum: forum.clone(),
+            author: "author".to_string(),
+            name: "Post".to_string(),
+            tags,
+            last_message_id,
+        }
+    }
+
+    #[async_std::test]
+    async fn forum_post_permissions() {
+        database_test!(|db| async move {
+            let user = User::create(&db, "User".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let server = Server {
+                id: Ulid::new().to_string(),
+                owner: "owner".to_string(),
+                name: "Server".to_string(),
+                default_permissions: *DEFAULT_PERMISSION_SERVER as i64,
+                ..Default::default()
+            };
+
+            server.create(&db).await.unwrap();
+            Member::create(&db, &server, &user).await.unwrap();
+            let member = db.fetch_member(&server.id, &user.id).await.unwrap();
+
+            let forum = forum(&server);
+            forum.create(&db).await.unwrap();
+            let post = post(&forum, Ulid::new().to_string(), vec![], None);
+
+            // Posts take their permissions from the forum they belong to
+            let mut query = DatabasePermissionQuery::new(&db, &user)
+                .server(&server)
+                .member(&member)
+                .channel(&post);
+
+            let permissions = calculate_channel_permissions(&mut query).await;
+            assert!(permissions.has_channel_permission(ChannelPermission::ViewChannel));
+            assert!(!permissions.has_channel_permission(ChannelPermission::SendMessage));
+        });
+    }
+
+    #[test]
+    fn forum_tags() {
+        let server = Server {
+            id: Ulid::new().to_string(),
+            ..Default::default()
+        };
+
+        let forum = forum(&server);
+        assert!(forum.throw_if_unknown_forum_tags(&[]).is_ok());
+        assert!(forum
+            .throw_if_unknown_forum_tags(&["news".to_string()])
+            .is_ok());
+
+        let error = forum
+            .throw_if_unknown_forum_tags(&["news".to_string(), "unknown".to_string()])
+            .unwrap_err();
+        assert_eq!(error.error_type, ErrorType::InvalidProperty);
+
+        let error = post(&forum, Ulid::new().to_string(), vec![], None)
+            .throw_if_unknown_forum_tags(&[])
+            .unwrap_err();
+        assert_eq!(error.error_type, ErrorType::InvalidOperation);
+    }
+
+    #[async_std::test]
+    async fn forum_post_sorting() {
+        database_test!(|db| async move {
+            let server = Server {
+                id: Ulid::new().to_string(),
+                ..Default::default()
+            };
+
+            let forum = forum(&server);
+
+            let mut ids: Vec<String> = (0..4).map(|_| Ulid::new().to_string()).collect();
+            ids.sort();
+
+            // Created oldest to newest, but activity runs the other way round
+            let mut posts = vec![];
+            for (index, tags) in [vec!["news".to_string()], vec![], vec!["news".to_string()]]
+                .into_iter()
+                .enumerate()
+            {
+                let post = post(
+                    &forum,
+                    ids[index].clone(),
+                    tags,
+                    Some(format!("0{}", 3 - index)),
+                );
+                post.create(&db).await.unwrap();
+                posts.push(post.id());
+            }
+
+            // Posts nobody has replied to sort after active ones
+            let quiet = post(&forum, ids[3].clone(), vec![], None);
+            quiet.create(&db).await.unwrap();
+
+            let fetch = |before: Option<String>, tag: Option<&str>, sort: ForumSortOrder| {
+                let db = db.clone();
+                let forum = forum.id();
+                let tag = tag.map(|tag| tag.to_string());
+                async move {
+                    db.fetch_forum_posts(
+                        &forum,
+                        ForumPostQuery {
+                            limit: 2,
+                            before,
+                            tag,
+                            sort,
+                        },
+                    )
+                    .await
+                    .unwrap()
+                    .into_iter()
+                    .map(|post| post.id())
+                    .collect::<Vec<String>>()
+                }
+            };
+
+            let page = fetch(None, None, ForumSortOrder::LatestActivity).await;
+            assert_eq!(page, vec![posts[0].clone(), posts[1].clone()]);
+
+            // The cursor is a post, so paging follows activity rather than post ids
+            let page = fetch(Some(posts[1].clone()), None, ForumSortOrder::LatestActivity).await;
+            assert_eq!(page, vec![posts[2].clone(), quiet.id()]);
+
+            let page = fetch(None, None, ForumSortOrder::CreationDate).await;
+            assert_eq!(page, vec![quiet.id(), posts[2].clone()]);
+
+            let page = fetch(Some(posts[2].clone()), None, ForumSortOrder::CreationDate).await;
+            assert_eq!(page, vec![posts[1].clone(), posts[0].clone()]);
+
+            let page = fetch(None, Some("news"), ForumSortOrder::LatestActivity).await;
+            assert_eq!(page, vec![posts[0].clone(), posts[2].clone()]);
+        });
+    }
+}
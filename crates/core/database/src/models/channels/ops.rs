@@ -1,4 +1,4 @@
-use crate::{revolt_result::Result, Channel, FieldsChannel, PartialChannel};
+use crate::{revolt_result::Result, Channel, FieldsChannel, ForumPostQuery, PartialChannel};
 use revolt_permissions::OverrideField;
 mod mongodb;
 mod reference;
@@ -17,6 +17,13 @@ pub trait AbstractChannels: Sync + Send {
     /// Fetch all direct messages for a user
     async fn find_direct_messages(&self, user_id: &str) -> Result<Vec<Channel>>;
 
+    /// Fetch posts within a forum channel
+    async fn fetch_forum_posts(
+        &self,
+        forum_id: &str,
+        query: ForumPostQuery,
+    ) -> Result<Vec<Channel>>;
+
     // Fetch saved messages channel
     async fn find_saved_messages_channel(&self, user_id: &str) -> Result<Channel>;
 
@@ -1,7 +1,9 @@
 use super::AbstractChannels;
-use crate::{Channel, FieldsChannel, IntoDocumentPath, MongoDb, PartialChannel};
+use crate::{Channel, FieldsChannel, ForumPostQuery, IntoDocumentPath, MongoDb, PartialChannel};
 use bson::Document;
 use futures::StreamExt;
+use mongodb::options::FindOptions;
+use revolt_models::v0::ForumSortOrder;
 use revolt_permissions::OverrideField;
 use revolt_result::Result;
 
@@ -72,6 +74,100 @@ impl AbstractChannels for MongoDb {
         )
     }
 
+    /// Fetch posts within a forum channel
+    async fn fetch_forum_posts(
+        &self,
+        forum_id: &str,
+        query: ForumPostQuery,
+    ) -> Result<Vec<Channel>> {
+        let key = match query.sort {
+            ForumSortOrder::LatestActivity => "last_message_id",
+            ForumSortOrder::CreationDate => "_id",
+        };
+
+        let mut filter = doc! {
+            "channel_type": "ForumPost",
+            "forum": forum_id
+        };
+
+        if let Some(before) = query.before {
+            match query.sort {
+                ForumSortOrder::CreationDate => {
+                    filter.insert(
+                        "_id",
+                        doc! {
+                            "$lt": before
+                        },
+                    );
+                }
+                // Activity moves posts around, so continue from where the cursor post sits now
+                ForumSortOrder::LatestActivity => {
+                    let last_message_id = match self.fetch_channel(&before).await? {
+                        Channel::ForumPost {
+                            last_message_id, ..
+                        } => last_message_id,
+                        _ => return Err(create_error!(NotFound)),
+                    };
+
+                    // Posts without any messages sort after everything else
+                    let quiet = doc! {
+                        "last_message_id": {
+                            "$exists": false
+                        }
+                    };
+
+                    filter.insert(
+                        "$or",
+                        match last_message_id {
+                            Some(last_message_id) => vec![
+                                doc! {
+                                    "last_message_id": {
+                                        "$lt": &last_message_id
+                                    }
+                                },
+                                doc! {
+                                    "last_message_id": &last_message_id,
+                                    "_id": {
+                                        "$lt": &before
+                                    }
+                                },
+                                quiet,
+                            ],
+                            None => vec![doc! {
+                                "$and": [
+                                    quiet,
+                                    {
+                                        "_id": {
+                                            "$lt": &before
+                                        }
+                                    }
+                                ]
+                            }],
+                        },
+                    );
+                }
+            }
+        }
+
+        if let Some(tag) = query.tag {
+            filter.insert("tags", tag);
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(query.limit)
+                .sort(doc! {
+                    key: -1_i32,
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
     // Fetch saved messages channel
     async fn find_saved_messages_channel(&self, user_id: &str) -> Result<Channel> {
         query!(
@@ -2,7 +2,8 @@ use std::collections::hash_map::Entry;
 
 use super::AbstractChannels;
 use crate::ReferenceDb;
-use crate::{Channel, FieldsChannel, PartialChannel};
+use crate::{Channel, FieldsChannel, ForumPostQuery, PartialChannel};
+use revolt_models::v0::ForumSortOrder;
 use revolt_permissions::OverrideField;
 use revolt_result::Result;
 
@@ -51,6 +52,52 @@ impl AbstractChannels for ReferenceDb {
             .collect())
     }
 
+    /// Fetch posts within a forum channel
+    async fn fetch_forum_posts(
+        &self,
+        forum_id: &str,
+        query: ForumPostQuery,
+    ) -> Result<Vec<Channel>> {
+        let sort_key = |channel: &Channel| match (channel, &query.sort) {
+            (
+                Channel::ForumPost {
+                    last_message_id, ..
+                },
+                ForumSortOrder::LatestActivity,
+            ) => (last_message_id.clone().unwrap_or_default(), channel.id()),
+            _ => (channel.id(), channel.id()),
+        };
+
+        let channels = self.channels.lock().await;
+        let cursor = match (&query.before, &query.sort) {
+            (Some(before), ForumSortOrder::CreationDate) => Some((before.clone(), before.clone())),
+            (Some(before), ForumSortOrder::LatestActivity) => match channels.get(before) {
+                Some(channel @ Channel::ForumPost { .. }) => Some(sort_key(channel)),
+                _ => return Err(create_error!(NotFound)),
+            },
+            (None, _) => None,
+        };
+
+        let mut posts: Vec<Channel> = channels
+            .values()
+            .filter(|channel| match channel {
+                Channel::ForumPost { forum, tags, .. } => {
+                    forum == forum_id
+                        && query.tag.as_ref().map_or(true, |tag| tags.contains(tag))
+                        && cursor
+                            .as_ref()
+                            .map_or(true, |cursor| &sort_key(channel) < cursor)
+                }
+                _ => false,
+            })
+            .cloned()
+            .collect();
+
+        posts.sort_by_key(|channel| std::cmp::Reverse(sort_key(channel)));
+        posts.truncate(query.limit as usize);
+        Ok(posts)
+    }
+
     // Fetch saved messages channel
     async fn find_saved_messages_channel(&self, user_id: &str) -> Result<Channel> {
         let channels = self.channels.lock().await;
@@ -97,6 +144,9 @@ impl AbstractChannels for ReferenceDb {
                 }
                 | Channel::VoiceChannel {
                     role_permissions, ..
+                }
+                | Channel::ForumChannel {
+                    role_permissions, ..
                 } => {
                     if role_permissions.get(role_id).is_some() {
                         role_permissions.remove(role_id);
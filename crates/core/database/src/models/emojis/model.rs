@@ -81,7 +81,12 @@ impl Emoji {
             db.fetch_emoji(emoji).await?;
             Ok(true)
         } else {
-            Ok(PERMISSIBLE_EMOJIS.contains(emoji))
+            Ok(Emoji::is_unicode(emoji))
         }
     }
+
+    /// Check whether a given string is a permissible unicode emoji
+    pub fn is_unicode(emoji: &str) -> bool {
+        PERMISSIBLE_EMOJIS.contains(emoji)
+    }
 }
@@ -2,22 +2,29 @@ use std::collections::HashSet;
 
 use indexmap::{IndexMap, IndexSet};
 use iso8601_timestamp::Timestamp;
+use regex::RegexBuilder;
 use revolt_config::config;
 use revolt_models::v0::{
-    self, DataMessageSend, Embed, MessageAuthor, MessageSort, MessageWebhook, PushNotification,
-    ReplyIntent, SendableEmbed, RE_MENTION,
+    self, DataMessageSend, Embed, MessageAuthor, MessageForward, MessageSort, MessageWebhook,
+    PushNotification, ReplyIntent, SendableEmbed, RE_MENTION, RE_ROLE_MENTION,
 };
 use revolt_permissions::{ChannelPermission, PermissionValue};
-use revolt_result::Result;
+use revolt_result::{ErrorType, Result};
 use ulid::Ulid;
 
 use crate::{
     events::client::EventV1,
     tasks::{self, ack::AckEvent},
     util::idempotency::IdempotencyKey,
-    Channel, Database, Emoji, File,
+    AutomodRule, AutomodSubject, AutomodVerdict, Channel, Database, Emoji, File, Server,
 };
 
+/// Number of characters to keep on either side of a search match
+const HIGHLIGHT_CONTEXT: usize = 80;
+
+/// Number of members fetched at a time when resolving role mentions
+const ROLE_MENTION_PAGE_SIZE: i64 = 500;
+
 auto_derived_partial!(
     /// Message
     pub struct Message {
@@ -52,6 +59,9 @@ auto_derived_partial!(
         /// Array of user ids mentioned in this message
         #[serde(skip_serializing_if = "Option::is_none")]
         pub mentions: Option<Vec<String>>,
+        /// Array of role ids mentioned in this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub role_mentions: Option<Vec<String>>,
         /// Array of message ids this message is replying to
         #[serde(skip_serializing_if = "Option::is_none")]
         pub replies: Option<Vec<String>>,
@@ -73,6 +83,18 @@ auto_derived_partial!(
         pub components: Option<Vec<Component>>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub session_id: Option<String>,
+        /// Id of the only user this message is visible to
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub ephemeral: Option<String>,
+        /// Original message this message was forwarded from
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub forwarded: Option<MessageForward>,
+        /// Whether this message is pinned in its channel
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub pinned: bool,
+        /// Whether this message has been published to channel followers
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub published: bool,
     },
     "PartialMessage"
 );
@@ -187,6 +209,34 @@ auto_derived!(
         pub time_period: MessageTimePeriod,
     }
 
+    /// Message Search Query
+    pub struct MessageSearchQuery {
+        /// Channels to search within
+        pub channels: Vec<String>,
+        /// Full-text search query
+        pub query: Option<String>,
+        /// Message author ID
+        pub author: Option<String>,
+        /// ID of a user who must be mentioned
+        pub mentions: Option<String>,
+        /// Whether messages must have attachments
+        pub has_attachment: bool,
+        /// Whether messages must have embeds
+        pub has_embed: bool,
+        /// Whether messages must contain a link
+        pub has_link: bool,
+        /// Whether messages must be pinned or not
+        pub pinned: Option<bool>,
+        /// Message id before which messages should be fetched
+        pub before: Option<String>,
+        /// Message id after which messages should be fetched
+        pub after: Option<String>,
+        /// Maximum number of messages to fetch
+        pub limit: i64,
+        /// Message sort direction
+        pub sort: MessageSort,
+    }
+
     #[serde(tag = "type")]
     pub enum Component {
         #[serde(rename = "button")]
@@ -225,6 +275,7 @@ impl Default for Message {
             edited: None,
             embeds: None,
             mentions: None,
+            role_mentions: None,
             replies: None,
             reactions: Default::default(),
             interactions: Default::default(),
@@ -232,6 +283,10 @@ impl Default for Message {
             components: Default::default(),
             is_stream: None,
             session_id: None,
+            ephemeral: None,
+            forwarded: None,
+            pinned: false,
+            published: false,
         }
     }
 }
@@ -289,6 +344,44 @@ impl Message {
             MessageAuthor::System { .. } => ("00000000000000000000000000".to_string(), None),
         };
 
+        // Ephemeral messages may only be sent by bots and webhooks
+        // to a user who can actually see the channel
+        if let Some(target) = &data.ephemeral {
+            if !matches!(&author, MessageAuthor::User(user) if user.bot.is_some())
+                && !matches!(&author, MessageAuthor::Webhook(_))
+            {
+                return Err(create_error!(InvalidOperation));
+            }
+
+            let visible = match &channel {
+                Channel::SavedMessages { user, .. } => user == target,
+                Channel::DirectMessage { recipients, .. } | Channel::Group { recipients, .. } => {
+                    recipients.contains(target)
+                }
+                Channel::TextChannel { server, .. }
+                | Channel::VoiceChannel { server, .. }
+                | Channel::ForumChannel { server, .. }
+                | Channel::ForumPost { server, .. } => {
+                    db.fetch_member(server, target).await.is_ok()
+                }
+            };
+
+            if !visible {
+                return Err(create_error!(UnknownUser));
+            }
+
+            // Uploads would be bound to a message which is never saved
+            if data.attachments.as_ref().is_some_and(|v| !v.is_empty())
+                || data
+                    .embeds
+                    .iter()
+                    .flatten()
+                    .any(|embed| embed.media.is_some())
+            {
+                return Err(create_error!(InvalidOperation));
+            }
+        }
+
         // Start constructing the message
         let message_id = Ulid::new().to_string();
         let mut message = Message {
@@ -306,6 +399,7 @@ impl Message {
                 .unwrap_or_default(),
             author: author_id,
             webhook: webhook.map(|w| w.into()),
+            ephemeral: data.ephemeral,
             ..Default::default()
         };
 
@@ -343,6 +437,34 @@ impl Message {
             message.mentions.replace(mentions.into_iter().collect());
         }
 
+        // Parse role mentions, only mentionable roles of this server are kept.
+        if let (
+            Some(content),
+            Channel::TextChannel { server, .. }
+            | Channel::VoiceChannel { server, .. }
+            | Channel::ForumPost { server, .. },
+        ) = (&data.content, &channel)
+        {
+            let role_ids: HashSet<&str> = RE_ROLE_MENTION
+                .captures_iter(content)
+                .filter_map(|capture| capture.get(1))
+                .map(|role| role.as_str())
+                .collect();
+
+            if !role_ids.is_empty() {
+                let server = db.fetch_server(server).await?;
+                let role_mentions: Vec<String> = role_ids
+                    .into_iter()
+                    .filter(|id| server.roles.get(*id).is_some_and(|role| role.mentionable))
+                    .map(|id| id.to_string())
+                    .collect();
+
+                if !role_mentions.is_empty() {
+                    message.role_mentions.replace(role_mentions);
+                }
+            }
+        }
+
         if !replies.is_empty() {
             message
                 .replies
@@ -399,6 +521,201 @@ impl Message {
         Ok(message)
     }
 
+    /// Forward a copy of an existing message into another channel
+    pub async fn create_forward(
+        db: &Database,
+        original: &Message,
+        destination: &Channel,
+        author: MessageAuthor<'_>,
+        idempotency: IdempotencyKey,
+    ) -> Result<Message> {
+        if original.system.is_some() || original.ephemeral.is_some() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        // Always point at the first message in a chain of forwards
+        let forwarded = if let Some(forwarded) = &original.forwarded {
+            if forwarded.deleted {
+                return Err(create_error!(UnknownMessage));
+            }
+
+            forwarded.clone()
+        } else {
+            MessageForward {
+                id: original.id.clone(),
+                channel: original.channel.clone(),
+                author: original.author.clone(),
+                webhook: original.webhook.clone(),
+                deleted: false,
+            }
+        };
+
+        let mut message = Message {
+            id: Ulid::new().to_string(),
+            nonce: Some(idempotency.into_key()),
+            channel: destination.id(),
+            author: author.id().to_string(),
+            content: original.content.clone(),
+            attachments: original.attachments.clone(),
+            embeds: original.embeds.clone(),
+            forwarded: Some(forwarded),
+            ..Default::default()
+        };
+
+        message.send(db, author, destination, false).await?;
+
+        Ok(message)
+    }
+
+    /// Replace forwards of any message sent in the given channels with a placeholder
+    pub async fn detach_channel_forwards(db: &Database, channels: &[String]) -> Result<()> {
+        let forwards = db.fetch_forwards_from_channels(channels).await?;
+        if forwards.is_empty() {
+            return Ok(());
+        }
+
+        let ids: Vec<String> = forwards
+            .iter()
+            .filter_map(|message| message.forwarded.as_ref())
+            .map(|forwarded| forwarded.id.clone())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+
+        db.mark_forwards_as_deleted(&ids).await?;
+
+        for message in forwards {
+            if let Some(mut forwarded) = message.forwarded {
+                forwarded.deleted = true;
+
+                EventV1::MessageUpdate {
+                    id: message.id,
+                    channel: message.channel.clone(),
+                    data: v0::PartialMessage {
+                        forwarded: Some(forwarded),
+                        ..Default::default()
+                    },
+                    clear: vec![
+                        v0::FieldsMessage::Content,
+                        v0::FieldsMessage::Attachments,
+                        v0::FieldsMessage::Embeds,
+                    ],
+                }
+                .p(message.channel)
+                .await;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Publish this message to every channel following its announcement channel
+    ///
+    /// Copies are sent through each follower's webhook under the source server's name and icon.
+    pub async fn publish(&mut self, db: &Database, server: &Server) -> Result<()> {
+        if self.published || self.system.is_some() || self.ephemeral.is_some() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let partial = PartialMessage {
+            published: Some(true),
+            ..Default::default()
+        };
+
+        db.update_message(&self.id, &partial).await?;
+        self.published = true;
+
+        EventV1::MessageUpdate {
+            id: self.id.clone(),
+            channel: self.channel.clone(),
+            data: partial.into(),
+            clear: vec![],
+        }
+        .p(self.channel.clone())
+        .await;
+
+        let source = MessageWebhook {
+            name: server.name.clone(),
+            avatar: server.icon.as_ref().map(|icon| icon.id.clone()),
+        };
+
+        for follower in db.fetch_channel_followers(&self.channel).await? {
+            let (channel, webhook) = match (
+                db.fetch_channel(&follower.target).await,
+                db.fetch_webhook(&follower.webhook).await,
+            ) {
+                (Ok(channel), Ok(webhook)) => (channel, webhook),
+                (channel, webhook) => {
+                    // Drop followers whose channel or webhook has since been removed,
+                    // anything else may be temporary so they're kept around
+                    if [channel.err(), webhook.err()]
+                        .into_iter()
+                        .flatten()
+                        .any(|error| matches!(error.error_type, ErrorType::NotFound))
+                    {
+                        follower.delete(db).await.ok();
+                    }
+
+                    continue;
+                }
+            };
+
+            let author = v0::Webhook {
+                name: source.name.clone(),
+                avatar: server.icon.clone().map(|icon| icon.into()),
+                ..webhook.into()
+            };
+
+            let mut message = Message {
+                id: Ulid::new().to_string(),
+                channel: follower.target,
+                author: author.id.clone(),
+                webhook: Some(source.clone()),
+                content: self.content.clone(),
+                attachments: self.attachments.clone(),
+                embeds: self.embeds.clone(),
+                ..Default::default()
+            };
+
+            message
+                .send(db, MessageAuthor::Webhook(&author), &channel, false)
+                .await
+                .ok();
+        }
+
+        Ok(())
+    }
+
+    /// Pin or unpin this message in its channel
+    pub async fn set_pinned(&mut self, db: &Database, pinned: bool) -> Result<()> {
+        if self.pinned == pinned {
+            return Err(create_error!(NoEffect));
+        }
+
+        if self.system.is_some() || self.ephemeral.is_some() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        let partial = PartialMessage {
+            pinned: Some(pinned),
+            ..Default::default()
+        };
+
+        db.update_message(&self.id, &partial).await?;
+        self.pinned = pinned;
+
+        EventV1::MessageUpdate {
+            id: self.id.clone(),
+            channel: self.channel.clone(),
+            data: partial.into(),
+            clear: vec![],
+        }
+        .p(self.channel.clone())
+        .await;
+
+        Ok(())
+    }
+
     /// Send a message without any notifications
     pub async fn send_without_notifications(
         &mut self,
@@ -406,6 +723,15 @@ impl Message {
         is_dm: bool,
         generate_embeds: bool,
     ) -> Result<()> {
+        // Ephemeral messages are only delivered to their recipient
+        if let Some(target) = &self.ephemeral {
+            EventV1::Message(self.clone().into())
+                .private(target.to_string())
+                .await;
+
+            return Ok(());
+        }
+
         db.insert_message(self).await?;
 
         // Fan out events
@@ -453,6 +779,37 @@ impl Message {
         channel: &Channel,
         generate_embeds: bool,
     ) -> Result<()> {
+        // Forums only hold posts, messages go into the posts themselves
+        if let Channel::ForumChannel { .. } = channel {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        // Run server automod against anything users send
+        if let (
+            MessageAuthor::User(_),
+            Channel::TextChannel { server, .. }
+            | Channel::VoiceChannel { server, .. }
+            | Channel::ForumPost { server, .. },
+        ) = (&author, channel)
+        {
+            if let AutomodVerdict::Block { rule } | AutomodVerdict::Delete { rule } =
+                AutomodRule::enforce(
+                    db,
+                    server,
+                    AutomodSubject {
+                        channel: &self.channel,
+                        author: &self.author,
+                        content: self.content.as_deref(),
+                        mentions: self.mentions.as_ref().map_or(0, |mentions| mentions.len()),
+                        attachments: self.attachments.as_deref().unwrap_or_default(),
+                    },
+                )
+                .await?
+            {
+                return Err(create_error!(BlockedByAutomod { rule }));
+            }
+        }
+
         self.send_without_notifications(
             db,
             matches!(channel, Channel::DirectMessage { .. }),
@@ -460,13 +817,26 @@ impl Message {
         )
         .await?;
 
+        if self.ephemeral.is_some() {
+            return Ok(());
+        }
+
+        let recipients = self.mentioned_users(db, channel).await;
+
         // Push out Web Push notifications
         crate::tasks::web_push::queue(
             {
                 match channel {
+                    // Message requests stay quiet until they are accepted
+                    Channel::DirectMessage {
+                        requested_by: Some(_),
+                        ..
+                    } => vec![],
                     Channel::DirectMessage { recipients, .. }
                     | Channel::Group { recipients, .. } => recipients.clone(),
-                    Channel::TextChannel { .. } => self.mentions.clone().unwrap_or_default(),
+                    Channel::TextChannel { .. }
+                    | Channel::VoiceChannel { .. }
+                    | Channel::ForumPost { .. } => recipients.into_iter().collect(),
                     _ => vec![],
                 }
             },
@@ -477,6 +847,56 @@ impl Message {
         Ok(())
     }
 
+    /// Collect users mentioned directly or through one of the mentioned roles
+    async fn mentioned_users(&self, db: &Database, channel: &Channel) -> HashSet<String> {
+        let mut recipients: HashSet<String> = self
+            .mentions
+            .clone()
+            .unwrap_or_default()
+            .into_iter()
+            .collect();
+
+        if let (
+            Some(role_mentions),
+            Channel::TextChannel { server, .. }
+            | Channel::VoiceChannel { server, .. }
+            | Channel::ForumPost { server, .. },
+        ) = (&self.role_mentions, channel)
+        {
+            for role in role_mentions {
+                let mut after = None;
+                loop {
+                    // A failed lookup should only cost notifications, never the message itself
+                    let members = match db
+                        .fetch_members_with_role(
+                            server,
+                            role,
+                            after.take(),
+                            Some(ROLE_MENTION_PAGE_SIZE),
+                        )
+                        .await
+                    {
+                        Ok(members) => members,
+                        Err(err) => {
+                            error!("Failed to resolve mention of role {role}: {err:?}");
+                            break;
+                        }
+                    };
+
+                    let exhausted = (members.len() as i64) < ROLE_MENTION_PAGE_SIZE;
+                    after = members.last().map(|member| member.id.user.clone());
+                    recipients.extend(members.into_iter().map(|member| member.id.user));
+
+                    if exhausted {
+                        break;
+                    }
+                }
+            }
+        }
+
+        recipients
+    }
+
     /// Append content to message
     pub async fn append(
         db: &Database,
@@ -531,6 +951,57 @@ impl Message {
         Ok(())
     }
 
+    /// Build an excerpt of the content around the first matched search term
+    ///
+    /// Every matched term within the excerpt is wrapped in bold markdown.
+    pub fn highlight(&self, query: &str) -> Option<String> {
+        let content = self.content.as_ref()?;
+        let terms: Vec<String> = query
+            .split_whitespace()
+            .map(|term| regex::escape(term.trim_matches(|c| c == '"' || c == '-')))
+            .filter(|term| !term.is_empty())
+            .collect();
+
+        if terms.is_empty() {
+            return None;
+        }
+
+        let pattern = RegexBuilder::new(&terms.join("|"))
+            .case_insensitive(true)
+            .build()
+            .ok()?;
+
+        let first = pattern.find(content)?;
+
+        // Snap the excerpt window to character boundaries
+        let start = content[..first.start()]
+            .char_indices()
+            .rev()
+            .nth(HIGHLIGHT_CONTEXT - 1)
+            .map(|(index, _)| index)
+            .unwrap_or(0);
+
+        let end = content[first.end()..]
+            .char_indices()
+            .nth(HIGHLIGHT_CONTEXT)
+            .map(|(index, _)| first.end() + index)
+            .unwrap_or(content.len());
+
+        let mut excerpt = pattern
+            .replace_all(&content[start..end], "**$0**")
+            .to_string();
+
+        if start > 0 {
+            excerpt.insert(0, '…');
+        }
+
+        if end < content.len() {
+            excerpt.push('…');
+        }
+
+        Some(excerpt)
+    }
+
     /// Validate the sum of content of a message is under threshold
     pub fn validate_sum(
         content: &Option<String>,
@@ -556,6 +1027,17 @@ impl Message {
     }
 }
 
+impl MessageSearchQuery {
+    /// Lowest possible id of a message sent at the given time
+    pub fn id_from_timestamp(timestamp: Timestamp) -> String {
+        let millis = timestamp
+            .duration_since(Timestamp::UNIX_EPOCH)
+            .whole_milliseconds();
+
+        Ulid::from_parts(millis.max(0) as u64, 0).to_string()
+    }
+}
+
 impl SystemMessage {
     pub fn into_message(self, channel: String) -> Message {
         Message {
@@ -609,3 +1091,337 @@ impl Interactions {
         !self.restrict_reactions && self.reactions.is_none()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use revolt_models::v0::{self, DataMessageSend, MessageSort};
+    use revolt_result::ErrorType;
+
+    use crate::{
+        util::idempotency::IdempotencyKey, BotInformation, Channel, Member, MemberCompositeKey,
+        Message, MessageSearchQuery, User,
+    };
+
+    use super::ROLE_MENTION_PAGE_SIZE;
+
+    fn message_data(content: &str) -> DataMessageSend {
+        DataMessageSend {
+            nonce: None,
+            content: Some(content.to_string()),
+            attachments: None,
+            replies: None,
+            embeds: None,
+            masquerade: None,
+            interactions: None,
+            components: None,
+            session_id: None,
+            is_stream: None,
+            ephemeral: None,
+        }
+    }
+
+    fn key() -> IdempotencyKey {
+        IdempotencyKey::unchecked_from_string(ulid::Ulid::new().to_string())
+    }
+
+    #[async_std::test]
+    async fn ephemeral() {
+        database_test!(|db| async move {
+            let user = User::create(&db, "User".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let mut bot = User::create(&db, "Bot".to_string(), None, None)
+                .await
+                .unwrap();
+
+            bot.bot = Some(BotInformation {
+                owner: user.id.clone(),
+                model: None,
+                welcome: None,
+            });
+
+            let channel = Channel::SavedMessages {
+                id: ulid::Ulid::new().to_string(),
+                user: user.id.clone(),
+            };
+
+            let user_author: v0::User = user.clone().into(None).await;
+            let bot_author: v0::User = bot.clone().into(None).await;
+
+            // Only bots and webhooks may send ephemeral messages
+            let mut data = message_data("Hello");
+            data.ephemeral = Some(user.id.clone());
+
+            let error = Message::create_from_api(
+                &db,
+                channel.clone(),
+                data,
+                v0::MessageAuthor::User(&user_author),
+                key(),
+                false,
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(error.error_type, ErrorType::InvalidOperation);
+
+            // Uploads may not be attached to ephemeral messages
+            let mut data = message_data("Hello");
+            data.ephemeral = Some(user.id.clone());
+            data.attachments = Some(vec!["attachment".to_string()]);
+
+            let error = Message::create_from_api(
+                &db,
+                channel.clone(),
+                data,
+                v0::MessageAuthor::User(&bot_author),
+                key(),
+                false,
+            )
+            .await
+            .unwrap_err();
+
+            assert_eq!(error.error_type, ErrorType::InvalidOperation);
+
+            // Ephemeral messages are delivered but never saved
+            let mut data = message_data("Hello");
+            data.ephemeral = Some(user.id.clone());
+
+            let message = Message::create_from_api(
+                &db,
+                channel,
+                data,
+                v0::MessageAuthor::User(&bot_author),
+                key(),
+                false,
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(message.ephemeral, Some(user.id));
+            assert!(db.fetch_message(&message.id).await.is_err());
+        });
+    }
+
+    fn search_query(channels: Vec<String>, query: Option<&str>) -> MessageSearchQuery {
+        MessageSearchQuery {
+            channels,
+            query: query.map(|query| query.to_string()),
+            author: None,
+            mentions: None,
+            has_attachment: false,
+            has_embed: false,
+            has_link: false,
+            pinned: None,
+            before: None,
+            after: None,
+            limit: 10,
+            sort: MessageSort::Latest,
+        }
+    }
+
+    #[async_std::test]
+    async fn search() {
+        database_test!(|db| async move {
+            let user = User::create(&db, "User".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let author: v0::User = user.clone().into(None).await;
+
+            let channels: Vec<Channel> = (0..2)
+                .map(|_| Channel::SavedMessages {
+                    id: ulid::Ulid::new().to_string(),
+                    user: user.id.clone(),
+                })
+                .collect();
+
+            let mut messages = vec![];
+            for (channel, content) in [
+                (&channels[0], "Hello world"),
+                (&channels[0], "Goodbye world, see https://revolt.chat"),
+                (&channels[1], "Hello there"),
+            ] {
+                messages.push(
+                    Message::create_from_api(
+                        &db,
+                        channel.clone(),
+                        message_data(content),
+                        v0::MessageAuthor::User(&author),
+                        key(),
+                        false,
+                    )
+                    .await
+                    .unwrap(),
+                );
+            }
+
+            // Only searched channels are included
+            let results = db
+                .search_messages(search_query(vec![channels[0].id()], Some("hello")))
+                .await
+                .unwrap();
+
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].id, messages[0].id);
+
+            let results = db
+                .search_messages(search_query(
+                    vec![channels[0].id(), channels[1].id()],
+                    Some("HELLO"),
+                ))
+                .await
+                .unwrap();
+
+            assert_eq!(results.len(), 2);
+
+            // Filters apply without a text query
+            let mut query = search_query(vec![channels[0].id()], None);
+            query.has_link = true;
+
+            let results = db.search_messages(query).await.unwrap();
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].id, messages[1].id);
+
+            messages[0].set_pinned(&db, true).await.unwrap();
+
+            let mut query = search_query(vec![channels[0].id()], Some("world"));
+            query.pinned = Some(true);
+
+            let results = db.search_messages(query).await.unwrap();
+            assert_eq!(results.len(), 1);
+            assert_eq!(results[0].id, messages[0].id);
+
+            let error = messages[0].set_pinned(&db, true).await.unwrap_err();
+            assert_eq!(error.error_type, ErrorType::NoEffect);
+        });
+    }
+
+    #[test]
+    fn highlight() {
+        let message = Message {
+            content: Some("The quick brown fox".to_string()),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            message.highlight("QUICK fox").as_deref(),
+            Some("The **quick** brown **fox**")
+        );
+        assert_eq!(message.highlight("cat"), None);
+        assert_eq!(message.highlight("\"-\""), None);
+
+        let message = Message {
+            content: Some(format!("{} needle {}", "a".repeat(200), "b".repeat(200))),
+            ..Default::default()
+        };
+
+        let excerpt = message.highlight("needle").unwrap();
+        assert!(excerpt.starts_with('…'));
+        assert!(excerpt.ends_with('…'));
+        assert!(excerpt.contains("**needle**"));
+        assert!(excerpt.chars().count() < 200);
+    }
+
+    #[async_std::test]
+    async fn detach_forwards_on_channel_delete() {
+        database_test!(|db| async move {
+            let user = User::create(&db, "User".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let author: v0::User = user.clone().into(None).await;
+
+            let source = Channel::SavedMessages {
+                id: ulid::Ulid::new().to_string(),
+                user: user.id.clone(),
+            };
+
+            source.create(&db).await.unwrap();
+
+            let destination = Channel::SavedMessages {
+                id: ulid::Ulid::new().to_string(),
+                user: user.id.clone(),
+            };
+
+            let original = Message::create_from_api(
+                &db,
+                source.clone(),
+                message_data("Hello"),
+                v0::MessageAuthor::User(&author),
+                key(),
+                false,
+            )
+            .await
+            .unwrap();
+
+            let forward = Message::create_forward(
+                &db,
+                &original,
+                &destination,
+                v0::MessageAuthor::User(&author),
+                key(),
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(forward.content.as_deref(), Some("Hello"));
+
+            source.delete(&db).await.unwrap();
+
+            let forward = db.fetch_message(&forward.id).await.unwrap();
+            assert!(forward.forwarded.unwrap().deleted);
+            assert!(forward.content.is_none());
+        });
+    }
+
+    #[async_std::test]
+    #[allow(clippy::disallowed_methods)]
+    async fn role_mentions() {
+        database_test!(|db| async move {
+            // Enough role holders to span more than one page
+            let holders = ROLE_MENTION_PAGE_SIZE as usize + 1;
+            for i in 0..=holders {
+                db.insert_member(&Member {
+                    id: MemberCompositeKey {
+                        server: "server".to_string(),
+                        user: format!("user{i:04}"),
+                    },
+                    roles: if i < holders {
+                        vec!["role".to_string()]
+                    } else {
+                        vec![]
+                    },
+                    ..Default::default()
+                })
+                .await
+                .unwrap();
+            }
+
+            let channel = Channel::VoiceChannel {
+                id: "channel".to_string(),
+                server: "server".to_string(),
+                name: "Voice".to_string(),
+                description: None,
+                icon: None,
+                default_permissions: None,
+                role_permissions: Default::default(),
+                nsfw: false,
+            };
+
+            let message = Message {
+                channel: "channel".to_string(),
+                mentions: Some(vec!["mentioned".to_string()]),
+                role_mentions: Some(vec!["role".to_string()]),
+                ..Default::default()
+            };
+
+            let recipients = message.mentioned_users(&db, &channel).await;
+            assert_eq!(recipients.len(), holders + 1);
+            assert!(recipients.contains("mentioned"));
+            assert!(recipients.contains(&format!("user{:04}", holders - 1)));
+            assert!(!recipients.contains(&format!("user{holders:04}")));
+        });
+    }
+}
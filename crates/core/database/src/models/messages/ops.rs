@@ -1,6 +1,6 @@
 use revolt_result::Result;
 
-use crate::{AppendMessage, Message, MessageQuery, PartialMessage};
+use crate::{AppendMessage, Message, MessageQuery, MessageSearchQuery, PartialMessage};
 
 mod mongodb;
 mod reference;
@@ -16,6 +16,9 @@ pub trait AbstractMessages: Sync + Send {
     /// Fetch multiple messages by given query
     async fn fetch_messages(&self, query: MessageQuery) -> Result<Vec<Message>>;
 
+    /// Search for messages across multiple channels
+    async fn search_messages(&self, query: MessageSearchQuery) -> Result<Vec<Message>>;
+
     /// Update a given message with new information
     async fn update_message(&self, id: &str, message: &PartialMessage) -> Result<()>;
 
@@ -36,4 +39,10 @@ pub trait AbstractMessages: Sync + Send {
 
     /// Delete messages from a channel by their ids and corresponding channel id
     async fn delete_messages(&self, channel: &str, ids: &[String]) -> Result<()>;
+
+    /// Fetch all forwards of messages originally sent in any of the given channels
+    async fn fetch_forwards_from_channels(&self, channels: &[String]) -> Result<Vec<Message>>;
+
+    /// Strip content from forwards of the given messages and mark their original as deleted
+    async fn mark_forwards_as_deleted(&self, ids: &[String]) -> Result<()>;
 }
@@ -4,7 +4,10 @@ use mongodb::options::FindOptions;
 use revolt_models::v0::MessageSort;
 use revolt_result::Result;
 
-use crate::{AppendMessage, Message, MessageQuery, MessageTimePeriod, MongoDb, PartialMessage};
+use crate::{
+    AppendMessage, Message, MessageQuery, MessageSearchQuery, MessageTimePeriod, MongoDb,
+    PartialMessage,
+};
 
 use super::AbstractMessages;
 
@@ -159,6 +162,106 @@ impl AbstractMessages for MongoDb {
         }
     }
 
+    /// Search for messages across multiple channels
+    async fn search_messages(&self, query: MessageSearchQuery) -> Result<Vec<Message>> {
+        let mut filter = doc! {
+            "channel": {
+                "$in": query.channels
+            }
+        };
+
+        if let Some(author) = query.author {
+            filter.insert("author", author);
+        }
+
+        if let Some(mentions) = query.mentions {
+            filter.insert("mentions", mentions);
+        }
+
+        if query.has_attachment {
+            filter.insert("attachments.0", doc! { "$exists": true });
+        }
+
+        if query.has_embed {
+            filter.insert("embeds.0", doc! { "$exists": true });
+        }
+
+        if query.has_link {
+            filter.insert("content", doc! { "$regex": "https?://" });
+        }
+
+        if let Some(pinned) = query.pinned {
+            if pinned {
+                filter.insert("pinned", true);
+            } else {
+                filter.insert("pinned", doc! { "$ne": true });
+            }
+        }
+
+        if let Some(doc) = match (query.before, query.after) {
+            (Some(before), Some(after)) => Some(doc! {
+                "$lt": before,
+                "$gt": after
+            }),
+            (Some(before), _) => Some(doc! {
+                "$lt": before
+            }),
+            (_, Some(after)) => Some(doc! {
+                "$gt": after
+            }),
+            _ => None,
+        } {
+            filter.insert("_id", doc);
+        }
+
+        let is_search_query = if let Some(query) = query.query {
+            filter.insert(
+                "$text",
+                doc! {
+                    "$search": query
+                },
+            );
+
+            true
+        } else {
+            false
+        };
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(query.limit)
+                .sort(match query.sort {
+                    // Sort by relevance, fallback to latest
+                    MessageSort::Relevance => {
+                        if is_search_query {
+                            doc! {
+                                "score": {
+                                    "$meta": "textScore"
+                                }
+                            }
+                        } else {
+                            doc! {
+                                "_id": -1_i32
+                            }
+                        }
+                    }
+                    // Sort by latest first
+                    MessageSort::Latest => doc! {
+                        "_id": -1_i32
+                    },
+                    // Sort by oldest first
+                    MessageSort::Oldest => doc! {
+                        "_id": 1_i32
+                    },
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
     /// Update a given message with new information
     async fn update_message(&self, id: &str, message: &PartialMessage) -> Result<()> {
         query!(self, update_one_by_id, COL, id, message, vec![], None).map(|_| ())
@@ -277,4 +380,47 @@ impl AbstractMessages for MongoDb {
             .map(|_| ())
             .map_err(|_| create_database_error!("delete_many", COL))
     }
+
+    /// Fetch all forwards of messages originally sent in any of the given channels
+    async fn fetch_forwards_from_channels(&self, channels: &[String]) -> Result<Vec<Message>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "forwarded.channel": {
+                    "$in": channels
+                },
+                "forwarded.deleted": {
+                    "$ne": true
+                }
+            }
+        )
+    }
+
+    /// Strip content from forwards of the given messages and mark their original as deleted
+    async fn mark_forwards_as_deleted(&self, ids: &[String]) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_many(
+                doc! {
+                    "forwarded.id": {
+                        "$in": ids
+                    }
+                },
+                doc! {
+                    "$set": {
+                        "forwarded.deleted": true
+                    },
+                    "$unset": {
+                        "content": 1_i32,
+                        "attachments": 1_i32,
+                        "embeds": 1_i32
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_many", COL))
+    }
 }
@@ -1,7 +1,10 @@
 use indexmap::IndexSet;
+use revolt_models::v0::MessageSort;
 use revolt_result::Result;
 
-use crate::{AppendMessage, Message, MessageQuery, PartialMessage, ReferenceDb};
+use crate::{
+    AppendMessage, Message, MessageQuery, MessageSearchQuery, PartialMessage, ReferenceDb,
+};
 
 use super::AbstractMessages;
 
@@ -176,6 +179,103 @@ impl AbstractMessages for ReferenceDb {
         }*/
     }
 
+    /// Search for messages across multiple channels
+    async fn search_messages(&self, query: MessageSearchQuery) -> Result<Vec<Message>> {
+        let messages = self.messages.lock().await;
+        let terms: Vec<String> = query
+            .query
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace()
+            .map(|term| term.to_lowercase())
+            .collect();
+
+        let mut matched_messages: Vec<Message> = messages
+            .values()
+            .filter(|message| {
+                if !query.channels.contains(&message.channel) {
+                    return false;
+                }
+
+                if let Some(author) = &query.author {
+                    if &message.author != author {
+                        return false;
+                    }
+                }
+
+                if let Some(mentions) = &query.mentions {
+                    if !message
+                        .mentions
+                        .as_ref()
+                        .is_some_and(|list| list.contains(mentions))
+                    {
+                        return false;
+                    }
+                }
+
+                if query.has_attachment
+                    && message.attachments.as_ref().map_or(true, |v| v.is_empty())
+                {
+                    return false;
+                }
+
+                if query.has_embed && message.embeds.as_ref().map_or(true, |v| v.is_empty()) {
+                    return false;
+                }
+
+                if query.has_link
+                    && !message.content.as_ref().is_some_and(|content| {
+                        content.contains("http://") || content.contains("https://")
+                    })
+                {
+                    return false;
+                }
+
+                if let Some(pinned) = query.pinned {
+                    if message.pinned != pinned {
+                        return false;
+                    }
+                }
+
+                if let Some(before) = &query.before {
+                    if &message.id >= before {
+                        return false;
+                    }
+                }
+
+                if let Some(after) = &query.after {
+                    if &message.id <= after {
+                        return false;
+                    }
+                }
+
+                if !terms.is_empty() {
+                    if let Some(content) = &message.content {
+                        let content = content.to_lowercase();
+                        if !terms.iter().any(|term| content.contains(term)) {
+                            return false;
+                        }
+                    } else {
+                        return false;
+                    }
+                }
+
+                true
+            })
+            .cloned()
+            .collect();
+
+        match query.sort {
+            MessageSort::Oldest => matched_messages.sort_by(|a, b| a.id.cmp(&b.id)),
+            MessageSort::Latest | MessageSort::Relevance => {
+                matched_messages.sort_by(|a, b| b.id.cmp(&a.id))
+            }
+        }
+
+        matched_messages.truncate(query.limit as usize);
+        Ok(matched_messages)
+    }
+
     /// Update a given message with new information
     async fn update_message(&self, id: &str, message: &PartialMessage) -> Result<()> {
         let mut messages = self.messages.lock().await;
@@ -269,4 +369,36 @@ impl AbstractMessages for ReferenceDb {
 
         Ok(())
     }
+
+    /// Fetch all forwards of messages originally sent in any of the given channels
+    async fn fetch_forwards_from_channels(&self, channels: &[String]) -> Result<Vec<Message>> {
+        Ok(self
+            .messages
+            .lock()
+            .await
+            .values()
+            .filter(|message| {
+                message.forwarded.as_ref().is_some_and(|forwarded| {
+                    !forwarded.deleted && channels.contains(&forwarded.channel)
+                })
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Strip content from forwards of the given messages and mark their original as deleted
+    async fn mark_forwards_as_deleted(&self, ids: &[String]) -> Result<()> {
+        for message in self.messages.lock().await.values_mut() {
+            if let Some(forwarded) = &mut message.forwarded {
+                if ids.contains(&forwarded.id) {
+                    forwarded.deleted = true;
+                    message.content = None;
+                    message.attachments = None;
+                    message.embeds = None;
+                }
+            }
+        }
+
+        Ok(())
+    }
 }
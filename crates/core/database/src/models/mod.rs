@@ -1,5 +1,8 @@
+mod account_strikes;
 mod admin_migrations;
 mod bots;
+mod channel_exports;
+mod channel_followers;
 mod channel_invites;
 mod channel_unreads;
 mod channel_webhooks;
@@ -8,14 +11,26 @@ mod emojis;
 mod files;
 mod messages;
 mod ratelimit_events;
+mod server_audit_log;
+mod server_auto_roles;
+mod server_automod;
 mod server_bans;
+mod server_events;
 mod server_members;
+mod server_raid_protection;
+mod server_role_jobs;
+mod server_screening;
+mod server_templates;
 mod servers;
+mod user_content_filters;
 mod user_settings;
 mod users;
 
+pub use account_strikes::*;
 pub use admin_migrations::*;
 pub use bots::*;
+pub use channel_exports::*;
+pub use channel_followers::*;
 pub use channel_invites::*;
 pub use channel_unreads::*;
 pub use channel_webhooks::*;
@@ -24,9 +39,18 @@ pub use emojis::*;
 pub use files::*;
 pub use messages::*;
 pub use ratelimit_events::*;
+pub use server_audit_log::*;
+pub use server_auto_roles::*;
+pub use server_automod::*;
 pub use server_bans::*;
+pub use server_events::*;
 pub use server_members::*;
+pub use server_raid_protection::*;
+pub use server_role_jobs::*;
+pub use server_screening::*;
+pub use server_templates::*;
 pub use servers::*;
+pub use user_content_filters::*;
 pub use user_settings::*;
 pub use users::*;
 
@@ -35,9 +59,12 @@ use crate::{Database, MongoDb, ReferenceDb};
 pub trait AbstractDatabase:
     Sync
     + Send
+    + account_strikes::AbstractAccountStrikes
     + admin_migrations::AbstractMigrations
     + bots::AbstractBots
     + channels::AbstractChannels
+    + channel_exports::AbstractChannelExports
+    + channel_followers::AbstractChannelFollowers
     + channel_invites::AbstractChannelInvites
     + channel_unreads::AbstractChannelUnreads
     + channel_webhooks::AbstractWebhooks
@@ -45,9 +72,18 @@ pub trait AbstractDatabase:
     + files::AbstractAttachments
     + messages::AbstractMessages
     + ratelimit_events::AbstractRatelimitEvents
+    + server_audit_log::AbstractServerAuditLog
+    + server_auto_roles::AbstractServerAutoRoles
+    + server_automod::AbstractServerAutomod
     + server_bans::AbstractServerBans
+    + server_events::AbstractServerEvents
     + server_members::AbstractServerMembers
+    + server_raid_protection::AbstractServerRaidProtection
+    + server_role_jobs::AbstractServerRoleJobs
+    + server_screening::AbstractServerScreening
+    + server_templates::AbstractServerTemplates
     + servers::AbstractServers
+    + user_content_filters::AbstractUserContentFilters
     + user_settings::AbstractUserSettings
     + users::AbstractUsers
 {
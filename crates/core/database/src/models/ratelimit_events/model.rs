@@ -20,6 +20,8 @@ auto_derived!(
     /// Event type
     pub enum RatelimitEventType {
         DiscriminatorChange,
+        ServerJoin,
+        VanityInviteChange,
     }
 );
 
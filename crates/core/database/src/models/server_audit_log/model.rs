@@ -0,0 +1,191 @@
+use std::time::{Duration, SystemTime};
+
+use revolt_models::v0::{AuditLogAction, AuditLogChanges};
+use revolt_result::Result;
+use serde_json::{Map, Value};
+use ulid::Ulid;
+
+use crate::Database;
+
+/// How long entries are kept in a server's audit log
+pub const AUDIT_LOG_RETENTION: Duration = Duration::from_secs(60 * 60 * 24 * 90);
+
+auto_derived!(
+    /// Audit Log Entry
+    pub struct AuditLogEntry {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this entry belongs to
+        pub server: String,
+        /// Id of the user who performed the action
+        pub actor: String,
+        /// Type of action that was performed
+        pub action: AuditLogAction,
+        /// Id of the object the action was performed on
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub target: Option<String>,
+        /// Reason given for the action
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+        /// Fields which were changed by the action
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub changes: Option<AuditLogChanges>,
+    }
+
+    /// Audit Log Query
+    pub struct AuditLogQuery {
+        /// Maximum number of entries to fetch
+        pub limit: i64,
+        /// Entry id before which entries should be fetched
+        pub before: Option<String>,
+        /// Only include actions performed by this user
+        pub actor: Option<String>,
+        /// Only include actions performed on this object
+        pub target: Option<String>,
+    }
+);
+
+impl AuditLogEntry {
+    /// Record an action in a server's audit log
+    ///
+    /// Failing to log an action never fails the action itself, errors are only reported.
+    pub async fn record(
+        db: &Database,
+        server: String,
+        actor: String,
+        action: AuditLogAction,
+        target: Option<String>,
+        reason: Option<String>,
+        changes: Option<AuditLogChanges>,
+    ) {
+        let entry = AuditLogEntry {
+            id: Ulid::new().to_string(),
+            server,
+            actor,
+            action,
+            target,
+            reason,
+            changes,
+        };
+
+        if let Err(err) = entry.create(db).await {
+            error!(
+                "Failed to record {:?} in the audit log of {}: {err:?}",
+                entry.action, entry.server
+            );
+        }
+    }
+
+    /// Record a new entry and prune any which have passed retention
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        db.insert_audit_log_entry(self).await?;
+
+        let cutoff = Ulid::from_datetime(SystemTime::now() - AUDIT_LOG_RETENTION);
+        db.delete_audit_log_entries_before(&self.server, &cutoff.to_string())
+            .await
+    }
+
+    /// Work out which fields differ between two snapshots of an object
+    pub fn diff<T: serde::Serialize>(before: &T, after: &T) -> Option<AuditLogChanges> {
+        let before = serde_json::to_value(before).ok()?;
+        let after = serde_json::to_value(after).ok()?;
+
+        match (before, after) {
+            (Value::Object(before), Value::Object(after)) => {
+                let mut old = Map::new();
+                let mut new = Map::new();
+
+                for key in before.keys().chain(after.keys()) {
+                    let (a, b) = (before.get(key), after.get(key));
+                    if a != b && !old.contains_key(key) {
+                        old.insert(key.clone(), a.cloned().unwrap_or_default());
+                        new.insert(key.clone(), b.cloned().unwrap_or_default());
+                    }
+                }
+
+                if old.is_empty() {
+                    None
+                } else {
+                    Some(AuditLogChanges {
+                        before: Value::Object(old),
+                        after: Value::Object(new),
+                    })
+                }
+            }
+            (before, after) => {
+                if before == after {
+                    None
+                } else {
+                    Some(AuditLogChanges { before, after })
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_models::v0::AuditLogAction;
+    use serde_json::json;
+    use ulid::Ulid;
+
+    use crate::{AuditLogEntry, AuditLogQuery};
+
+    #[test]
+    fn diff() {
+        let before = json!({ "name": "Moderators", "colour": "red", "rank": 1 });
+        let after = json!({ "name": "Moderators", "colour": "blue", "hoist": true });
+
+        let changes = AuditLogEntry::diff(&before, &after).unwrap();
+        assert_eq!(
+            changes.before,
+            json!({ "colour": "red", "rank": 1, "hoist": null })
+        );
+        assert_eq!(
+            changes.after,
+            json!({ "colour": "blue", "rank": null, "hoist": true })
+        );
+
+        assert!(AuditLogEntry::diff(&before, &before).is_none());
+
+        let changes = AuditLogEntry::diff(&Some("abc"), &None).unwrap();
+        assert_eq!(changes.before, json!("abc"));
+        assert_eq!(changes.after, json!(null));
+    }
+
+    #[async_std::test]
+    async fn record() {
+        database_test!(|db| async move {
+            let server = Ulid::new().to_string();
+
+            AuditLogEntry::record(
+                &db,
+                server.clone(),
+                "actor".to_string(),
+                AuditLogAction::MemberKick,
+                Some("target".to_string()),
+                Some("Spam".to_string()),
+                None,
+            )
+            .await;
+
+            let entries = db
+                .fetch_audit_log_entries(
+                    &server,
+                    AuditLogQuery {
+                        limit: 10,
+                        before: None,
+                        actor: None,
+                        target: None,
+                    },
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(entries.len(), 1);
+            assert_eq!(entries[0].action, AuditLogAction::MemberKick);
+            assert_eq!(entries[0].reason.as_deref(), Some("Spam"));
+        });
+    }
+}
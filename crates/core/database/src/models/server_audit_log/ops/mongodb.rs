@@ -0,0 +1,75 @@
+use mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{AuditLogEntry, AuditLogQuery};
+
+use super::AbstractServerAuditLog;
+
+static COL: &str = "server_audit_log";
+
+#[async_trait]
+impl AbstractServerAuditLog for MongoDb {
+    /// Insert a new audit log entry into the database
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()> {
+        query!(self, insert_one, COL, &entry).map(|_| ())
+    }
+
+    /// Fetch audit log entries of a server, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>> {
+        let mut filter = doc! {
+            "server": server_id
+        };
+
+        if let Some(before) = query.before {
+            filter.insert(
+                "_id",
+                doc! {
+                    "$lt": before
+                },
+            );
+        }
+
+        if let Some(actor) = query.actor {
+            filter.insert("actor", actor);
+        }
+
+        if let Some(target) = query.target {
+            filter.insert("target", target);
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(query.limit)
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Delete all audit log entries of a server created before the given id
+    async fn delete_audit_log_entries_before(&self, server_id: &str, id: &str) -> Result<()> {
+        self.col::<AuditLogEntry>(COL)
+            .delete_many(
+                doc! {
+                    "server": server_id,
+                    "_id": {
+                        "$lt": id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("delete_many", COL))
+    }
+}
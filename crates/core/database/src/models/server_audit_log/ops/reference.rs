@@ -0,0 +1,59 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{AuditLogEntry, AuditLogQuery};
+
+use super::AbstractServerAuditLog;
+
+#[async_trait]
+impl AbstractServerAuditLog for ReferenceDb {
+    /// Insert a new audit log entry into the database
+    async fn insert_audit_log_entry(&self, entry: &AuditLogEntry) -> Result<()> {
+        let mut server_audit_log = self.server_audit_log.lock().await;
+        if server_audit_log.contains_key(&entry.id) {
+            Err(create_database_error!("insert", "audit_log_entry"))
+        } else {
+            server_audit_log.insert(entry.id.to_string(), entry.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch audit log entries of a server, newest first
+    async fn fetch_audit_log_entries(
+        &self,
+        server_id: &str,
+        query: AuditLogQuery,
+    ) -> Result<Vec<AuditLogEntry>> {
+        let server_audit_log = self.server_audit_log.lock().await;
+        let mut entries: Vec<AuditLogEntry> = server_audit_log
+            .values()
+            .filter(|entry| {
+                entry.server == server_id
+                    && query
+                        .before
+                        .as_ref()
+                        .map_or(true, |before| &entry.id < before)
+                    && query
+                        .actor
+                        .as_ref()
+                        .map_or(true, |actor| &entry.actor == actor)
+                    && query
+                        .target
+                        .as_ref()
+                        .map_or(true, |target| entry.target.as_ref() == Some(target))
+            })
+            .cloned()
+            .collect();
+
+        entries.sort_by(|a, b| b.id.cmp(&a.id));
+        entries.truncate(query.limit as usize);
+        Ok(entries)
+    }
+
+    /// Delete all audit log entries of a server created before the given id
+    async fn delete_audit_log_entries_before(&self, server_id: &str, id: &str) -> Result<()> {
+        let mut server_audit_log = self.server_audit_log.lock().await;
+        server_audit_log.retain(|_, entry| entry.server != server_id || entry.id.as_str() >= id);
+        Ok(())
+    }
+}
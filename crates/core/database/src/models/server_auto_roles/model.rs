@@ -0,0 +1,547 @@
+use std::time::{Duration, SystemTime};
+
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::AutoRoleTrigger;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+
+use crate::{
+    util::permissions::DatabasePermissionQuery, Channel, Database, Member, MemberQuery,
+    PartialMember, Server,
+};
+
+/// Maximum number of auto role rules a server may have
+pub const MAX_AUTO_ROLE_RULES: usize = 25;
+
+/// Longest tenure a rule may wait for before giving out its role
+const MAX_TENURE_DAYS: u32 = 3_650;
+
+/// Number of members checked at a time when applying tenure rules
+const PAGE_SIZE: i64 = 100;
+
+auto_derived_partial!(
+    /// Rule which gives members a role automatically
+    pub struct AutoRoleRule {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this rule belongs to
+        pub server: String,
+        /// Id of the user who created this rule
+        pub creator: String,
+        /// Id of the role given out by this rule
+        pub role: String,
+        /// Condition under which the role is given out
+        pub trigger: AutoRoleTrigger,
+        /// Members who joined before this time have already been handled by a tenure rule
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub applied_until: Option<Timestamp>,
+    },
+    "PartialAutoRoleRule"
+);
+
+/// Find the point in time a member must have joined by to satisfy a tenure of some days
+fn tenure_cutoff(days: u32) -> Timestamp {
+    Timestamp::from(SystemTime::now() - Duration::from_secs(days as u64 * 86_400))
+}
+
+/// Check whether a member is ranked below a given ranking
+fn is_ranked_below(server: &Server, member: &Member, ranking: i64) -> bool {
+    member.id.user != server.owner && member.get_ranking(server) > ranking
+}
+
+#[allow(clippy::disallowed_methods)]
+impl AutoRoleRule {
+    /// Create a new auto role rule
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        if db.fetch_auto_role_rules(&self.server).await?.len() >= MAX_AUTO_ROLE_RULES {
+            return Err(create_error!(TooManyAutoRoleRules {
+                max: MAX_AUTO_ROLE_RULES
+            }));
+        }
+
+        match &self.trigger {
+            AutoRoleTrigger::Join => {}
+            AutoRoleTrigger::Tenure { days } => {
+                if *days == 0 || *days > MAX_TENURE_DAYS {
+                    return Err(create_error!(InvalidProperty));
+                }
+            }
+            AutoRoleTrigger::Reaction {
+                channel, message, ..
+            } => {
+                match db.fetch_channel(channel).await? {
+                    Channel::TextChannel { server, .. }
+                    | Channel::VoiceChannel { server, .. }
+                    | Channel::ForumPost { server, .. }
+                        if server == self.server => {}
+                    _ => return Err(create_error!(UnknownChannel)),
+                }
+
+                if db.fetch_message(message).await?.channel != *channel {
+                    return Err(create_error!(UnknownMessage));
+                }
+            }
+        }
+
+        db.insert_auto_role_rule(self).await
+    }
+
+    /// Delete this auto role rule
+    pub async fn delete(self, db: &Database) -> Result<()> {
+        db.delete_auto_role_rule(&self.id).await
+    }
+
+    /// Find the ranking of this rule's creator if they may still give out its role
+    ///
+    /// The creator may have lost their permissions or been outranked since the rule was made,
+    /// in which case the rule does nothing until they regain them.
+    async fn creator_ranking(&self, db: &Database, server: &Server) -> Result<Option<i64>> {
+        let Some(role) = server.roles.get(&self.role) else {
+            return Ok(None);
+        };
+
+        let creator = db.fetch_user(&self.creator).await?;
+        let mut query = DatabasePermissionQuery::new(db, &creator).server(server);
+        if !calculate_server_permissions(&mut query)
+            .await
+            .has_channel_permission(ChannelPermission::AssignRoles)
+        {
+            return Ok(None);
+        }
+
+        let ranking = server.get_member_ranking(db, &self.creator).await?;
+        Ok((role.rank > ranking).then_some(ranking))
+    }
+
+    /// Find the roles a new member of a server should be given
+    ///
+    /// Members who still have to pass screening receive these once they are let in.
+    pub async fn join_roles(db: &Database, server: &Server) -> Result<Vec<String>> {
+        let mut roles = vec![];
+        for rule in db.fetch_auto_role_rules(&server.id).await? {
+            if matches!(rule.trigger, AutoRoleTrigger::Join)
+                && matches!(rule.creator_ranking(db, server).await, Ok(Some(_)))
+                && !roles.contains(&rule.role)
+            {
+                roles.push(rule.role);
+            }
+        }
+
+        Ok(roles)
+    }
+
+    /// Give out roles held back while a member was pending screening
+    ///
+    /// This covers join rules as well as any tenure the member has already reached.
+    pub async fn apply_admission(db: &Database, member: &mut Member) -> Result<()> {
+        // Any role would keep a temporary membership around for good
+        if member.temporary {
+            return Ok(());
+        }
+
+        let server = db.fetch_server(&member.id.server).await?;
+        let mut roles = member.roles.clone();
+
+        for rule in db.fetch_auto_role_rules(&server.id).await? {
+            let earned = match rule.trigger {
+                AutoRoleTrigger::Join => true,
+                AutoRoleTrigger::Tenure { days } => *member.joined_at <= *tenure_cutoff(days),
+                AutoRoleTrigger::Reaction { .. } => false,
+            };
+
+            if earned
+                && !roles.contains(&rule.role)
+                && matches!(
+                    rule.creator_ranking(db, &server).await,
+                    Ok(Some(ranking)) if is_ranked_below(&server, member, ranking)
+                )
+            {
+                roles.push(rule.role);
+            }
+        }
+
+        if roles.len() == member.roles.len() {
+            return Ok(());
+        }
+
+        member
+            .update(
+                db,
+                PartialMember {
+                    roles: Some(roles),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+    }
+
+    /// Give out roles for a reaction added to a message
+    pub async fn apply_reaction(
+        db: &Database,
+        server_id: &str,
+        message_id: &str,
+        emoji: &str,
+        user_id: &str,
+    ) -> Result<()> {
+        let rules: Vec<AutoRoleRule> = db
+            .fetch_reaction_auto_role_rules(message_id)
+            .await?
+            .into_iter()
+            .filter(|rule| match &rule.trigger {
+                AutoRoleTrigger::Reaction {
+                    emoji: expected, ..
+                } => rule.server == server_id && expected == emoji,
+                _ => false,
+            })
+            .collect();
+
+        if rules.is_empty() {
+            return Ok(());
+        }
+
+        let server = db.fetch_server(server_id).await?;
+        let mut member = db.fetch_member(server_id, user_id).await?;
+        if member.temporary {
+            return Ok(());
+        }
+
+        for rule in rules {
+            if member.roles.contains(&rule.role) {
+                continue;
+            }
+
+            // Members ranked at or above the creator are left alone
+            if let Ok(Some(ranking)) = rule.creator_ranking(db, &server).await {
+                if is_ranked_below(&server, &member, ranking) {
+                    member.add_role(db, &rule.role).await?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Give out this rule's role to members who have been around long enough
+    ///
+    /// Each member is only considered once, when they first reach the tenure,
+    /// so that anyone who has the role taken away by hand keeps it that way.
+    pub async fn apply_tenure(&mut self, db: &Database) -> Result<()> {
+        let AutoRoleTrigger::Tenure { days } = self.trigger else {
+            return Err(create_error!(InvalidOperation));
+        };
+
+        let server = db.fetch_server(&self.server).await?;
+        let Some(our_ranking) = self.creator_ranking(db, &server).await? else {
+            return Ok(());
+        };
+
+        let cutoff = tenure_cutoff(days);
+        let mut after = None;
+
+        loop {
+            let members = db
+                .fetch_members_by_query(MemberQuery {
+                    server: self.server.clone(),
+                    role: None,
+                    joined_after: self.applied_until,
+                    joined_before: Some(cutoff),
+                    after: after.clone(),
+                    limit: PAGE_SIZE,
+                })
+                .await?;
+
+            let Some(last) = members.last() else {
+                break;
+            };
+
+            after = Some(last.id.user.clone());
+            let count = members.len() as i64;
+
+            // Pending members are given the role once they are let in,
+            // temporary members never receive it
+            for mut member in members {
+                if !member.is_pending()
+                    && !member.temporary
+                    && !member.roles.contains(&self.role)
+                    && is_ranked_below(&server, &member, our_ranking)
+                {
+                    member.add_role(db, &self.role).await?;
+                }
+            }
+
+            if count < PAGE_SIZE {
+                break;
+            }
+        }
+
+        let partial = PartialAutoRoleRule {
+            applied_until: Some(cutoff),
+            ..Default::default()
+        };
+
+        db.update_auto_role_rule(&self.id, &partial).await?;
+        self.apply_options(partial);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::time::{Duration, SystemTime};
+
+    use iso8601_timestamp::Timestamp;
+    use revolt_models::v0::AutoRoleTrigger;
+    use revolt_permissions::OverrideField;
+
+    use crate::{
+        AutoRoleRule, Database, Member, MemberCompositeKey, MemberScreening, Role, Server, User,
+    };
+
+    /// Create a server with a single role and return the id of its owner
+    async fn setup(db: &Database) -> String {
+        let owner = User::create(db, "Owner".to_string(), None, None)
+            .await
+            .unwrap();
+
+        let role = Role {
+            name: "Notifications".to_string(),
+            permissions: OverrideField::default(),
+            colour: None,
+            icon: None,
+            emoji: None,
+            hoist: false,
+            mentionable: false,
+            rank: 1,
+        };
+
+        db.insert_server(&Server {
+            id: "server".to_string(),
+            owner: owner.id.clone(),
+            roles: HashMap::from([("role".to_string(), role)]),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+        owner.id
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn insert_member(db: &Database, user: &str, days: u64, pending: bool) {
+        db.insert_member(&Member {
+            id: MemberCompositeKey {
+                server: "server".to_string(),
+                user: user.to_string(),
+            },
+            joined_at: Timestamp::from(SystemTime::now() - Duration::from_secs(days * 86_400)),
+            pending: pending.then(MemberScreening::default),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    }
+
+    #[allow(clippy::disallowed_methods)]
+    async fn insert_rule(db: &Database, id: &str, creator: &str, trigger: AutoRoleTrigger) {
+        db.insert_auto_role_rule(&AutoRoleRule {
+            id: id.to_string(),
+            server: "server".to_string(),
+            creator: creator.to_string(),
+            role: "role".to_string(),
+            trigger,
+            applied_until: None,
+        })
+        .await
+        .unwrap();
+    }
+
+    async fn roles(db: &Database, user: &str) -> Vec<String> {
+        db.fetch_member("server", user).await.unwrap().roles
+    }
+
+    #[async_std::test]
+    async fn reaction_roles() {
+        database_test!(|db| async move {
+            let owner = setup(&db).await;
+            insert_member(&db, "user", 0, false).await;
+
+            let invalid = AutoRoleRule {
+                id: "invalid".to_string(),
+                server: "server".to_string(),
+                creator: owner.clone(),
+                role: "role".to_string(),
+                trigger: AutoRoleTrigger::Tenure { days: 0 },
+                applied_until: None,
+            };
+
+            assert!(invalid.create(&db).await.is_err());
+
+            insert_rule(
+                &db,
+                "rule",
+                &owner,
+                AutoRoleTrigger::Reaction {
+                    channel: "channel".to_string(),
+                    message: "message".to_string(),
+                    emoji: "👍".to_string(),
+                },
+            )
+            .await;
+
+            AutoRoleRule::apply_reaction(&db, "server", "message", "👎", "user")
+                .await
+                .unwrap();
+
+            assert!(roles(&db, "user").await.is_empty());
+
+            AutoRoleRule::apply_reaction(&db, "server", "message", "👍", "user")
+                .await
+                .unwrap();
+
+            assert_eq!(roles(&db, "user").await, vec!["role".to_string()]);
+        });
+    }
+
+    #[async_std::test]
+    async fn creator_must_assign_roles() {
+        database_test!(|db| async move {
+            setup(&db).await;
+
+            // Members have no permissions in this server by default
+            let moderator = User::create(&db, "Moderator".to_string(), None, None)
+                .await
+                .unwrap();
+
+            insert_member(&db, &moderator.id, 0, false).await;
+            insert_member(&db, "user", 30, false).await;
+
+            insert_rule(
+                &db,
+                "reaction",
+                &moderator.id,
+                AutoRoleTrigger::Reaction {
+                    channel: "channel".to_string(),
+                    message: "message".to_string(),
+                    emoji: "👍".to_string(),
+                },
+            )
+            .await;
+
+            insert_rule(
+                &db,
+                "tenure",
+                &moderator.id,
+                AutoRoleTrigger::Tenure { days: 7 },
+            )
+            .await;
+            insert_rule(&db, "join", &moderator.id, AutoRoleTrigger::Join).await;
+
+            AutoRoleRule::apply_reaction(&db, "server", "message", "👍", "user")
+                .await
+                .unwrap();
+
+            let mut rule = db.fetch_auto_role_rule("tenure").await.unwrap();
+            rule.apply_tenure(&db).await.unwrap();
+
+            let server = db.fetch_server("server").await.unwrap();
+            assert!(AutoRoleRule::join_roles(&db, &server)
+                .await
+                .unwrap()
+                .is_empty());
+
+            assert!(roles(&db, "user").await.is_empty());
+        });
+    }
+
+    #[async_std::test]
+    async fn tenure_roles() {
+        database_test!(|db| async move {
+            let owner = setup(&db).await;
+            insert_member(&db, "veteran", 30, false).await;
+            insert_member(&db, "newcomer", 1, false).await;
+            insert_rule(&db, "rule", &owner, AutoRoleTrigger::Tenure { days: 7 }).await;
+
+            let mut rule = db.fetch_auto_role_rule("rule").await.unwrap();
+            rule.apply_tenure(&db).await.unwrap();
+
+            assert_eq!(roles(&db, "veteran").await, vec!["role".to_string()]);
+            assert!(roles(&db, "newcomer").await.is_empty());
+
+            // Roles taken away by hand are not given back
+            db.fetch_member("server", "veteran")
+                .await
+                .unwrap()
+                .remove_role(&db, "role")
+                .await
+                .unwrap();
+
+            let mut rule = db.fetch_auto_role_rule("rule").await.unwrap();
+            assert!(rule.applied_until.is_some());
+            rule.apply_tenure(&db).await.unwrap();
+
+            assert!(roles(&db, "veteran").await.is_empty());
+        });
+    }
+
+    #[async_std::test]
+    async fn roles_wait_for_screening() {
+        database_test!(|db| async move {
+            let owner = setup(&db).await;
+            insert_member(&db, "user", 30, true).await;
+            insert_rule(&db, "rule", &owner, AutoRoleTrigger::Tenure { days: 7 }).await;
+
+            let mut rule = db.fetch_auto_role_rule("rule").await.unwrap();
+            rule.apply_tenure(&db).await.unwrap();
+
+            assert!(roles(&db, "user").await.is_empty());
+
+            db.fetch_member("server", "user")
+                .await
+                .unwrap()
+                .approve_screening(&db)
+                .await
+                .unwrap();
+
+            assert_eq!(roles(&db, "user").await, vec!["role".to_string()]);
+        });
+    }
+
+    #[async_std::test]
+    #[allow(clippy::disallowed_methods)]
+    async fn temporary_members() {
+        database_test!(|db| async move {
+            let owner = setup(&db).await;
+            db.insert_member(&Member {
+                id: MemberCompositeKey {
+                    server: "server".to_string(),
+                    user: "user".to_string(),
+                },
+                joined_at: Timestamp::from(SystemTime::now() - Duration::from_secs(30 * 86_400)),
+                pending: Some(MemberScreening::default()),
+                temporary: true,
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+            insert_rule(&db, "join", &owner, AutoRoleTrigger::Join).await;
+            insert_rule(&db, "tenure", &owner, AutoRoleTrigger::Tenure { days: 7 }).await;
+
+            // A role would stop the membership from being cleaned up
+            db.fetch_member("server", "user")
+                .await
+                .unwrap()
+                .approve_screening(&db)
+                .await
+                .unwrap();
+
+            let mut rule = db.fetch_auto_role_rule("tenure").await.unwrap();
+            rule.apply_tenure(&db).await.unwrap();
+
+            assert!(roles(&db, "user").await.is_empty());
+        });
+    }
+}
@@ -0,0 +1,30 @@
+use revolt_result::Result;
+
+use crate::{AutoRoleRule, PartialAutoRoleRule};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerAutoRoles: Sync + Send {
+    /// Insert a new auto role rule into the database
+    async fn insert_auto_role_rule(&self, rule: &AutoRoleRule) -> Result<()>;
+
+    /// Fetch an auto role rule by its id
+    async fn fetch_auto_role_rule(&self, id: &str) -> Result<AutoRoleRule>;
+
+    /// Fetch all auto role rules of a server
+    async fn fetch_auto_role_rules(&self, server_id: &str) -> Result<Vec<AutoRoleRule>>;
+
+    /// Fetch all auto role rules which trigger on reactions to a message
+    async fn fetch_reaction_auto_role_rules(&self, message_id: &str) -> Result<Vec<AutoRoleRule>>;
+
+    /// Fetch all auto role rules which trigger on tenure, across every server
+    async fn fetch_tenure_auto_role_rules(&self) -> Result<Vec<AutoRoleRule>>;
+
+    /// Update an auto role rule with new information
+    async fn update_auto_role_rule(&self, id: &str, partial: &PartialAutoRoleRule) -> Result<()>;
+
+    /// Delete an auto role rule by its id
+    async fn delete_auto_role_rule(&self, id: &str) -> Result<()>;
+}
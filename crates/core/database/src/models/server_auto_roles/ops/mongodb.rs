@@ -0,0 +1,68 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{AutoRoleRule, PartialAutoRoleRule};
+
+use super::AbstractServerAutoRoles;
+
+static COL: &str = "server_auto_roles";
+
+#[async_trait]
+impl AbstractServerAutoRoles for MongoDb {
+    /// Insert a new auto role rule into the database
+    async fn insert_auto_role_rule(&self, rule: &AutoRoleRule) -> Result<()> {
+        query!(self, insert_one, COL, &rule).map(|_| ())
+    }
+
+    /// Fetch an auto role rule by its id
+    async fn fetch_auto_role_rule(&self, id: &str) -> Result<AutoRoleRule> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all auto role rules of a server
+    async fn fetch_auto_role_rules(&self, server_id: &str) -> Result<Vec<AutoRoleRule>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "server": server_id
+            }
+        )
+    }
+
+    /// Fetch all auto role rules which trigger on reactions to a message
+    async fn fetch_reaction_auto_role_rules(&self, message_id: &str) -> Result<Vec<AutoRoleRule>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "trigger.type": "Reaction",
+                "trigger.message": message_id
+            }
+        )
+    }
+
+    /// Fetch all auto role rules which trigger on tenure, across every server
+    async fn fetch_tenure_auto_role_rules(&self) -> Result<Vec<AutoRoleRule>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "trigger.type": "Tenure"
+            }
+        )
+    }
+
+    /// Update an auto role rule with new information
+    async fn update_auto_role_rule(&self, id: &str, partial: &PartialAutoRoleRule) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+
+    /// Delete an auto role rule by its id
+    async fn delete_auto_role_rule(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
@@ -0,0 +1,84 @@
+use revolt_models::v0::AutoRoleTrigger;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{AutoRoleRule, PartialAutoRoleRule};
+
+use super::AbstractServerAutoRoles;
+
+#[async_trait]
+impl AbstractServerAutoRoles for ReferenceDb {
+    /// Insert a new auto role rule into the database
+    async fn insert_auto_role_rule(&self, rule: &AutoRoleRule) -> Result<()> {
+        let mut server_auto_roles = self.server_auto_roles.lock().await;
+        if server_auto_roles.contains_key(&rule.id) {
+            Err(create_database_error!("insert", "auto_role_rule"))
+        } else {
+            server_auto_roles.insert(rule.id.to_string(), rule.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch an auto role rule by its id
+    async fn fetch_auto_role_rule(&self, id: &str) -> Result<AutoRoleRule> {
+        let server_auto_roles = self.server_auto_roles.lock().await;
+        server_auto_roles
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all auto role rules of a server
+    async fn fetch_auto_role_rules(&self, server_id: &str) -> Result<Vec<AutoRoleRule>> {
+        let server_auto_roles = self.server_auto_roles.lock().await;
+        Ok(server_auto_roles
+            .values()
+            .filter(|rule| rule.server == server_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch all auto role rules which trigger on reactions to a message
+    async fn fetch_reaction_auto_role_rules(&self, message_id: &str) -> Result<Vec<AutoRoleRule>> {
+        let server_auto_roles = self.server_auto_roles.lock().await;
+        Ok(server_auto_roles
+            .values()
+            .filter(|rule| match &rule.trigger {
+                AutoRoleTrigger::Reaction { message, .. } => message == message_id,
+                _ => false,
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch all auto role rules which trigger on tenure, across every server
+    async fn fetch_tenure_auto_role_rules(&self) -> Result<Vec<AutoRoleRule>> {
+        let server_auto_roles = self.server_auto_roles.lock().await;
+        Ok(server_auto_roles
+            .values()
+            .filter(|rule| matches!(rule.trigger, AutoRoleTrigger::Tenure { .. }))
+            .cloned()
+            .collect())
+    }
+
+    /// Update an auto role rule with new information
+    async fn update_auto_role_rule(&self, id: &str, partial: &PartialAutoRoleRule) -> Result<()> {
+        let mut server_auto_roles = self.server_auto_roles.lock().await;
+        if let Some(rule) = server_auto_roles.get_mut(id) {
+            rule.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete an auto role rule by its id
+    async fn delete_auto_role_rule(&self, id: &str) -> Result<()> {
+        let mut server_auto_roles = self.server_auto_roles.lock().await;
+        if server_auto_roles.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
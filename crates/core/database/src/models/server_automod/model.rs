@@ -0,0 +1,375 @@
+use std::time::Duration;
+
+use iso8601_timestamp::Timestamp;
+use once_cell::sync::Lazy;
+use regex::{Regex, RegexBuilder};
+use revolt_models::v0::{AutomodAction, AutomodTrigger};
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{util::expiry::expires_in, Database, File, PartialMember, SystemMessage};
+
+/// Maximum number of automod rules a server may have
+pub const MAX_AUTOMOD_RULES: usize = 25;
+
+/// Maximum number of keywords on a single rule
+const MAX_KEYWORDS: usize = 100;
+
+/// Maximum number of regular expressions on a single rule
+const MAX_PATTERNS: usize = 10;
+
+/// Maximum length of a single keyword or regular expression
+const MAX_PATTERN_LENGTH: usize = 256;
+
+/// Longest timeout an automod rule may hand out (28 days)
+const MAX_TIMEOUT: u64 = 28 * 24 * 60 * 60;
+
+static RE_INVITE: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(
+        r"(?i)\b(?:rvlt\.gg|(?:app\.)?revolt\.chat/invite|discord(?:app)?\.(?:gg|com/invite))/[a-z0-9-]+",
+    )
+    .unwrap()
+});
+
+auto_derived_partial!(
+    /// Automod Rule
+    pub struct AutomodRule {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this rule belongs to
+        pub server: String,
+        /// Name of the rule
+        pub name: String,
+        /// Whether this rule is currently being enforced
+        pub enabled: bool,
+        /// Condition which causes this rule to fire
+        pub trigger: AutomodTrigger,
+        /// Actions taken when this rule fires
+        pub actions: Vec<AutomodAction>,
+        /// Roles which are not subject to this rule
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub exempt_roles: Vec<String>,
+        /// Channels in which this rule is not enforced
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub exempt_channels: Vec<String>,
+    },
+    "PartialAutomodRule"
+);
+
+/// Message being checked against a server's automod rules
+pub struct AutomodSubject<'a> {
+    /// Id of the channel the message is in
+    pub channel: &'a str,
+    /// Id of the message author
+    pub author: &'a str,
+    /// Message content
+    pub content: Option<&'a str>,
+    /// Number of unique users mentioned
+    pub mentions: usize,
+    /// Attachments on the message
+    pub attachments: &'a [File],
+}
+
+/// Outcome of running automod against a message
+#[derive(Debug, PartialEq, Eq)]
+pub enum AutomodVerdict {
+    /// Message may go through
+    Allow,
+    /// Message must be rejected
+    Block { rule: String },
+    /// Message must be removed
+    Delete { rule: String },
+}
+
+#[allow(clippy::disallowed_methods)]
+impl AutomodRule {
+    /// Create a new automod rule
+    pub async fn create(&self, db: &Database) -> Result<()> {
+        if db.fetch_automod_rules(&self.server).await?.len() >= MAX_AUTOMOD_RULES {
+            return Err(create_error!(TooManyAutomodRules {
+                max: MAX_AUTOMOD_RULES
+            }));
+        }
+
+        Self::validate_trigger(&self.trigger)?;
+        for action in &self.actions {
+            Self::validate_action(action)?;
+        }
+
+        db.insert_automod_rule(self).await
+    }
+
+    /// Update automod rule data
+    pub async fn update(&mut self, db: &Database, partial: PartialAutomodRule) -> Result<()> {
+        if let Some(trigger) = &partial.trigger {
+            Self::validate_trigger(trigger)?;
+        }
+
+        for action in partial.actions.iter().flatten() {
+            Self::validate_action(action)?;
+        }
+
+        self.apply_options(partial.clone());
+        db.update_automod_rule(&self.id, &partial).await
+    }
+
+    /// Delete this automod rule
+    pub async fn delete(self, db: &Database) -> Result<()> {
+        db.delete_automod_rule(&self.id).await
+    }
+
+    /// Ensure a trigger is within limits and all its patterns compile
+    pub fn validate_trigger(trigger: &AutomodTrigger) -> Result<()> {
+        match trigger {
+            AutomodTrigger::Keyword { keywords, patterns } => {
+                if (keywords.is_empty() && patterns.is_empty())
+                    || keywords.len() > MAX_KEYWORDS
+                    || patterns.len() > MAX_PATTERNS
+                    || keywords
+                        .iter()
+                        .chain(patterns)
+                        .any(|entry| entry.is_empty() || entry.len() > MAX_PATTERN_LENGTH)
+                {
+                    return Err(create_error!(InvalidProperty));
+                }
+
+                for pattern in patterns {
+                    compile(pattern).map_err(|_| create_error!(InvalidProperty))?;
+                }
+            }
+            AutomodTrigger::Attachment { blocked } => {
+                if blocked.is_empty() || blocked.len() > MAX_KEYWORDS {
+                    return Err(create_error!(InvalidProperty));
+                }
+            }
+            AutomodTrigger::MentionSpam { .. }
+            | AutomodTrigger::InviteLink
+            | AutomodTrigger::NewAccount { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    /// Ensure a single action is within limits
+    pub fn validate_action(action: &AutomodAction) -> Result<()> {
+        match action {
+            AutomodAction::Timeout { duration } => {
+                if *duration == 0 || *duration > MAX_TIMEOUT {
+                    return Err(create_error!(InvalidProperty));
+                }
+            }
+            AutomodAction::Block | AutomodAction::Delete | AutomodAction::Alert { .. } => {}
+        }
+
+        Ok(())
+    }
+
+    /// Check whether a message trips this rule
+    pub fn is_triggered_by(&self, subject: &AutomodSubject) -> bool {
+        match &self.trigger {
+            AutomodTrigger::Keyword { keywords, patterns } => {
+                let Some(content) = subject.content else {
+                    return false;
+                };
+
+                let lowercase = content.to_lowercase();
+                keywords
+                    .iter()
+                    .any(|keyword| lowercase.contains(&keyword.to_lowercase()))
+                    || patterns.iter().any(|pattern| {
+                        compile(pattern)
+                            .map(|regex| regex.is_match(content))
+                            .unwrap_or_default()
+                    })
+            }
+            AutomodTrigger::MentionSpam { limit } => subject.mentions > *limit as usize,
+            AutomodTrigger::InviteLink => subject
+                .content
+                .is_some_and(|content| RE_INVITE.is_match(content)),
+            AutomodTrigger::NewAccount { min_age } => Ulid::from_string(subject.author)
+                .ok()
+                .and_then(|ulid| ulid.datetime().elapsed().ok())
+                .is_some_and(|age| age < Duration::from_secs(*min_age)),
+            AutomodTrigger::Attachment { blocked } => subject.attachments.iter().any(|file| {
+                let filename = file.filename.to_lowercase();
+                blocked.iter().any(|entry| {
+                    let entry = entry.to_lowercase();
+                    if entry.starts_with('.') {
+                        filename.ends_with(&entry)
+                    } else {
+                        file.content_type.to_lowercase().starts_with(&entry)
+                    }
+                })
+            }),
+        }
+    }
+
+    /// Run a server's automod rules against a message and carry out their actions
+    ///
+    /// Timeouts and alerts are applied here, it is up to the caller
+    /// to act on the returned verdict.
+    pub async fn enforce(
+        db: &Database,
+        server_id: &str,
+        subject: AutomodSubject<'_>,
+    ) -> Result<AutomodVerdict> {
+        let rules: Vec<AutomodRule> = db
+            .fetch_automod_rules(server_id)
+            .await?
+            .into_iter()
+            .filter(|rule| {
+                rule.enabled && !rule.exempt_channels.iter().any(|c| c == subject.channel)
+            })
+            .collect();
+
+        if rules.is_empty() {
+            return Ok(AutomodVerdict::Allow);
+        }
+
+        // Server owners are never subject to automod
+        let server = db.fetch_server(server_id).await?;
+        if server.owner == subject.author {
+            return Ok(AutomodVerdict::Allow);
+        }
+
+        let mut member = db.fetch_member(server_id, subject.author).await.ok();
+        let mut verdict = AutomodVerdict::Allow;
+
+        for rule in rules {
+            if member.as_ref().is_some_and(|member| {
+                member
+                    .roles
+                    .iter()
+                    .any(|role| rule.exempt_roles.contains(role))
+            }) || !rule.is_triggered_by(&subject)
+            {
+                continue;
+            }
+
+            for action in &rule.actions {
+                match action {
+                    AutomodAction::Block => {
+                        if verdict == AutomodVerdict::Allow {
+                            verdict = AutomodVerdict::Block {
+                                rule: rule.name.clone(),
+                            };
+                        }
+                    }
+                    AutomodAction::Delete => {
+                        if !matches!(verdict, AutomodVerdict::Delete { .. }) {
+                            verdict = AutomodVerdict::Delete {
+                                rule: rule.name.clone(),
+                            };
+                        }
+                    }
+                    AutomodAction::Timeout { duration } => {
+                        if let (Some(member), Ok(until)) = (&mut member, expires_in(*duration)) {
+                            if member.timeout.map_or(true, |timeout| *timeout < *until) {
+                                member
+                                    .update(
+                                        db,
+                                        PartialMember {
+                                            timeout: Some(until),
+                                            ..Default::default()
+                                        },
+                                        vec![],
+                                    )
+                                    .await
+                                    .ok();
+                            }
+                        }
+                    }
+                    AutomodAction::Alert { channel } => {
+                        if !server.channels.contains(channel) {
+                            continue;
+                        }
+
+                        if let Ok(channel) = db.fetch_channel(channel).await {
+                            SystemMessage::Text {
+                                content: format!(
+                                    "Automod rule **{}** was triggered by <@{}> in <#{}>.",
+                                    rule.name, subject.author, subject.channel
+                                ),
+                            }
+                            .into_message(channel.id())
+                            .send_without_notifications(db, false, false)
+                            .await
+                            .ok();
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(verdict)
+    }
+}
+
+/// Compile a user-provided regular expression with conservative limits
+fn compile(pattern: &str) -> std::result::Result<Regex, regex::Error> {
+    RegexBuilder::new(pattern)
+        .case_insensitive(true)
+        .size_limit(1 << 16)
+        .build()
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_models::v0::{AutomodAction, AutomodTrigger};
+    use revolt_result::ErrorType;
+    use ulid::Ulid;
+
+    use super::MAX_TIMEOUT;
+    use crate::{AutomodRule, PartialAutomodRule};
+
+    #[test]
+    fn validate_action() {
+        assert!(AutomodRule::validate_action(&AutomodAction::Timeout { duration: 60 }).is_ok());
+        assert!(AutomodRule::validate_action(&AutomodAction::Timeout { duration: 0 }).is_err());
+        assert!(AutomodRule::validate_action(&AutomodAction::Timeout {
+            duration: MAX_TIMEOUT + 1
+        })
+        .is_err());
+    }
+
+    #[async_std::test]
+    async fn reject_long_timeout() {
+        database_test!(|db| async move {
+            let mut rule = AutomodRule {
+                id: Ulid::new().to_string(),
+                server: Ulid::new().to_string(),
+                name: "Invites".to_string(),
+                enabled: true,
+                trigger: AutomodTrigger::InviteLink,
+                actions: vec![AutomodAction::Timeout {
+                    duration: MAX_TIMEOUT + 1,
+                }],
+                exempt_roles: vec![],
+                exempt_channels: vec![],
+            };
+
+            let error = rule.create(&db).await.unwrap_err();
+            assert_eq!(error.error_type, ErrorType::InvalidProperty);
+
+            rule.actions = vec![AutomodAction::Block];
+            rule.create(&db).await.unwrap();
+
+            let error = rule
+                .update(
+                    &db,
+                    PartialAutomodRule {
+                        actions: Some(vec![AutomodAction::Timeout {
+                            duration: MAX_TIMEOUT + 1,
+                        }]),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap_err();
+
+            assert_eq!(error.error_type, ErrorType::InvalidProperty);
+            assert_eq!(rule.actions, vec![AutomodAction::Block]);
+        });
+    }
+}
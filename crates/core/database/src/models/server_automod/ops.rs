@@ -0,0 +1,24 @@
+use revolt_result::Result;
+
+use crate::{AutomodRule, PartialAutomodRule};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerAutomod: Sync + Send {
+    /// Insert a new automod rule into the database
+    async fn insert_automod_rule(&self, rule: &AutomodRule) -> Result<()>;
+
+    /// Fetch an automod rule by its id
+    async fn fetch_automod_rule(&self, id: &str) -> Result<AutomodRule>;
+
+    /// Fetch all automod rules of a server
+    async fn fetch_automod_rules(&self, server_id: &str) -> Result<Vec<AutomodRule>>;
+
+    /// Update an automod rule with new information
+    async fn update_automod_rule(&self, id: &str, partial: &PartialAutomodRule) -> Result<()>;
+
+    /// Delete an automod rule by its id
+    async fn delete_automod_rule(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,43 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{AutomodRule, PartialAutomodRule};
+
+use super::AbstractServerAutomod;
+
+static COL: &str = "server_automod";
+
+#[async_trait]
+impl AbstractServerAutomod for MongoDb {
+    /// Insert a new automod rule into the database
+    async fn insert_automod_rule(&self, rule: &AutomodRule) -> Result<()> {
+        query!(self, insert_one, COL, &rule).map(|_| ())
+    }
+
+    /// Fetch an automod rule by its id
+    async fn fetch_automod_rule(&self, id: &str) -> Result<AutomodRule> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all automod rules of a server
+    async fn fetch_automod_rules(&self, server_id: &str) -> Result<Vec<AutomodRule>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "server": server_id
+            }
+        )
+    }
+
+    /// Update an automod rule with new information
+    async fn update_automod_rule(&self, id: &str, partial: &PartialAutomodRule) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+
+    /// Delete an automod rule by its id
+    async fn delete_automod_rule(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
@@ -0,0 +1,60 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{AutomodRule, PartialAutomodRule};
+
+use super::AbstractServerAutomod;
+
+#[async_trait]
+impl AbstractServerAutomod for ReferenceDb {
+    /// Insert a new automod rule into the database
+    async fn insert_automod_rule(&self, rule: &AutomodRule) -> Result<()> {
+        let mut server_automod = self.server_automod.lock().await;
+        if server_automod.contains_key(&rule.id) {
+            Err(create_database_error!("insert", "automod_rule"))
+        } else {
+            server_automod.insert(rule.id.to_string(), rule.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch an automod rule by its id
+    async fn fetch_automod_rule(&self, id: &str) -> Result<AutomodRule> {
+        let server_automod = self.server_automod.lock().await;
+        server_automod
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all automod rules of a server
+    async fn fetch_automod_rules(&self, server_id: &str) -> Result<Vec<AutomodRule>> {
+        let server_automod = self.server_automod.lock().await;
+        Ok(server_automod
+            .values()
+            .filter(|rule| rule.server == server_id)
+            .cloned()
+            .collect())
+    }
+
+    /// Update an automod rule with new information
+    async fn update_automod_rule(&self, id: &str, partial: &PartialAutomodRule) -> Result<()> {
+        let mut server_automod = self.server_automod.lock().await;
+        if let Some(rule) = server_automod.get_mut(id) {
+            rule.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete an automod rule by its id
+    async fn delete_automod_rule(&self, id: &str) -> Result<()> {
+        let mut server_automod = self.server_automod.lock().await;
+        if server_automod.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
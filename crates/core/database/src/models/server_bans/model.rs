@@ -1,3 +1,5 @@
+use iso8601_timestamp::Timestamp;
+
 use crate::MemberCompositeKey;
 
 auto_derived!(
@@ -8,5 +10,19 @@ auto_derived!(
         pub id: MemberCompositeKey,
         /// Reason for ban creation
         pub reason: Option<String>,
+        /// Time at which this ban is lifted
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub expires: Option<Timestamp>,
     }
 );
+
+impl ServerBan {
+    /// Check whether this ban has run its course
+    pub fn is_expired(&self) -> bool {
+        if let Some(expires) = self.expires {
+            *expires <= *Timestamp::now_utc()
+        } else {
+            false
+        }
+    }
+}
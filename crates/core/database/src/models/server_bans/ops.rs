@@ -16,6 +16,9 @@ pub trait AbstractServerBans: Sync + Send {
     /// Fetch all bans in a server
     async fn fetch_bans(&self, server_id: &str) -> Result<Vec<ServerBan>>;
 
+    /// Fetch all bans which have passed their expiry
+    async fn fetch_expired_bans(&self) -> Result<Vec<ServerBan>>;
+
     /// Delete a ban from the database
     async fn delete_ban(&self, id: &MemberCompositeKey) -> Result<()>;
 }
@@ -1,3 +1,5 @@
+use bson::to_bson;
+use iso8601_timestamp::Timestamp;
 use revolt_result::Result;
 
 use crate::MongoDb;
@@ -40,6 +42,21 @@ impl AbstractServerBans for MongoDb {
         )
     }
 
+    /// Fetch all bans which have passed their expiry
+    async fn fetch_expired_bans(&self) -> Result<Vec<ServerBan>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "expires": {
+                    "$lte": to_bson(&Timestamp::now_utc())
+                        .map_err(|_| create_database_error!("to_bson", "timestamp"))?
+                }
+            }
+        )
+    }
+
     /// Delete a ban from the database
     async fn delete_ban(&self, id: &MemberCompositeKey) -> Result<()> {
         query!(
@@ -40,6 +40,16 @@ impl AbstractServerBans for ReferenceDb {
             .collect())
     }
 
+    /// Fetch all bans which have passed their expiry
+    async fn fetch_expired_bans(&self) -> Result<Vec<ServerBan>> {
+        let server_bans = self.server_bans.lock().await;
+        Ok(server_bans
+            .values()
+            .filter(|ban| ban.is_expired())
+            .cloned()
+            .collect())
+    }
+
     /// Delete a ban from the database
     async fn delete_ban(&self, id: &MemberCompositeKey) -> Result<()> {
         let mut server_bans = self.server_bans.lock().await;
@@ -0,0 +1,322 @@
+use std::time::Duration;
+
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::{DataCreateServerEvent, ServerEventLocation, ServerEventStatus};
+use revolt_result::{create_error, Result};
+use ulid::Ulid;
+
+use crate::{events::client::EventV1, Channel, Database, Server};
+
+/// How long an event without an end time stays active
+pub const OPEN_EVENT_DURATION: Duration = Duration::from_secs(24 * 60 * 60);
+
+auto_derived_partial!(
+    /// Scheduled server event
+    pub struct ServerEvent {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this event belongs to
+        pub server: String,
+        /// Id of the user who scheduled this event
+        pub creator: String,
+
+        /// Title of the event
+        pub name: String,
+        /// Description of the event
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+
+        /// Time at which this event starts
+        pub starts_at: Timestamp,
+        /// Time at which this event ends
+        ///
+        /// Events without an end time complete once they have been
+        /// active for [`OPEN_EVENT_DURATION`].
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub ends_at: Option<Timestamp>,
+
+        /// Where this event takes place
+        pub location: ServerEventLocation,
+        /// Current status of this event
+        pub status: ServerEventStatus,
+
+        /// Ids of members who are attending this event
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub attendees: Vec<String>,
+        /// Whether attendees have been reminded of this event
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub reminded: bool,
+    },
+    "PartialServerEvent"
+);
+
+auto_derived!(
+    /// Optional fields on server event object
+    pub enum FieldsServerEvent {
+        Description,
+        EndsAt,
+    }
+);
+
+#[allow(clippy::disallowed_methods)]
+impl ServerEvent {
+    /// Schedule a new event in a server
+    pub async fn create(
+        db: &Database,
+        server: &Server,
+        creator_id: String,
+        data: DataCreateServerEvent,
+    ) -> Result<ServerEvent> {
+        ServerEvent::validate_start(&data.starts_at)?;
+        ServerEvent::validate_schedule(&data.starts_at, &data.ends_at)?;
+        ServerEvent::validate_location(db, &server.id, &data.location).await?;
+
+        let event = ServerEvent {
+            id: Ulid::new().to_string(),
+            server: server.id.clone(),
+            creator: creator_id,
+            name: data.name,
+            description: data.description,
+            starts_at: data.starts_at,
+            ends_at: data.ends_at,
+            location: data.location,
+            status: ServerEventStatus::Scheduled,
+            attendees: vec![],
+            reminded: false,
+        };
+
+        db.insert_server_event(&event).await?;
+
+        EventV1::ServerEventCreate(event.clone().into())
+            .p(event.server.clone())
+            .await;
+
+        Ok(event)
+    }
+
+    /// Ensure an event starts in the future
+    pub fn validate_start(starts_at: &Timestamp) -> Result<()> {
+        if **starts_at <= *Timestamp::now_utc() {
+            Err(create_error!(InvalidProperty))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Ensure an event ends after it starts
+    pub fn validate_schedule(starts_at: &Timestamp, ends_at: &Option<Timestamp>) -> Result<()> {
+        if ends_at.is_some_and(|ends_at| *ends_at <= **starts_at) {
+            Err(create_error!(InvalidProperty))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Ensure an event takes place in a voice channel of its server
+    pub async fn validate_location(
+        db: &Database,
+        server_id: &str,
+        location: &ServerEventLocation,
+    ) -> Result<()> {
+        match location {
+            ServerEventLocation::Channel { id } => match db.fetch_channel(id).await {
+                Ok(Channel::VoiceChannel { server, .. }) if server == server_id => Ok(()),
+                _ => Err(create_error!(UnknownChannel)),
+            },
+            ServerEventLocation::External { url } => {
+                if (1..=256).contains(&url.len())
+                    && (url.starts_with("https://") || url.starts_with("http://"))
+                {
+                    Ok(())
+                } else {
+                    Err(create_error!(InvalidProperty))
+                }
+            }
+        }
+    }
+
+    /// Update server event data
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        partial: PartialServerEvent,
+        remove: Vec<FieldsServerEvent>,
+    ) -> Result<()> {
+        for field in &remove {
+            self.remove_field(field);
+        }
+
+        self.apply_options(partial.clone());
+
+        db.update_server_event(&self.id, &partial, &remove).await?;
+
+        EventV1::ServerEventUpdate {
+            id: self.server.clone(),
+            event_id: self.id.clone(),
+            data: partial.into(),
+            clear: remove.into_iter().map(|field| field.into()).collect(),
+        }
+        .p(self.server.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Remove a field from this object
+    pub fn remove_field(&mut self, field: &FieldsServerEvent) {
+        match field {
+            FieldsServerEvent::Description => self.description = None,
+            FieldsServerEvent::EndsAt => self.ends_at = None,
+        }
+    }
+
+    /// Mark whether a member is attending this event
+    pub async fn set_attending(
+        &mut self,
+        db: &Database,
+        user_id: &str,
+        attending: bool,
+    ) -> Result<()> {
+        if self.attendees.iter().any(|id| id == user_id) == attending {
+            return Err(create_error!(NoEffect));
+        }
+
+        if attending {
+            db.add_server_event_attendee(&self.id, user_id).await?;
+            self.attendees.push(user_id.to_string());
+        } else {
+            db.remove_server_event_attendee(&self.id, user_id).await?;
+            self.attendees.retain(|id| id != user_id);
+        }
+
+        EventV1::ServerEventRsvp {
+            id: self.server.clone(),
+            event_id: self.id.clone(),
+            user: user_id.to_string(),
+            attending,
+        }
+        .p(self.server.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Delete this event
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_server_event(&self.id).await?;
+
+        EventV1::ServerEventDelete {
+            id: self.server.clone(),
+            event_id: self.id.clone(),
+        }
+        .p(self.server.clone())
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use iso8601_timestamp::Timestamp;
+    use revolt_models::v0::{DataCreateServerEvent, ServerEventLocation, ServerEventStatus};
+
+    use crate::{PartialServerEvent, Server, ServerEvent, OPEN_EVENT_DURATION};
+
+    #[async_std::test]
+    async fn lifecycle() {
+        database_test!(|db| async move {
+            let server = Server {
+                id: "server".to_string(),
+                ..Default::default()
+            };
+
+            let starts_at = Timestamp::from(SystemTime::now() + Duration::from_secs(3600));
+
+            let mut event = ServerEvent::create(
+                &db,
+                &server,
+                "creator".to_string(),
+                DataCreateServerEvent {
+                    name: "Game Night".to_string(),
+                    description: None,
+                    starts_at,
+                    ends_at: None,
+                    location: ServerEventLocation::External {
+                        url: "https://example.com".to_string(),
+                    },
+                },
+            )
+            .await
+            .unwrap();
+
+            assert_eq!(event.status, ServerEventStatus::Scheduled);
+
+            event.set_attending(&db, "user", true).await.unwrap();
+            assert!(event.set_attending(&db, "user", true).await.is_err());
+
+            event
+                .update(
+                    &db,
+                    PartialServerEvent {
+                        status: Some(ServerEventStatus::Active),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await
+                .unwrap();
+
+            assert_eq!(db.fetch_server_event(&event.id).await.unwrap(), event);
+            assert_eq!(event.attendees, vec!["user".to_string()]);
+
+            event.delete(&db).await.unwrap();
+            assert!(db.fetch_server_event(&event.id).await.is_err());
+        });
+    }
+
+    #[async_std::test]
+    async fn open_ended_events_complete() {
+        database_test!(|db| async move {
+            let event = |id: &str, started: Duration| ServerEvent {
+                id: id.to_string(),
+                server: "server".to_string(),
+                creator: "creator".to_string(),
+                name: "Game Night".to_string(),
+                description: None,
+                starts_at: Timestamp::from(SystemTime::now() - started),
+                ends_at: None,
+                location: ServerEventLocation::External {
+                    url: "https://example.com".to_string(),
+                },
+                status: ServerEventStatus::Active,
+                attendees: vec![],
+                reminded: true,
+            };
+
+            #[allow(clippy::disallowed_methods)]
+            {
+                db.insert_server_event(&event("recent", Duration::from_secs(3600)))
+                    .await
+                    .unwrap();
+                db.insert_server_event(&event(
+                    "stale",
+                    OPEN_EVENT_DURATION + Duration::from_secs(60),
+                ))
+                .await
+                .unwrap();
+            }
+
+            let due = db
+                .fetch_due_server_events(Timestamp::now_utc())
+                .await
+                .unwrap();
+
+            assert_eq!(due.len(), 1);
+            assert_eq!(due[0].id, "stale");
+        });
+    }
+}
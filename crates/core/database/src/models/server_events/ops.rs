@@ -0,0 +1,41 @@
+use iso8601_timestamp::Timestamp;
+use revolt_result::Result;
+
+use crate::{FieldsServerEvent, PartialServerEvent, ServerEvent};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerEvents: Sync + Send {
+    /// Insert new server event into the database
+    async fn insert_server_event(&self, event: &ServerEvent) -> Result<()>;
+
+    /// Fetch a server event by its id
+    async fn fetch_server_event(&self, id: &str) -> Result<ServerEvent>;
+
+    /// Fetch all events scheduled in a server
+    async fn fetch_server_events(&self, server_id: &str) -> Result<Vec<ServerEvent>>;
+
+    /// Fetch events which are scheduled to start before the given time or have ended
+    ///
+    /// Events without an end time have ended once they have been active for [`crate::OPEN_EVENT_DURATION`]
+    async fn fetch_due_server_events(&self, until: Timestamp) -> Result<Vec<ServerEvent>>;
+
+    /// Update server event with new information
+    async fn update_server_event(
+        &self,
+        id: &str,
+        partial: &PartialServerEvent,
+        remove: &[FieldsServerEvent],
+    ) -> Result<()>;
+
+    /// Add a member to the attendees of an event
+    async fn add_server_event_attendee(&self, id: &str, user_id: &str) -> Result<()>;
+
+    /// Remove a member from the attendees of an event
+    async fn remove_server_event_attendee(&self, id: &str, user_id: &str) -> Result<()>;
+
+    /// Delete a server event by its id
+    async fn delete_server_event(&self, id: &str) -> Result<()>;
+}
@@ -0,0 +1,153 @@
+use std::time::SystemTime;
+
+use bson::{to_bson, Document};
+use iso8601_timestamp::Timestamp;
+use mongodb::options::FindOptions;
+use revolt_result::Result;
+
+use crate::{FieldsServerEvent, PartialServerEvent, ServerEvent, OPEN_EVENT_DURATION};
+use crate::{IntoDocumentPath, MongoDb};
+
+use super::AbstractServerEvents;
+
+static COL: &str = "server_events";
+
+#[async_trait]
+impl AbstractServerEvents for MongoDb {
+    /// Insert new server event into the database
+    async fn insert_server_event(&self, event: &ServerEvent) -> Result<()> {
+        query!(self, insert_one, COL, &event).map(|_| ())
+    }
+
+    /// Fetch a server event by its id
+    async fn fetch_server_event(&self, id: &str) -> Result<ServerEvent> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all events scheduled in a server
+    async fn fetch_server_events(&self, server_id: &str) -> Result<Vec<ServerEvent>> {
+        self.find_with_options(
+            COL,
+            doc! {
+                "server": server_id
+            },
+            FindOptions::builder()
+                .sort(doc! {
+                    "starts_at": 1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Fetch events which are scheduled to start before the given time or have ended
+    async fn fetch_due_server_events(&self, until: Timestamp) -> Result<Vec<ServerEvent>> {
+        let open_until = Timestamp::from(SystemTime::now() - OPEN_EVENT_DURATION);
+
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "$or": [
+                    {
+                        "status": "Scheduled",
+                        "starts_at": {
+                            "$lte": to_bson(&until)
+                                .map_err(|_| create_database_error!("to_bson", "timestamp"))?
+                        }
+                    },
+                    {
+                        "status": "Active",
+                        "ends_at": {
+                            "$lte": to_bson(&Timestamp::now_utc())
+                                .map_err(|_| create_database_error!("to_bson", "timestamp"))?
+                        }
+                    },
+                    {
+                        "status": "Active",
+                        "ends_at": {
+                            "$exists": false
+                        },
+                        "starts_at": {
+                            "$lte": to_bson(&open_until)
+                                .map_err(|_| create_database_error!("to_bson", "timestamp"))?
+                        }
+                    }
+                ]
+            }
+        )
+    }
+
+    /// Update server event with new information
+    async fn update_server_event(
+        &self,
+        id: &str,
+        partial: &PartialServerEvent,
+        remove: &[FieldsServerEvent],
+    ) -> Result<()> {
+        query!(
+            self,
+            update_one_by_id,
+            COL,
+            id,
+            partial,
+            remove.iter().map(|x| x as &dyn IntoDocumentPath).collect(),
+            None
+        )
+        .map(|_| ())
+    }
+
+    /// Add a member to the attendees of an event
+    async fn add_server_event_attendee(&self, id: &str, user_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$addToSet": {
+                        "attendees": user_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Remove a member from the attendees of an event
+    async fn remove_server_event_attendee(&self, id: &str, user_id: &str) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$pull": {
+                        "attendees": user_id
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("update_one", COL))
+    }
+
+    /// Delete a server event by its id
+    async fn delete_server_event(&self, id: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, id).map(|_| ())
+    }
+}
+
+impl IntoDocumentPath for FieldsServerEvent {
+    fn as_path(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldsServerEvent::Description => "description",
+            FieldsServerEvent::EndsAt => "ends_at",
+        })
+    }
+}
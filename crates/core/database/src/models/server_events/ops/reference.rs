@@ -0,0 +1,121 @@
+use std::time::SystemTime;
+
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::ServerEventStatus;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{FieldsServerEvent, PartialServerEvent, ServerEvent, OPEN_EVENT_DURATION};
+
+use super::AbstractServerEvents;
+
+#[async_trait]
+impl AbstractServerEvents for ReferenceDb {
+    /// Insert new server event into the database
+    async fn insert_server_event(&self, event: &ServerEvent) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if server_events.contains_key(&event.id) {
+            Err(create_database_error!("insert", "server_events"))
+        } else {
+            server_events.insert(event.id.to_string(), event.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a server event by its id
+    async fn fetch_server_event(&self, id: &str) -> Result<ServerEvent> {
+        let server_events = self.server_events.lock().await;
+        server_events
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all events scheduled in a server
+    async fn fetch_server_events(&self, server_id: &str) -> Result<Vec<ServerEvent>> {
+        let server_events = self.server_events.lock().await;
+        let mut events: Vec<ServerEvent> = server_events
+            .values()
+            .filter(|event| event.server == server_id)
+            .cloned()
+            .collect();
+
+        events.sort_by_key(|event| *event.starts_at);
+        Ok(events)
+    }
+
+    /// Fetch events which are scheduled to start before the given time or have ended
+    async fn fetch_due_server_events(&self, until: Timestamp) -> Result<Vec<ServerEvent>> {
+        let now = Timestamp::now_utc();
+        let open_until = Timestamp::from(SystemTime::now() - OPEN_EVENT_DURATION);
+        let server_events = self.server_events.lock().await;
+        Ok(server_events
+            .values()
+            .filter(|event| match event.status {
+                ServerEventStatus::Scheduled => *event.starts_at <= *until,
+                ServerEventStatus::Active => match event.ends_at {
+                    Some(ends_at) => *ends_at <= *now,
+                    None => *event.starts_at <= *open_until,
+                },
+                ServerEventStatus::Completed => false,
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Update server event with new information
+    async fn update_server_event(
+        &self,
+        id: &str,
+        partial: &PartialServerEvent,
+        remove: &[FieldsServerEvent],
+    ) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if let Some(event) = server_events.get_mut(id) {
+            for field in remove {
+                #[allow(clippy::disallowed_methods)]
+                event.remove_field(field);
+            }
+
+            event.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Add a member to the attendees of an event
+    async fn add_server_event_attendee(&self, id: &str, user_id: &str) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if let Some(event) = server_events.get_mut(id) {
+            if !event.attendees.iter().any(|id| id == user_id) {
+                event.attendees.push(user_id.to_string());
+            }
+
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Remove a member from the attendees of an event
+    async fn remove_server_event_attendee(&self, id: &str, user_id: &str) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if let Some(event) = server_events.get_mut(id) {
+            event.attendees.retain(|id| id != user_id);
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+
+    /// Delete a server event by its id
+    async fn delete_server_event(&self, id: &str) -> Result<()> {
+        let mut server_events = self.server_events.lock().await;
+        if server_events.remove(id).is_some() {
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
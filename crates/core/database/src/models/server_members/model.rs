@@ -3,8 +3,8 @@ use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
 use revolt_result::{create_error, Result};
 
 use crate::{
-    events::client::EventV1, util::permissions::DatabasePermissionQuery, Database, File, Server,
-    SystemMessage, User,
+    events::client::EventV1, util::permissions::DatabasePermissionQuery, AutoRoleRule, Database,
+    File, Server, ServerScreening, SystemMessage, User,
 };
 
 auto_derived_partial!(
@@ -30,6 +30,12 @@ auto_derived_partial!(
         /// Timestamp this member is timed out until
         #[serde(skip_serializing_if = "Option::is_none")]
         pub timeout: Option<Timestamp>,
+        /// Screening this member has yet to pass before they may participate
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub pending: Option<MemberScreening>,
+        /// Whether this member is removed once they go offline, unless given a role
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub temporary: bool,
     },
     "PartialMember"
 );
@@ -44,12 +50,24 @@ auto_derived!(
         pub user: String,
     }
 
+    /// Screening state of a member who has not yet been let into a server
+    #[derive(Default)]
+    pub struct MemberScreening {
+        /// Time at which the member accepted the rules and submitted their answers
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub submitted_at: Option<Timestamp>,
+        /// Answers to the server's screening questions
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub answers: Vec<String>,
+    }
+
     /// Optional fields on server member object
     pub enum FieldsMember {
         Nickname,
         Avatar,
         Roles,
         Timeout,
+        Pending,
     }
 
     /// Member removal intention
@@ -58,6 +76,22 @@ auto_derived!(
         Kick,
         Ban,
     }
+
+    /// Member Query
+    pub struct MemberQuery {
+        /// Id of the server to fetch members from
+        pub server: String,
+        /// Only include members who have this role
+        pub role: Option<String>,
+        /// Only include members who joined after this time
+        pub joined_after: Option<Timestamp>,
+        /// Only include members who joined before this time
+        pub joined_before: Option<Timestamp>,
+        /// User id after which members should be fetched
+        pub after: Option<String>,
+        /// Maximum number of members to fetch
+        pub limit: i64,
+    }
 );
 
 impl Default for Member {
@@ -69,6 +103,8 @@ impl Default for Member {
             avatar: None,
             roles: vec![],
             timeout: None,
+            pending: None,
+            temporary: false,
         }
     }
 }
@@ -83,7 +119,11 @@ impl Member {
         // channels: Option<Vec<Channel>>,
         //) -> Result<Vec<Channel>> {
     ) -> Result<()> {
-        if db.fetch_ban(&server.id, &user.id).await.is_ok() {
+        if db
+            .fetch_ban(&server.id, &user.id)
+            .await
+            .is_ok_and(|ban| !ban.is_expired())
+        {
             return Err(create_error!(Banned));
         }
 
@@ -96,6 +136,7 @@ impl Member {
                 server: server.id.to_string(),
                 user: user.id.to_string(),
             },
+            roles: AutoRoleRule::join_roles(db, server).await?,
             ..Default::default()
         };
 
@@ -188,7 +229,52 @@ impl Member {
             FieldsMember::Nickname => self.nickname = None,
             FieldsMember::Roles => self.roles.clear(),
             FieldsMember::Timeout => self.timeout = None,
+            FieldsMember::Pending => self.pending = None,
+        }
+    }
+
+    /// Give this member a role
+    pub async fn add_role(&mut self, db: &Database, role_id: &str) -> Result<()> {
+        if self.roles.iter().any(|id| id == role_id) {
+            return Err(create_error!(NoEffect));
+        }
+
+        let mut roles = self.roles.clone();
+        roles.push(role_id.to_string());
+
+        self.update(
+            db,
+            PartialMember {
+                roles: Some(roles),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+
+    /// Take a role away from this member
+    pub async fn remove_role(&mut self, db: &Database, role_id: &str) -> Result<()> {
+        if !self.roles.iter().any(|id| id == role_id) {
+            return Err(create_error!(NoEffect));
         }
+
+        let roles = self
+            .roles
+            .iter()
+            .filter(|id| *id != role_id)
+            .cloned()
+            .collect();
+
+        self.update(
+            db,
+            PartialMember {
+                roles: Some(roles),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
     }
 
     /// Get this user's current ranking
@@ -213,4 +299,169 @@ impl Member {
             false
         }
     }
+
+    /// Throw an error if this member is currently timed out
+    pub fn throw_if_in_timeout(&self) -> Result<()> {
+        match self.timeout {
+            Some(timeout) if self.in_timeout() => Err(create_error!(InTimeout {
+                until: timeout.format().to_string()
+            })),
+            _ => Ok(()),
+        }
+    }
+
+    /// Check whether this member has yet to pass membership screening
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Accept the server's rules and submit answers to its questions
+    ///
+    /// Members are let in straight away unless the server requires
+    /// approval, in which case moderators are notified instead.
+    pub async fn submit_screening(
+        &mut self,
+        db: &Database,
+        screening: &ServerScreening,
+        answers: Vec<String>,
+    ) -> Result<()> {
+        if self
+            .pending
+            .as_ref()
+            .map_or(true, |pending| pending.submitted_at.is_some())
+        {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        // Screening may have been turned off since this member joined
+        if !screening.enabled {
+            return self.approve_screening(db).await;
+        }
+
+        screening.validate_answers(&answers)?;
+
+        if !screening.require_approval {
+            return self.approve_screening(db).await;
+        }
+
+        self.update(
+            db,
+            PartialMember {
+                pending: Some(MemberScreening {
+                    submitted_at: Some(Timestamp::now_utc()),
+                    answers,
+                }),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+        EventV1::ServerMemberScreeningSubmit {
+            id: self.id.server.clone(),
+            user: self.id.user.clone(),
+        }
+        .p(self.id.server.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Let this member into the server
+    pub async fn approve_screening(&mut self, db: &Database) -> Result<()> {
+        if !self.is_pending() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        self.update(db, Default::default(), vec![FieldsMember::Pending])
+            .await?;
+
+        // Roles were held back while the member was being screened
+        if let Err(err) = AutoRoleRule::apply_admission(db, self).await {
+            error!(
+                "Failed to give out auto roles to {} in {}: {err:?}",
+                self.id.user, self.id.server
+            );
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, SystemTime};
+
+    use iso8601_timestamp::Timestamp;
+    use revolt_result::ErrorType;
+    use ulid::Ulid;
+
+    use crate::{Channel, Member, PartialMember, Server, User};
+
+    #[async_std::test]
+    async fn timeout() {
+        database_test!(|db| async move {
+            let owner = User::create(&db, "Owner".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let user = User::create(&db, "User".to_string(), None, None)
+                .await
+                .unwrap();
+
+            let server = Server {
+                id: Ulid::new().to_string(),
+                owner: owner.id.clone(),
+                name: "Server".to_string(),
+                ..Default::default()
+            };
+
+            server.create(&db).await.unwrap();
+            Member::create(&db, &server, &user).await.unwrap();
+
+            let channel = Channel::ForumPost {
+                id: Ulid::new().to_string(),
+                server: server.id.clone(),
+                forum: Ulid::new().to_string(),
+                author: owner.id.clone(),
+                name: "Post".to_string(),
+                tags: vec![],
+                last_message_id: None,
+            };
+
+            channel.throw_if_timed_out(&db, &user.id).await.unwrap();
+
+            let mut member = db.fetch_member(&server.id, &user.id).await.unwrap();
+            member
+                .update(
+                    &db,
+                    PartialMember {
+                        timeout: Some(Timestamp::from(SystemTime::now() + Duration::from_secs(60))),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await
+                .unwrap();
+
+            let error = channel.throw_if_timed_out(&db, &user.id).await.unwrap_err();
+
+            assert!(matches!(error.error_type, ErrorType::InTimeout { .. }));
+
+            // Elapsed timeouts no longer apply
+            member
+                .update(
+                    &db,
+                    PartialMember {
+                        timeout: Some(Timestamp::from(SystemTime::now() - Duration::from_secs(60))),
+                        ..Default::default()
+                    },
+                    vec![],
+                )
+                .await
+                .unwrap();
+
+            channel.throw_if_timed_out(&db, &user.id).await.unwrap();
+        });
+    }
 }
@@ -1,6 +1,6 @@
 use revolt_result::Result;
 
-use crate::{FieldsMember, Member, MemberCompositeKey, PartialMember};
+use crate::{FieldsMember, Member, MemberCompositeKey, MemberQuery, PartialMember};
 
 mod mongodb;
 mod reference;
@@ -19,6 +19,24 @@ pub trait AbstractServerMembers: Sync + Send {
     /// Fetch all memberships for a user
     async fn fetch_all_memberships<'a>(&self, user_id: &str) -> Result<Vec<Member>>;
 
+    /// Fetch all members whose timeout has elapsed but not yet been cleared
+    async fn fetch_expired_timeouts(&self) -> Result<Vec<Member>>;
+
+    /// Fetch all members of a server whose screening awaits approval
+    async fn fetch_pending_members(&self, server_id: &str) -> Result<Vec<Member>>;
+
+    /// Fetch members of a server who have a given role, ordered by user id
+    async fn fetch_members_with_role(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        after: Option<String>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Member>>;
+
+    /// Fetch members of a server matching a query, ordered by user id
+    async fn fetch_members_by_query(&self, query: MemberQuery) -> Result<Vec<Member>>;
+
     /// Fetch multiple members by their ids
     async fn fetch_members<'a>(&self, server_id: &str, ids: &'a [String]) -> Result<Vec<Member>>;
 
@@ -1,7 +1,10 @@
+use bson::to_bson;
 use futures::StreamExt;
+use iso8601_timestamp::Timestamp;
+use mongodb::options::FindOptions;
 use revolt_result::Result;
 
-use crate::{FieldsMember, Member, MemberCompositeKey, PartialMember};
+use crate::{FieldsMember, Member, MemberCompositeKey, MemberQuery, PartialMember};
 use crate::{IntoDocumentPath, MongoDb};
 
 use super::AbstractServerMembers;
@@ -75,6 +78,116 @@ impl AbstractServerMembers for MongoDb {
             .await)
     }
 
+    /// Fetch all members whose timeout has elapsed but not yet been cleared
+    async fn fetch_expired_timeouts(&self) -> Result<Vec<Member>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "timeout": {
+                    "$lte": to_bson(&Timestamp::now_utc())
+                        .map_err(|_| create_database_error!("to_bson", "timestamp"))?
+                }
+            }
+        )
+    }
+
+    /// Fetch all members of a server whose screening awaits approval
+    async fn fetch_pending_members(&self, server_id: &str) -> Result<Vec<Member>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "_id.server": server_id,
+                "pending.submitted_at": {
+                    "$exists": true
+                }
+            }
+        )
+    }
+
+    /// Fetch members of a server who have a given role, ordered by user id
+    async fn fetch_members_with_role(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        after: Option<String>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Member>> {
+        let mut filter = doc! {
+            "_id.server": server_id,
+            "roles": role_id
+        };
+
+        if let Some(after) = after {
+            filter.insert("_id.user", doc! { "$gt": after });
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(limit)
+                .sort(doc! {
+                    "_id.user": 1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
+    /// Fetch members of a server matching a query, ordered by user id
+    async fn fetch_members_by_query(&self, query: MemberQuery) -> Result<Vec<Member>> {
+        let mut filter = doc! {
+            "_id.server": query.server
+        };
+
+        if let Some(role) = query.role {
+            filter.insert("roles", role);
+        }
+
+        if let Some(after) = query.after {
+            filter.insert("_id.user", doc! { "$gt": after });
+        }
+
+        let mut joined_at = doc! {};
+        if let Some(joined_after) = query.joined_after {
+            joined_at.insert(
+                "$gt",
+                to_bson(&joined_after)
+                    .map_err(|_| create_database_error!("to_bson", "timestamp"))?,
+            );
+        }
+
+        if let Some(joined_before) = query.joined_before {
+            joined_at.insert(
+                "$lt",
+                to_bson(&joined_before)
+                    .map_err(|_| create_database_error!("to_bson", "timestamp"))?,
+            );
+        }
+
+        if !joined_at.is_empty() {
+            filter.insert("joined_at", joined_at);
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(query.limit)
+                .sort(doc! {
+                    "_id.user": 1_i32
+                })
+                .build(),
+        )
+        .await
+        .map_err(|_| create_database_error!("find", COL))
+    }
+
     /// Fetch multiple members by their ids
     async fn fetch_members<'a>(&self, server_id: &str, ids: &'a [String]) -> Result<Vec<Member>> {
         Ok(self
@@ -173,6 +286,7 @@ impl IntoDocumentPath for FieldsMember {
             FieldsMember::Nickname => "nickname",
             FieldsMember::Roles => "roles",
             FieldsMember::Timeout => "timeout",
+            FieldsMember::Pending => "pending",
         })
     }
 }
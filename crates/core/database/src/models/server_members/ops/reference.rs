@@ -1,7 +1,7 @@
 use revolt_result::Result;
 
 use crate::ReferenceDb;
-use crate::{FieldsMember, Member, MemberCompositeKey, PartialMember};
+use crate::{FieldsMember, Member, MemberCompositeKey, MemberQuery, PartialMember};
 
 use super::AbstractServerMembers;
 
@@ -50,6 +50,91 @@ impl AbstractServerMembers for ReferenceDb {
             .collect())
     }
 
+    /// Fetch all members whose timeout has elapsed but not yet been cleared
+    async fn fetch_expired_timeouts(&self) -> Result<Vec<Member>> {
+        let server_members = self.server_members.lock().await;
+        Ok(server_members
+            .values()
+            .filter(|member| member.timeout.is_some() && !member.in_timeout())
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch all members of a server whose screening awaits approval
+    async fn fetch_pending_members(&self, server_id: &str) -> Result<Vec<Member>> {
+        let server_members = self.server_members.lock().await;
+        Ok(server_members
+            .values()
+            .filter(|member| {
+                member.id.server == server_id
+                    && member
+                        .pending
+                        .as_ref()
+                        .is_some_and(|pending| pending.submitted_at.is_some())
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Fetch members of a server who have a given role, ordered by user id
+    async fn fetch_members_with_role(
+        &self,
+        server_id: &str,
+        role_id: &str,
+        after: Option<String>,
+        limit: Option<i64>,
+    ) -> Result<Vec<Member>> {
+        let server_members = self.server_members.lock().await;
+        let mut members: Vec<Member> = server_members
+            .values()
+            .filter(|member| {
+                member.id.server == server_id
+                    && member.roles.iter().any(|role| role == role_id)
+                    && after.as_ref().map_or(true, |after| &member.id.user > after)
+            })
+            .cloned()
+            .collect();
+
+        members.sort_by(|a, b| a.id.user.cmp(&b.id.user));
+
+        if let Some(limit) = limit {
+            members.truncate(limit as usize);
+        }
+
+        Ok(members)
+    }
+
+    /// Fetch members of a server matching a query, ordered by user id
+    async fn fetch_members_by_query(&self, query: MemberQuery) -> Result<Vec<Member>> {
+        let server_members = self.server_members.lock().await;
+        let mut members: Vec<Member> = server_members
+            .values()
+            .filter(|member| {
+                member.id.server == query.server
+                    && query
+                        .role
+                        .as_ref()
+                        .map_or(true, |role| member.roles.contains(role))
+                    && query
+                        .after
+                        .as_ref()
+                        .map_or(true, |after| &member.id.user > after)
+                    && query
+                        .joined_after
+                        .map_or(true, |joined_after| *member.joined_at > *joined_after)
+                    && query
+                        .joined_before
+                        .map_or(true, |joined_before| *member.joined_at < *joined_before)
+            })
+            .cloned()
+            .collect();
+
+        members.sort_by(|a, b| a.id.user.cmp(&b.id.user));
+        members.truncate(query.limit as usize);
+
+        Ok(members)
+    }
+
     /// Fetch multiple members by their ids
     async fn fetch_members<'a>(&self, server_id: &str, ids: &'a [String]) -> Result<Vec<Member>> {
         let server_members = self.server_members.lock().await;
@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::{FieldsRaidProtection, Lockdown};
+use revolt_result::{ErrorType, Result};
+use ulid::Ulid;
+
+use crate::{
+    util::expiry::expires_in, Database, RatelimitEvent, RatelimitEventType, SystemMessage,
+};
+
+/// Default length of the window over which joins are counted
+const DEFAULT_JOIN_WINDOW: u64 = 60;
+
+/// Default length of an automatic lockdown
+const DEFAULT_LOCKDOWN_DURATION: u64 = 60 * 30;
+
+auto_derived_partial!(
+    /// Raid protection settings of a server
+    pub struct RaidProtection {
+        /// Id of the server
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Number of joins within the join window which starts a lockdown
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub join_threshold: Option<u32>,
+        /// Length of the window over which joins are counted, in seconds
+        pub join_window: u64,
+        /// How long an automatic lockdown lasts, in seconds
+        pub lockdown_duration: u64,
+        /// Minimum age of an account in seconds before it may join
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub min_account_age: Option<u64>,
+        /// Whether only accounts with a verified email may join
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub require_verified_email: bool,
+        /// Channel in which moderators are alerted when a lockdown starts
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub alert_channel: Option<String>,
+        /// Lockdown currently in effect
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub lockdown: Option<Lockdown>,
+    },
+    "PartialRaidProtection"
+);
+
+impl RaidProtection {
+    /// Fetch the raid protection settings of a server, falling back to defaults
+    pub async fn fetch(db: &Database, server_id: &str) -> Result<RaidProtection> {
+        match db.fetch_raid_protection(server_id).await {
+            Ok(mut protection) => {
+                // Expired lockdowns are dropped the next time settings are saved
+                if !protection.is_locked_down() {
+                    protection.lockdown = None;
+                }
+
+                Ok(protection)
+            }
+            Err(error) if matches!(error.error_type, ErrorType::NotFound) => Ok(RaidProtection {
+                id: server_id.to_string(),
+                join_threshold: None,
+                join_window: DEFAULT_JOIN_WINDOW,
+                lockdown_duration: DEFAULT_LOCKDOWN_DURATION,
+                min_account_age: None,
+                require_verified_email: false,
+                alert_channel: None,
+                lockdown: None,
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Update raid protection settings
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        partial: PartialRaidProtection,
+        remove: Vec<FieldsRaidProtection>,
+    ) -> Result<()> {
+        for field in &remove {
+            self.remove_field(field);
+        }
+
+        self.apply_options(partial);
+        db.upsert_raid_protection(self).await
+    }
+
+    /// Remove a field from this object
+    pub fn remove_field(&mut self, field: &FieldsRaidProtection) {
+        match field {
+            FieldsRaidProtection::JoinThreshold => self.join_threshold = None,
+            FieldsRaidProtection::MinAccountAge => self.min_account_age = None,
+            FieldsRaidProtection::AlertChannel => self.alert_channel = None,
+        }
+    }
+
+    /// Check whether the server is currently locked down
+    pub fn is_locked_down(&self) -> bool {
+        self.lockdown.as_ref().is_some_and(|lockdown| {
+            lockdown
+                .expires
+                .map_or(true, |expires| *expires > *Timestamp::now_utc())
+        })
+    }
+
+    /// Check whether joining requires a verified email right now
+    pub fn requires_verified_email(&self) -> bool {
+        self.require_verified_email || self.is_locked_down()
+    }
+
+    /// Check whether an account is too young to join
+    pub fn is_account_too_new(&self, user_id: &str) -> bool {
+        self.min_account_age.is_some_and(|min_age| {
+            Ulid::from_string(user_id)
+                .ok()
+                .and_then(|ulid| ulid.datetime().elapsed().ok())
+                .map_or(true, |age| age < Duration::from_secs(min_age))
+        })
+    }
+
+    /// Count a new join and lock the server down if too many have happened recently
+    pub async fn record_join(&mut self, db: &Database) -> Result<()> {
+        let Some(threshold) = self.join_threshold else {
+            return Ok(());
+        };
+
+        RatelimitEvent::create(db, self.id.clone(), RatelimitEventType::ServerJoin).await?;
+
+        if !self.is_locked_down()
+            && db
+                .has_ratelimited(
+                    &self.id,
+                    RatelimitEventType::ServerJoin,
+                    Duration::from_secs(self.join_window),
+                    threshold as usize,
+                )
+                .await?
+        {
+            self.start_lockdown(db, None, Some(self.lockdown_duration))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Lock the server down, lasting until lifted if no duration is given
+    pub async fn start_lockdown(
+        &mut self,
+        db: &Database,
+        started_by: Option<String>,
+        duration: Option<u64>,
+    ) -> Result<()> {
+        let expires = duration.map(expires_in).transpose()?;
+
+        let lockdown = Lockdown {
+            started_by,
+            since: Timestamp::now_utc(),
+            expires,
+        };
+
+        let content = match &lockdown.started_by {
+            Some(user) => format!("<@{user}> locked the server down."),
+            None => format!(
+                "Server was locked down after more than {} members joined within {} seconds.",
+                self.join_threshold.unwrap_or_default(),
+                self.join_window
+            ),
+        };
+
+        self.lockdown = Some(lockdown);
+        db.upsert_raid_protection(self).await?;
+        self.alert(db, content).await;
+        Ok(())
+    }
+
+    /// Lift the current lockdown
+    pub async fn end_lockdown(&mut self, db: &Database) -> Result<()> {
+        self.lockdown = None;
+        db.upsert_raid_protection(self).await
+    }
+
+    /// Post a notice in the alert channel, if one is configured
+    async fn alert(&self, db: &Database, content: String) {
+        let Some(channel) = &self.alert_channel else {
+            return;
+        };
+
+        if let Ok(channel) = db.fetch_channel(channel).await {
+            SystemMessage::Text { content }
+                .into_message(channel.id())
+                .send_without_notifications(db, false, false)
+                .await
+                .ok();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::RaidProtection;
+
+    #[async_std::test]
+    async fn lockdown() {
+        database_test!(|db| async move {
+            let mut protection = RaidProtection::fetch(&db, "server").await.unwrap();
+            assert!(!protection.is_locked_down());
+
+            protection
+                .start_lockdown(&db, None, Some(3600))
+                .await
+                .unwrap();
+
+            let protection = RaidProtection::fetch(&db, "server").await.unwrap();
+            assert!(protection.is_locked_down());
+            assert!(protection.requires_verified_email());
+        });
+    }
+}
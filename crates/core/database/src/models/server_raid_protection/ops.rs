@@ -0,0 +1,15 @@
+use revolt_result::Result;
+
+use crate::RaidProtection;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerRaidProtection: Sync + Send {
+    /// Fetch the raid protection settings of a server
+    async fn fetch_raid_protection(&self, server_id: &str) -> Result<RaidProtection>;
+
+    /// Insert or replace the raid protection settings of a server
+    async fn upsert_raid_protection(&self, protection: &RaidProtection) -> Result<()>;
+}
@@ -0,0 +1,32 @@
+use mongodb::options::ReplaceOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::RaidProtection;
+
+use super::AbstractServerRaidProtection;
+
+static COL: &str = "server_raid_protection";
+
+#[async_trait]
+impl AbstractServerRaidProtection for MongoDb {
+    /// Fetch the raid protection settings of a server
+    async fn fetch_raid_protection(&self, server_id: &str) -> Result<RaidProtection> {
+        query!(self, find_one_by_id, COL, server_id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Insert or replace the raid protection settings of a server
+    async fn upsert_raid_protection(&self, protection: &RaidProtection) -> Result<()> {
+        self.col::<RaidProtection>(COL)
+            .replace_one(
+                doc! {
+                    "_id": &protection.id
+                },
+                protection,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("replace_one", COL))
+    }
+}
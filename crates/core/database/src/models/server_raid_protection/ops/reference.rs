@@ -0,0 +1,25 @@
+use revolt_result::Result;
+
+use crate::RaidProtection;
+use crate::ReferenceDb;
+
+use super::AbstractServerRaidProtection;
+
+#[async_trait]
+impl AbstractServerRaidProtection for ReferenceDb {
+    /// Fetch the raid protection settings of a server
+    async fn fetch_raid_protection(&self, server_id: &str) -> Result<RaidProtection> {
+        let server_raid_protection = self.server_raid_protection.lock().await;
+        server_raid_protection
+            .get(server_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Insert or replace the raid protection settings of a server
+    async fn upsert_raid_protection(&self, protection: &RaidProtection) -> Result<()> {
+        let mut server_raid_protection = self.server_raid_protection.lock().await;
+        server_raid_protection.insert(protection.id.to_string(), protection.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::{MemberSelection, RoleJobAction, RoleJobStatus};
+use revolt_result::Result;
+use ulid::Ulid;
+
+use crate::{events::client::EventV1, tasks, Database};
+
+/// Maximum number of members which may be selected explicitly
+pub const MAX_ROLE_JOB_MEMBERS: usize = 1_000;
+
+/// Maximum number of jobs a server may have waiting or running at once
+const MAX_UNFINISHED_ROLE_JOBS: usize = 3;
+
+auto_derived_partial!(
+    /// Bulk role assignment job
+    pub struct RoleJob {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Id of the server this job runs in
+        pub server: String,
+        /// Id of the user who started this job
+        pub creator: String,
+        /// Id of the role being added or removed
+        pub role: String,
+        /// Whether the role is being added or removed
+        pub action: RoleJobAction,
+        /// Members this job applies to
+        pub selection: MemberSelection,
+        /// Current state of the job
+        pub status: RoleJobStatus,
+        /// Number of selected members processed so far
+        pub processed: u32,
+        /// Number of members whose roles were changed
+        pub affected: u32,
+        /// Time at which the job finished
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub completed_at: Option<Timestamp>,
+    },
+    "PartialRoleJob"
+);
+
+#[allow(clippy::disallowed_methods)]
+impl RoleJob {
+    /// Create and queue a new role job
+    pub async fn create(
+        db: &Database,
+        server: String,
+        creator: String,
+        role: String,
+        action: RoleJobAction,
+        selection: MemberSelection,
+    ) -> Result<RoleJob> {
+        match &selection {
+            MemberSelection::Members { ids } => {
+                if ids.is_empty()
+                    || ids.len() > MAX_ROLE_JOB_MEMBERS
+                    || ids.iter().any(|id| Ulid::from_string(id).is_err())
+                {
+                    return Err(create_error!(InvalidProperty));
+                }
+            }
+            MemberSelection::JoinDate {
+                after: Some(after),
+                before: Some(before),
+            } => {
+                if **before <= **after {
+                    return Err(create_error!(InvalidProperty));
+                }
+            }
+            _ => {}
+        }
+
+        // All servers share one queue, so no server may hog it
+        if db.fetch_unfinished_role_jobs(Some(&server)).await?.len() >= MAX_UNFINISHED_ROLE_JOBS {
+            return Err(create_error!(TooManyPendingRoleJobs {
+                max: MAX_UNFINISHED_ROLE_JOBS
+            }));
+        }
+
+        let job = RoleJob {
+            id: Ulid::new().to_string(),
+            server,
+            creator,
+            role,
+            action,
+            selection,
+            status: RoleJobStatus::Pending,
+            processed: 0,
+            affected: 0,
+            completed_at: None,
+        };
+
+        db.insert_role_job(&job).await?;
+        tasks::role_jobs::queue(job.id.clone()).await;
+
+        Ok(job)
+    }
+
+    /// Update job progress and notify the user who started it
+    pub async fn update(&mut self, db: &Database, partial: PartialRoleJob) -> Result<()> {
+        self.apply_options(partial.clone());
+        db.update_role_job(&self.id, &partial).await?;
+
+        EventV1::RoleJobUpdate {
+            id: self.id.clone(),
+            server: self.server.clone(),
+            status: self.status.clone(),
+            processed: self.processed,
+            affected: self.affected,
+        }
+        .private(self.creator.clone())
+        .await;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_models::v0::{MemberSelection, RoleJobAction};
+    use revolt_result::{ErrorType, Result};
+
+    use crate::{Database, RoleJob};
+
+    async fn create(db: &Database, server: &str) -> Result<RoleJob> {
+        RoleJob::create(
+            db,
+            server.to_string(),
+            "creator".to_string(),
+            "role".to_string(),
+            RoleJobAction::Add,
+            MemberSelection::Role {
+                id: "other".to_string(),
+            },
+        )
+        .await
+    }
+
+    #[async_std::test]
+    async fn unfinished_job_limit() {
+        database_test!(|db| async move {
+            for _ in 0..super::MAX_UNFINISHED_ROLE_JOBS {
+                create(&db, "server").await.unwrap();
+            }
+
+            let error = create(&db, "server").await.unwrap_err();
+            assert_eq!(
+                error.error_type,
+                ErrorType::TooManyPendingRoleJobs {
+                    max: super::MAX_UNFINISHED_ROLE_JOBS
+                }
+            );
+
+            // Other servers are not held back
+            create(&db, "elsewhere").await.unwrap();
+        });
+    }
+}
@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::{PartialRoleJob, RoleJob};
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerRoleJobs: Sync + Send {
+    /// Insert a new role job into the database
+    async fn insert_role_job(&self, job: &RoleJob) -> Result<()>;
+
+    /// Fetch a role job by its id
+    async fn fetch_role_job(&self, id: &str) -> Result<RoleJob>;
+
+    /// Fetch all role jobs which have not finished yet, optionally only those of a given server
+    async fn fetch_unfinished_role_jobs(&self, server: Option<&str>) -> Result<Vec<RoleJob>>;
+
+    /// Update a role job with new information
+    async fn update_role_job(&self, id: &str, partial: &PartialRoleJob) -> Result<()>;
+}
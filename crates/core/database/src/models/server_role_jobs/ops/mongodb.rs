@@ -0,0 +1,41 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::{PartialRoleJob, RoleJob};
+
+use super::AbstractServerRoleJobs;
+
+static COL: &str = "server_role_jobs";
+
+#[async_trait]
+impl AbstractServerRoleJobs for MongoDb {
+    /// Insert a new role job into the database
+    async fn insert_role_job(&self, job: &RoleJob) -> Result<()> {
+        query!(self, insert_one, COL, &job).map(|_| ())
+    }
+
+    /// Fetch a role job by its id
+    async fn fetch_role_job(&self, id: &str) -> Result<RoleJob> {
+        query!(self, find_one_by_id, COL, id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all role jobs which have not finished yet, optionally only those of a given server
+    async fn fetch_unfinished_role_jobs(&self, server: Option<&str>) -> Result<Vec<RoleJob>> {
+        let mut filter = doc! {
+            "status": {
+                "$in": ["Pending", "Running"]
+            }
+        };
+
+        if let Some(server) = server {
+            filter.insert("server", server);
+        }
+
+        query!(self, find, COL, filter)
+    }
+
+    /// Update a role job with new information
+    async fn update_role_job(&self, id: &str, partial: &PartialRoleJob) -> Result<()> {
+        query!(self, update_one_by_id, COL, id, partial, vec![], None).map(|_| ())
+    }
+}
@@ -0,0 +1,54 @@
+use revolt_models::v0::RoleJobStatus;
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::{PartialRoleJob, RoleJob};
+
+use super::AbstractServerRoleJobs;
+
+#[async_trait]
+impl AbstractServerRoleJobs for ReferenceDb {
+    /// Insert a new role job into the database
+    async fn insert_role_job(&self, job: &RoleJob) -> Result<()> {
+        let mut server_role_jobs = self.server_role_jobs.lock().await;
+        if server_role_jobs.contains_key(&job.id) {
+            Err(create_database_error!("insert", "role_job"))
+        } else {
+            server_role_jobs.insert(job.id.to_string(), job.clone());
+            Ok(())
+        }
+    }
+
+    /// Fetch a role job by its id
+    async fn fetch_role_job(&self, id: &str) -> Result<RoleJob> {
+        let server_role_jobs = self.server_role_jobs.lock().await;
+        server_role_jobs
+            .get(id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all role jobs which have not finished yet, optionally only those of a given server
+    async fn fetch_unfinished_role_jobs(&self, server: Option<&str>) -> Result<Vec<RoleJob>> {
+        let server_role_jobs = self.server_role_jobs.lock().await;
+        Ok(server_role_jobs
+            .values()
+            .filter(|job| {
+                matches!(job.status, RoleJobStatus::Pending | RoleJobStatus::Running)
+                    && server.map_or(true, |server| job.server == server)
+            })
+            .cloned()
+            .collect())
+    }
+
+    /// Update a role job with new information
+    async fn update_role_job(&self, id: &str, partial: &PartialRoleJob) -> Result<()> {
+        let mut server_role_jobs = self.server_role_jobs.lock().await;
+        if let Some(job) = server_role_jobs.get_mut(id) {
+            job.apply_options(partial.clone());
+            Ok(())
+        } else {
+            Err(create_error!(NotFound))
+        }
+    }
+}
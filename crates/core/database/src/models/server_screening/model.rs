@@ -0,0 +1,101 @@
+use revolt_models::v0::ScreeningQuestion;
+use revolt_result::{ErrorType, Result};
+
+use crate::Database;
+
+/// Maximum length of a single rule
+const MAX_RULE_LENGTH: usize = 500;
+
+/// Maximum length of a single question
+const MAX_QUESTION_LENGTH: usize = 300;
+
+/// Maximum length of an answer to a question
+const MAX_ANSWER_LENGTH: usize = 1000;
+
+auto_derived_partial!(
+    /// Membership screening settings of a server
+    pub struct ServerScreening {
+        /// Id of the server
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Whether new members must pass screening before they may participate
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub enabled: bool,
+        /// Rules new members must accept
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub rules: Vec<String>,
+        /// Questions new members are asked
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub questions: Vec<ScreeningQuestion>,
+        /// Whether a moderator must approve members after they submit
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub require_approval: bool,
+    },
+    "PartialServerScreening"
+);
+
+impl ServerScreening {
+    /// Fetch the membership screening settings of a server, falling back to defaults
+    pub async fn fetch(db: &Database, server_id: &str) -> Result<ServerScreening> {
+        match db.fetch_screening(server_id).await {
+            Ok(screening) => Ok(screening),
+            Err(error) if matches!(error.error_type, ErrorType::NotFound) => Ok(ServerScreening {
+                id: server_id.to_string(),
+                enabled: false,
+                rules: vec![],
+                questions: vec![],
+                require_approval: false,
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Update membership screening settings
+    pub async fn update(&mut self, db: &Database, partial: PartialServerScreening) -> Result<()> {
+        if partial
+            .rules
+            .iter()
+            .flatten()
+            .any(|rule| rule.is_empty() || rule.len() > MAX_RULE_LENGTH)
+            || partial.questions.iter().flatten().any(|question| {
+                question.question.is_empty() || question.question.len() > MAX_QUESTION_LENGTH
+            })
+        {
+            return Err(create_error!(InvalidProperty));
+        }
+
+        self.apply_options(partial);
+
+        // Screening with nothing to accept or answer would only ever hold members back
+        if self.enabled && self.rules.is_empty() && self.questions.is_empty() {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        db.upsert_screening(self).await
+    }
+
+    /// Ensure submitted answers line up with this server's questions
+    pub fn validate_answers(&self, answers: &[String]) -> Result<()> {
+        if answers.len() > self.questions.len()
+            || answers
+                .iter()
+                .any(|answer| answer.len() > MAX_ANSWER_LENGTH)
+        {
+            return Err(create_error!(InvalidProperty));
+        }
+
+        for (index, question) in self.questions.iter().enumerate() {
+            if question.required
+                && answers
+                    .get(index)
+                    .map_or(true, |answer| answer.trim().is_empty())
+            {
+                return Err(create_error!(FailedValidation {
+                    error: format!("question {} must be answered", index + 1)
+                }));
+            }
+        }
+
+        Ok(())
+    }
+}
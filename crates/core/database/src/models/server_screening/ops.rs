@@ -0,0 +1,15 @@
+use revolt_result::Result;
+
+use crate::ServerScreening;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerScreening: Sync + Send {
+    /// Fetch the membership screening settings of a server
+    async fn fetch_screening(&self, server_id: &str) -> Result<ServerScreening>;
+
+    /// Insert or replace the membership screening settings of a server
+    async fn upsert_screening(&self, screening: &ServerScreening) -> Result<()>;
+}
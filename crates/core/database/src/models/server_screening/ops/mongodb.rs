@@ -0,0 +1,32 @@
+use mongodb::options::ReplaceOptions;
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::ServerScreening;
+
+use super::AbstractServerScreening;
+
+static COL: &str = "server_screening";
+
+#[async_trait]
+impl AbstractServerScreening for MongoDb {
+    /// Fetch the membership screening settings of a server
+    async fn fetch_screening(&self, server_id: &str) -> Result<ServerScreening> {
+        query!(self, find_one_by_id, COL, server_id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Insert or replace the membership screening settings of a server
+    async fn upsert_screening(&self, screening: &ServerScreening) -> Result<()> {
+        self.col::<ServerScreening>(COL)
+            .replace_one(
+                doc! {
+                    "_id": &screening.id
+                },
+                screening,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("replace_one", COL))
+    }
+}
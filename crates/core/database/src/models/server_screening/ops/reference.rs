@@ -0,0 +1,25 @@
+use revolt_result::Result;
+
+use crate::ReferenceDb;
+use crate::ServerScreening;
+
+use super::AbstractServerScreening;
+
+#[async_trait]
+impl AbstractServerScreening for ReferenceDb {
+    /// Fetch the membership screening settings of a server
+    async fn fetch_screening(&self, server_id: &str) -> Result<ServerScreening> {
+        let server_screening = self.server_screening.lock().await;
+        server_screening
+            .get(server_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Insert or replace the membership screening settings of a server
+    async fn upsert_screening(&self, screening: &ServerScreening) -> Result<()> {
+        let mut server_screening = self.server_screening.lock().await;
+        server_screening.insert(screening.id.to_string(), screening.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,444 @@
+use std::collections::HashMap;
+
+use iso8601_timestamp::Timestamp;
+use revolt_models::v0::{DataCreateServerTemplate, TemplateChannelType};
+use revolt_permissions::OverrideField;
+use revolt_result::{create_error, Result};
+use ulid::Ulid;
+
+use crate::{Category, Channel, Database, Role, Server, SystemMessageChannels};
+
+/// Maximum number of templates a single server may have
+const MAX_TEMPLATES_PER_SERVER: usize = 5;
+
+auto_derived!(
+    /// Snapshot of a server's structure which new servers can be created from
+    pub struct ServerTemplate {
+        /// Code used to share this template
+        #[serde(rename = "_id")]
+        pub code: String,
+        /// Id of the server this template was created from
+        pub server: String,
+        /// Id of the user who created this template
+        pub creator: String,
+
+        /// Name of the template
+        pub name: String,
+        /// Description of the template
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Time at which this template was created
+        pub created_at: Timestamp,
+
+        /// Captured server structure
+        pub snapshot: ServerTemplateSnapshot,
+    }
+
+    /// Structure of a server captured by a template
+    pub struct ServerTemplateSnapshot {
+        /// Channels in the order they appeared on the server
+        pub channels: Vec<TemplateChannel>,
+        /// Categories for the channels
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub categories: Vec<Category>,
+        /// Configuration for sending system event messages
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub system_messages: Option<SystemMessageChannels>,
+        /// Roles and their permissions
+        #[serde(
+            default = "HashMap::<String, Role>::new",
+            skip_serializing_if = "HashMap::<String, Role>::is_empty"
+        )]
+        pub roles: HashMap<String, Role>,
+        /// Default set of server and channel permissions
+        pub default_permissions: i64,
+    }
+
+    /// Channel captured by a template
+    pub struct TemplateChannel {
+        /// Id of the channel within the template
+        pub id: String,
+        /// Type of channel
+        pub channel_type: TemplateChannelType,
+        /// Display name of the channel
+        pub name: String,
+        /// Channel description
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub description: Option<String>,
+        /// Default permissions assigned to users in this channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this channel
+        #[serde(
+            default = "HashMap::<String, OverrideField>::new",
+            skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+        /// Whether this channel is marked as not safe for work
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub nsfw: bool,
+        /// Seconds a member has to wait between sending messages
+        #[serde(skip_serializing_if = "crate::if_zero_u32", default)]
+        pub slowmode_seconds: u32,
+    }
+);
+
+impl ServerTemplate {
+    /// Create a template from the current structure of a server
+    pub async fn create(
+        db: &Database,
+        server: &Server,
+        creator_id: String,
+        data: DataCreateServerTemplate,
+    ) -> Result<ServerTemplate> {
+        if db.fetch_server_templates(&server.id).await?.len() >= MAX_TEMPLATES_PER_SERVER {
+            return Err(create_error!(TooManyServerTemplates {
+                max: MAX_TEMPLATES_PER_SERVER
+            }));
+        }
+
+        let channels = db.fetch_channels(&server.channels).await?;
+        let template = ServerTemplate {
+            code: nanoid::nanoid!(12),
+            server: server.id.clone(),
+            creator: creator_id,
+            name: data.name,
+            description: data.description,
+            created_at: Timestamp::now_utc(),
+            snapshot: ServerTemplateSnapshot::capture(server, channels),
+        };
+
+        db.insert_server_template(&template).await?;
+        Ok(template)
+    }
+
+    /// Delete this template
+    pub async fn delete(&self, db: &Database) -> Result<()> {
+        db.delete_server_template(&self.code).await
+    }
+}
+
+impl ServerTemplateSnapshot {
+    /// Capture the channels, categories, roles and permissions of a server
+    pub fn capture(server: &Server, mut channels: Vec<Channel>) -> ServerTemplateSnapshot {
+        channels.sort_by_key(|channel| {
+            let id = channel.id();
+            server.channels.iter().position(|entry| entry == &id)
+        });
+
+        ServerTemplateSnapshot {
+            channels: channels
+                .into_iter()
+                .filter_map(|channel| match channel {
+                    Channel::TextChannel {
+                        id,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        nsfw,
+                        slowmode_seconds,
+                        ..
+                    } => Some(TemplateChannel {
+                        id,
+                        channel_type: TemplateChannelType::Text,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        nsfw,
+                        slowmode_seconds,
+                    }),
+                    Channel::VoiceChannel {
+                        id,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        nsfw,
+                        ..
+                    } => Some(TemplateChannel {
+                        id,
+                        channel_type: TemplateChannelType::Voice,
+                        name,
+                        description,
+                        default_permissions,
+                        role_permissions,
+                        nsfw,
+                        slowmode_seconds: 0,
+                    }),
+                    _ => None,
+                })
+                .collect(),
+            categories: server.categories.clone().unwrap_or_default(),
+            system_messages: server.system_messages.clone(),
+            // Icons belong to the source server, so they are left behind
+            roles: server
+                .roles
+                .iter()
+                .map(|(id, role)| {
+                    (
+                        id.clone(),
+                        Role {
+                            icon: None,
+                            ..role.clone()
+                        },
+                    )
+                })
+                .collect(),
+            default_permissions: server.default_permissions,
+        }
+    }
+
+    /// Apply this structure to a new server, returning the channels which must be created
+    ///
+    /// Every channel, role and category is given a fresh id, references
+    /// to anything not captured by the template are dropped.
+    pub fn apply(&self, server: &mut Server) -> Vec<Channel> {
+        let role_ids: HashMap<&str, String> = self
+            .roles
+            .keys()
+            .map(|id| (id.as_str(), Ulid::new().to_string()))
+            .collect();
+
+        let channel_ids: HashMap<&str, String> = self
+            .channels
+            .iter()
+            .map(|channel| (channel.id.as_str(), Ulid::new().to_string()))
+            .collect();
+
+        let map_channel =
+            |id: &Option<String>| id.as_deref().and_then(|id| channel_ids.get(id)).cloned();
+
+        let map_channels = |ids: &[String]| -> Vec<String> {
+            ids.iter()
+                .filter_map(|id| channel_ids.get(id.as_str()).cloned())
+                .collect()
+        };
+
+        let map_role_permissions =
+            |role_permissions: &HashMap<String, OverrideField>| -> HashMap<String, OverrideField> {
+                role_permissions
+                    .iter()
+                    .filter_map(|(role, permissions)| {
+                        role_ids
+                            .get(role.as_str())
+                            .map(|role| (role.clone(), *permissions))
+                    })
+                    .collect()
+            };
+
+        let channels: Vec<Channel> = self
+            .channels
+            .iter()
+            .map(|channel| {
+                let id = channel_ids[channel.id.as_str()].clone();
+                let role_permissions = map_role_permissions(&channel.role_permissions);
+
+                match channel.channel_type {
+                    TemplateChannelType::Text => Channel::TextChannel {
+                        id,
+                        server: server.id.clone(),
+                        name: channel.name.clone(),
+                        description: channel.description.clone(),
+                        icon: None,
+                        last_message_id: None,
+                        default_permissions: channel.default_permissions,
+                        role_permissions,
+                        nsfw: channel.nsfw,
+                        slowmode_seconds: channel.slowmode_seconds,
+                        announcement: false,
+                    },
+                    TemplateChannelType::Voice => Channel::VoiceChannel {
+                        id,
+                        server: server.id.clone(),
+                        name: channel.name.clone(),
+                        description: channel.description.clone(),
+                        icon: None,
+                        default_permissions: channel.default_permissions,
+                        role_permissions,
+                        nsfw: channel.nsfw,
+                    },
+                }
+            })
+            .collect();
+
+        server.channels = channels.iter().map(|channel| channel.id()).collect();
+        server.roles = self
+            .roles
+            .iter()
+            .map(|(id, role)| {
+                (
+                    role_ids[id.as_str()].clone(),
+                    Role {
+                        icon: None,
+                        ..role.clone()
+                    },
+                )
+            })
+            .collect();
+        server.default_permissions = self.default_permissions;
+
+        server.categories = if self.categories.is_empty() {
+            None
+        } else {
+            Some(
+                self.categories
+                    .iter()
+                    .map(|category| Category {
+                        id: Ulid::new().to_string(),
+                        title: category.title.clone(),
+                        channels: map_channels(&category.channels),
+                        default_permissions: category.default_permissions,
+                        role_permissions: map_role_permissions(&category.role_permissions),
+                        synced: map_channels(&category.synced),
+                    })
+                    .collect(),
+            )
+        };
+
+        server.system_messages =
+            self.system_messages
+                .as_ref()
+                .map(|system_messages| SystemMessageChannels {
+                    user_joined: map_channel(&system_messages.user_joined),
+                    user_left: map_channel(&system_messages.user_left),
+                    user_kicked: map_channel(&system_messages.user_kicked),
+                    user_banned: map_channel(&system_messages.user_banned),
+                });
+
+        channels
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use revolt_permissions::OverrideField;
+
+    use crate::{
+        Category, Channel, File, Metadata, Role, Server, ServerTemplateSnapshot,
+        SystemMessageChannels,
+    };
+
+    #[test]
+    fn apply_remaps_ids() {
+        let role = Role {
+            name: "Moderator".to_string(),
+            permissions: OverrideField::default(),
+            colour: None,
+            icon: None,
+            emoji: None,
+            hoist: true,
+            mentionable: false,
+            rank: 1,
+        };
+
+        let source = Server {
+            id: "source".to_string(),
+            channels: vec!["general".to_string(), "missing".to_string()],
+            categories: Some(vec![Category {
+                id: "category".to_string(),
+                title: "Text".to_string(),
+                channels: vec!["general".to_string(), "missing".to_string()],
+                default_permissions: None,
+                role_permissions: HashMap::new(),
+                synced: vec!["general".to_string()],
+            }]),
+            system_messages: Some(SystemMessageChannels {
+                user_joined: Some("general".to_string()),
+                user_left: Some("missing".to_string()),
+                user_kicked: None,
+                user_banned: None,
+            }),
+            roles: HashMap::from([(
+                "moderator".to_string(),
+                Role {
+                    icon: Some(File {
+                        id: "icon".to_string(),
+                        tag: "icons".to_string(),
+                        filename: "icon.png".to_string(),
+                        metadata: Metadata::Image {
+                            width: 64,
+                            height: 64,
+                        },
+                        content_type: "image/png".to_string(),
+                        size: 1024,
+                        deleted: None,
+                        reported: None,
+                        message_id: None,
+                        user_id: None,
+                        server_id: None,
+                        object_id: Some("moderator".to_string()),
+                    }),
+                    ..role.clone()
+                },
+            )]),
+            ..Default::default()
+        };
+
+        let snapshot = ServerTemplateSnapshot::capture(
+            &source,
+            vec![Channel::TextChannel {
+                id: "general".to_string(),
+                server: "source".to_string(),
+                name: "General".to_string(),
+                description: None,
+                icon: None,
+                last_message_id: Some("message".to_string()),
+                default_permissions: None,
+                role_permissions: HashMap::from([(
+                    "moderator".to_string(),
+                    OverrideField::default(),
+                )]),
+                nsfw: false,
+                slowmode_seconds: 5,
+                announcement: true,
+            }],
+        );
+
+        assert!(snapshot.roles["moderator"].icon.is_none());
+
+        let mut server = Server {
+            id: "target".to_string(),
+            ..Default::default()
+        };
+
+        let channels = snapshot.apply(&mut server);
+        assert_eq!(channels.len(), 1);
+        assert_eq!(server.channels, vec![channels[0].id()]);
+        assert_ne!(server.channels[0], "general");
+
+        let (role_id, new_role) = server.roles.iter().next().unwrap();
+        assert_ne!(role_id, "moderator");
+        assert_eq!(new_role, &role);
+
+        if let Channel::TextChannel {
+            server: server_id,
+            role_permissions,
+            last_message_id,
+            slowmode_seconds,
+            ..
+        } = &channels[0]
+        {
+            assert_eq!(server_id, "target");
+            assert!(role_permissions.contains_key(role_id));
+            assert!(last_message_id.is_none());
+            assert_eq!(*slowmode_seconds, 5);
+        } else {
+            panic!("expected a text channel");
+        }
+
+        let categories = server.categories.unwrap();
+        assert_eq!(categories[0].channels, server.channels);
+        assert_eq!(categories[0].synced, server.channels);
+
+        let system_messages = server.system_messages.unwrap();
+        assert_eq!(
+            system_messages.user_joined,
+            Some(server.channels[0].clone())
+        );
+        assert!(system_messages.user_left.is_none());
+    }
+}
@@ -0,0 +1,21 @@
+use revolt_result::Result;
+
+use crate::ServerTemplate;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractServerTemplates: Sync + Send {
+    /// Insert a new server template into the database
+    async fn insert_server_template(&self, template: &ServerTemplate) -> Result<()>;
+
+    /// Fetch a server template by its code
+    async fn fetch_server_template(&self, code: &str) -> Result<ServerTemplate>;
+
+    /// Fetch all templates created from a server
+    async fn fetch_server_templates(&self, server_id: &str) -> Result<Vec<ServerTemplate>>;
+
+    /// Delete a server template by its code
+    async fn delete_server_template(&self, code: &str) -> Result<()>;
+}
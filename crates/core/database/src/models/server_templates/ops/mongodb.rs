@@ -0,0 +1,38 @@
+use revolt_result::Result;
+
+use crate::MongoDb;
+use crate::ServerTemplate;
+
+use super::AbstractServerTemplates;
+
+static COL: &str = "server_templates";
+
+#[async_trait]
+impl AbstractServerTemplates for MongoDb {
+    /// Insert a new server template into the database
+    async fn insert_server_template(&self, template: &ServerTemplate) -> Result<()> {
+        query!(self, insert_one, COL, &template).map(|_| ())
+    }
+
+    /// Fetch a server template by its code
+    async fn fetch_server_template(&self, code: &str) -> Result<ServerTemplate> {
+        query!(self, find_one_by_id, COL, code)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Fetch all templates created from a server
+    async fn fetch_server_templates(&self, server_id: &str) -> Result<Vec<ServerTemplate>> {
+        query!(
+            self,
+            find,
+            COL,
+            doc! {
+                "server": server_id
+            }
+        )
+    }
+
+    /// Delete a server template by its code
+    async fn delete_server_template(&self, code: &str) -> Result<()> {
+        query!(self, delete_one_by_id, COL, code).map(|_| ())
+    }
+}
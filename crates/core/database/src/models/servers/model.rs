@@ -4,7 +4,7 @@ use revolt_permissions::OverrideField;
 use revolt_result::Result;
 use ulid::Ulid;
 
-use crate::{events::client::EventV1, Database, File};
+use crate::{events::client::EventV1, ChannelFollower, Database, File, Message};
 
 auto_derived_partial!(
     /// Server
@@ -60,6 +60,10 @@ auto_derived_partial!(
         /// Whether this server should be publicly discoverable
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub discoverable: bool,
+
+        /// Id of the member an ownership transfer is awaiting acceptance from
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub pending_owner: Option<String>,
     },
     "PartialServer"
 );
@@ -76,9 +80,18 @@ auto_derived_partial!(
         /// This can be any valid CSS colour
         #[serde(skip_serializing_if = "Option::is_none")]
         pub colour: Option<String>,
+        /// Icon shown next to members with this role
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub icon: Option<File>,
+        /// Unicode emoji shown next to members with this role
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub emoji: Option<String>,
         /// Whether this role should be shown separately on the member sidebar
         #[serde(skip_serializing_if = "crate::if_false", default)]
         pub hoist: bool,
+        /// Whether anyone can mention this role
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub mentionable: bool,
         /// Ranking of this role
         #[serde(default)]
         pub rank: i64,
@@ -95,6 +108,19 @@ auto_derived!(
         pub title: String,
         /// Channels in this category
         pub channels: Vec<String>,
+
+        /// Default permissions assigned to users in this category
+        #[serde(skip_serializing_if = "Option::is_none", default)]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this category
+        #[serde(
+            default = "HashMap::<String, OverrideField>::new",
+            skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+        /// Channels which use this category's permissions in place of their own
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub synced: Vec<String>,
     }
 
     /// System message channel assignments
@@ -120,11 +146,14 @@ auto_derived!(
         SystemMessages,
         Icon,
         Banner,
+        PendingOwner,
     }
 
     /// Optional fields on server object
     pub enum FieldsRole {
         Colour,
+        Icon,
+        Emoji,
     }
 );
 
@@ -147,6 +176,7 @@ impl Default for Server {
             flags: Default::default(),
             analytics: Default::default(),
             discoverable: Default::default(),
+            pending_owner: Default::default(),
         }
     }
 }
@@ -192,7 +222,9 @@ impl Server {
         .p(self.id.clone())
         .await;
 
-        db.delete_server(&self.id).await
+        db.delete_server(&self.id).await?;
+        ChannelFollower::delete_for_channels(db, &self.channels).await?;
+        Message::detach_channel_forwards(db, &self.channels).await
     }
 
     /// Remove a field from Server
@@ -203,9 +235,18 @@ impl Server {
             FieldsServer::SystemMessages => self.system_messages = None,
             FieldsServer::Icon => self.icon = None,
             FieldsServer::Banner => self.banner = None,
+            FieldsServer::PendingOwner => self.pending_owner = None,
         }
     }
 
+    /// Find the category whose permissions a channel uses in place of its own
+    pub fn synced_category(&self, channel_id: &str) -> Option<&Category> {
+        self.categories
+            .as_ref()?
+            .iter()
+            .find(|category| category.synced.iter().any(|id| id == channel_id))
+    }
+
     /// Set role permission on a server
     pub async fn set_role_permission(
         &mut self,
@@ -232,6 +273,70 @@ impl Server {
         }
     }
 
+    /// Ask a member to accept ownership of this server
+    pub async fn request_ownership_transfer(&mut self, db: &Database, user_id: &str) -> Result<()> {
+        if self.owner == user_id {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        if self.pending_owner.as_deref() == Some(user_id) {
+            return Err(create_error!(NoEffect));
+        }
+
+        db.fetch_member(&self.id, user_id).await?;
+
+        self.update(
+            db,
+            PartialServer {
+                pending_owner: Some(user_id.to_string()),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
+    }
+
+    /// Accept a pending ownership transfer as the requested member
+    pub async fn accept_ownership_transfer(&mut self, db: &Database, user_id: &str) -> Result<()> {
+        if self.pending_owner.as_deref() != Some(user_id) {
+            return Err(create_error!(NotFound));
+        }
+
+        // The member may have left since the transfer was requested
+        db.fetch_member(&self.id, user_id).await?;
+
+        self.update(
+            db,
+            PartialServer {
+                owner: Some(user_id.to_string()),
+                ..Default::default()
+            },
+            vec![FieldsServer::PendingOwner],
+        )
+        .await
+    }
+
+    /// Cancel or decline a pending ownership transfer
+    pub async fn cancel_ownership_transfer(&mut self, db: &Database) -> Result<()> {
+        if self.pending_owner.is_none() {
+            return Err(create_error!(NoEffect));
+        }
+
+        self.update(db, Default::default(), vec![FieldsServer::PendingOwner])
+            .await
+    }
+
+    /// Get the ranking of a user in this server
+    ///
+    /// Lower values rank higher, the owner outranks every role.
+    pub async fn get_member_ranking(&self, db: &Database, user_id: &str) -> Result<i64> {
+        if self.owner == user_id {
+            Ok(i64::MIN)
+        } else {
+            Ok(db.fetch_member(&self.id, user_id).await?.get_ranking(self))
+        }
+    }
+
     /* /// Create a new member in a server
     pub async fn create_member(
         &self,
@@ -380,6 +485,9 @@ impl Role {
             colour: self.colour,
             hoist: Some(self.hoist),
             rank: Some(self.rank),
+            icon: self.icon,
+            emoji: self.emoji,
+            mentionable: Some(self.mentionable),
         }
     }
 
@@ -434,6 +542,8 @@ impl Role {
     pub fn remove_field(&mut self, field: &FieldsRole) {
         match field {
             FieldsRole::Colour => self.colour = None,
+            FieldsRole::Icon => self.icon = None,
+            FieldsRole::Emoji => self.emoji = None,
         }
     }
 
@@ -169,6 +169,7 @@ impl IntoDocumentPath for FieldsServer {
             FieldsServer::Categories => "categories",
             FieldsServer::Description => "description",
             FieldsServer::Icon => "icon",
+            FieldsServer::PendingOwner => "pending_owner",
             FieldsServer::SystemMessages => "system_messages",
         })
     }
@@ -178,6 +179,8 @@ impl IntoDocumentPath for FieldsRole {
     fn as_path(&self) -> Option<&'static str> {
         Some(match self {
             FieldsRole::Colour => "colour",
+            FieldsRole::Icon => "icon",
+            FieldsRole::Emoji => "emoji",
         })
     }
 }
@@ -0,0 +1,130 @@
+use revolt_models::v0::DirectMessagePrivacy;
+use revolt_result::{ErrorType, Result};
+
+use crate::{events::client::EventV1, Database};
+
+/// Maximum length of a single filtered word
+const MAX_WORD_LENGTH: usize = 64;
+
+auto_derived_partial!(
+    /// Personal content filter applied to direct messages and groups
+    pub struct ContentFilter {
+        /// Id of the user this filter belongs to
+        #[serde(rename = "_id")]
+        pub id: String,
+        /// Messages containing any of these words are hidden
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        pub words: Vec<String>,
+        /// Whether attachments sent by users who are not friends are hidden
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub hide_stranger_attachments: bool,
+        /// Who may send direct messages
+        #[serde(default)]
+        pub direct_messages: DirectMessagePrivacy,
+    },
+    "PartialContentFilter"
+);
+
+impl ContentFilter {
+    /// Fetch the content filter of a user, falling back to one which lets everything through
+    pub async fn fetch(db: &Database, user_id: &str) -> Result<ContentFilter> {
+        match db.fetch_content_filter(user_id).await {
+            Ok(filter) => Ok(filter),
+            Err(error) if matches!(error.error_type, ErrorType::NotFound) => Ok(ContentFilter {
+                id: user_id.to_string(),
+                words: vec![],
+                hide_stranger_attachments: false,
+                direct_messages: Default::default(),
+            }),
+            Err(error) => Err(error),
+        }
+    }
+
+    /// Update content filter
+    pub async fn update(&mut self, db: &Database, mut partial: PartialContentFilter) -> Result<()> {
+        if let Some(words) = &mut partial.words {
+            for word in words.iter_mut() {
+                *word = word.trim().to_lowercase();
+            }
+
+            if words
+                .iter()
+                .any(|word| word.is_empty() || word.len() > MAX_WORD_LENGTH)
+            {
+                return Err(create_error!(InvalidProperty));
+            }
+
+            words.sort();
+            words.dedup();
+        }
+
+        self.apply_options(partial);
+        db.upsert_content_filter(self).await?;
+
+        EventV1::UserContentFilterUpdate {
+            id: self.id.clone(),
+            filter: self.clone().into(),
+        }
+        .private(self.id.clone())
+        .await;
+
+        Ok(())
+    }
+
+    /// Check whether a message's content contains any filtered word
+    ///
+    /// Words only match on their own, so filtering "cat" does not hide "concatenate".
+    pub fn hides_content(&self, content: &str) -> bool {
+        if self.words.is_empty() {
+            return false;
+        }
+
+        let content = content.to_lowercase();
+        self.words.iter().any(|word| {
+            content.match_indices(word.as_str()).any(|(start, _)| {
+                let before = content[..start].chars().next_back();
+                let after = content[start + word.len()..].chars().next();
+                !before.is_some_and(char::is_alphanumeric)
+                    && !after.is_some_and(char::is_alphanumeric)
+            })
+        })
+    }
+
+    /// Check whether attachments sent by a user should be hidden
+    pub fn hides_attachments_from(&self, is_friend: bool) -> bool {
+        self.hide_stranger_attachments && !is_friend
+    }
+
+    /// Check whether a user may send direct messages at all
+    pub fn accepts_request_from(&self, is_friend: bool) -> bool {
+        is_friend || !matches!(self.direct_messages, DirectMessagePrivacy::Friends)
+    }
+
+    /// Check whether a new conversation with a user starts out as a message request
+    pub fn requires_request_from(&self, is_friend: bool) -> bool {
+        !is_friend && matches!(self.direct_messages, DirectMessagePrivacy::Requests)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ContentFilter;
+
+    #[test]
+    fn hides_content() {
+        let filter = ContentFilter {
+            id: "user".to_string(),
+            words: vec!["cat".to_string(), "bad word".to_string()],
+            hide_stranger_attachments: false,
+            direct_messages: Default::default(),
+        };
+
+        assert!(filter.hides_content("cat"));
+        assert!(filter.hides_content("My CAT, again."));
+        assert!(filter.hides_content("that is a bad word!"));
+        assert!(!filter.hides_content("concatenate"));
+        assert!(!filter.hides_content("cats"));
+        assert!(!filter.hides_content("a badword"));
+        assert!(!filter.hides_content(""));
+    }
+}
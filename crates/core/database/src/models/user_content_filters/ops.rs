@@ -0,0 +1,15 @@
+use revolt_result::Result;
+
+use crate::ContentFilter;
+
+mod mongodb;
+mod reference;
+
+#[async_trait]
+pub trait AbstractUserContentFilters: Sync + Send {
+    /// Fetch the content filter of a user
+    async fn fetch_content_filter(&self, user_id: &str) -> Result<ContentFilter>;
+
+    /// Insert or replace the content filter of a user
+    async fn upsert_content_filter(&self, filter: &ContentFilter) -> Result<()>;
+}
@@ -0,0 +1,32 @@
+use mongodb::options::ReplaceOptions;
+use revolt_result::Result;
+
+use crate::ContentFilter;
+use crate::MongoDb;
+
+use super::AbstractUserContentFilters;
+
+static COL: &str = "user_content_filters";
+
+#[async_trait]
+impl AbstractUserContentFilters for MongoDb {
+    /// Fetch the content filter of a user
+    async fn fetch_content_filter(&self, user_id: &str) -> Result<ContentFilter> {
+        query!(self, find_one_by_id, COL, user_id)?.ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Insert or replace the content filter of a user
+    async fn upsert_content_filter(&self, filter: &ContentFilter) -> Result<()> {
+        self.col::<ContentFilter>(COL)
+            .replace_one(
+                doc! {
+                    "_id": &filter.id
+                },
+                filter,
+                ReplaceOptions::builder().upsert(true).build(),
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| create_database_error!("replace_one", COL))
+    }
+}
@@ -0,0 +1,25 @@
+use revolt_result::Result;
+
+use crate::ContentFilter;
+use crate::ReferenceDb;
+
+use super::AbstractUserContentFilters;
+
+#[async_trait]
+impl AbstractUserContentFilters for ReferenceDb {
+    /// Fetch the content filter of a user
+    async fn fetch_content_filter(&self, user_id: &str) -> Result<ContentFilter> {
+        let user_content_filters = self.user_content_filters.lock().await;
+        user_content_filters
+            .get(user_id)
+            .cloned()
+            .ok_or_else(|| create_error!(NotFound))
+    }
+
+    /// Insert or replace the content filter of a user
+    async fn upsert_content_filter(&self, filter: &ContentFilter) -> Result<()> {
+        let mut user_content_filters = self.user_content_filters.lock().await;
+        user_content_filters.insert(filter.id.to_string(), filter.clone());
+        Ok(())
+    }
+}
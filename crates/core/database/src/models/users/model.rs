@@ -628,7 +628,23 @@ impl User {
     }
 
     pub async fn from_token(db: &Database, token: &str) -> Result<User> {
-        db.fetch_user(&db.fetch_bot_by_token(token).await?.id).await
+        let user = db
+            .fetch_user(&db.fetch_bot_by_token(token).await?.id)
+            .await?;
+        user.throw_if_restricted()?;
+        Ok(user)
+    }
+
+    /// Throw an error if this account has been suspended or banned from the platform
+    pub fn throw_if_restricted(&self) -> Result<()> {
+        let flags = self.flags.unwrap_or_default();
+        if flags & v0::UserFlags::Banned as i32 != 0 {
+            Err(create_error!(AccountBanned))
+        } else if flags & v0::UserFlags::Suspended as i32 != 0 {
+            Err(create_error!(AccountSuspended))
+        } else {
+            Ok(())
+        }
     }
 }
 
@@ -650,6 +666,7 @@ impl User {
                 active: true,
                 recipients: vec![id.clone(), user_id.clone()],
                 last_message_id: None,
+                requested_by: None,
             }
             .create(db)
             .await?;
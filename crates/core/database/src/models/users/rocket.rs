@@ -42,6 +42,10 @@ impl<'r> FromRequest<'r> for User {
             .await;
 
         if let Some(user) = user {
+            if user.throw_if_restricted().is_err() && !allows_restricted(request) {
+                return Outcome::Failure((Status::Forbidden, authifier::Error::InvalidSession));
+            }
+
             return Outcome::Success(user.clone());
         }
         // else {
@@ -113,6 +117,14 @@ impl<'r> FromRequest<'r> for User {
     }
 }
 
+/// Whether a suspended or banned account may still use this route,
+/// they need to be able to see their standing and appeal strikes
+fn allows_restricted(request: &Request<'_>) -> bool {
+    let path = request.uri().path().as_str();
+    path.ends_with("/safety/standing")
+        || (path.contains("/safety/strikes/") && path.ends_with("/appeal"))
+}
+
 impl User {
     pub async fn get_or_create_new_user(
         authifier: &Authifier,
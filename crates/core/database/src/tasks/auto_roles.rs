@@ -0,0 +1,25 @@
+use async_std::task;
+use std::time::Duration;
+
+use crate::Database;
+
+/// How often to give out roles to members who have been around long enough
+const POLL_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match db.fetch_tenure_auto_role_rules().await {
+            Ok(rules) => {
+                for mut rule in rules {
+                    if let Err(err) = rule.apply_tenure(&db).await {
+                        error!("Failed to apply auto role rule {}: {err:?}", rule.id);
+                    }
+                }
+            }
+            Err(err) => error!("Failed to fetch tenure auto role rules: {err:?}"),
+        }
+
+        task::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,38 @@
+use async_std::task;
+use std::time::Duration;
+
+use crate::{events::client::EventV1, Database};
+
+/// How often to look for bans which have expired
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match db.fetch_expired_bans().await {
+            Ok(bans) => {
+                for ban in bans {
+                    if let Err(err) = db.delete_ban(&ban.id).await {
+                        error!("Failed to lift expired ban {:?}: {err:?}", ban.id);
+                        continue;
+                    }
+
+                    info!(
+                        "Lifted expired ban for {} in {}.",
+                        ban.id.user, ban.id.server
+                    );
+
+                    EventV1::ServerMemberUnban {
+                        id: ban.id.server.clone(),
+                        user: ban.id.user,
+                    }
+                    .p(ban.id.server)
+                    .await;
+                }
+            }
+            Err(err) => error!("Failed to fetch expired bans: {err:?}"),
+        }
+
+        task::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,178 @@
+use crate::{
+    ChannelExport, ChannelExportPart, Database, MessageFilter, MessageQuery, MessageTimePeriod,
+    PartialChannelExport,
+};
+
+use async_std::task;
+use deadqueue::limited::Queue;
+use iso8601_timestamp::Timestamp;
+use once_cell::sync::Lazy;
+use revolt_models::v0::{ExportStatus, MessageSort};
+use revolt_result::Result;
+use std::time::{Duration, SystemTime};
+use ulid::Ulid;
+
+/// Number of messages rendered into each part of an export
+const PAGE_SIZE: i64 = 100;
+
+/// How long finished exports are kept around for download
+const RETENTION: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// How often to look for exports which have outlived their retention
+const POLL_INTERVAL: Duration = Duration::from_secs(3600);
+
+static Q: Lazy<Queue<String>> = Lazy::new(|| Queue::new(1_000));
+
+/// Queue a new export for a worker
+///
+/// Exports which don't fit in the queue stay pending until the next startup picks them up.
+pub async fn queue(id: String) {
+    if Q.try_push(id.clone()).is_err() {
+        warn!("Export queue is full, {id} will be picked up on the next startup.");
+    }
+
+    info!("Queue is using {} slots from {}.", Q.len(), Q.capacity());
+}
+
+/// Re-queue exports which were interrupted or never picked up before a restart
+pub async fn recover(db: Database) {
+    match db.fetch_unfinished_channel_exports(None).await {
+        Ok(exports) => {
+            for export in exports {
+                queue(export.id).await;
+            }
+        }
+        Err(err) => error!("Failed to fetch unfinished channel exports: {err:?}"),
+    }
+}
+
+/// Start a new worker which removes exports past their retention
+pub async fn expiry_worker(db: Database) {
+    loop {
+        let before = Ulid::from_datetime(SystemTime::now() - RETENTION).to_string();
+        match db.fetch_stale_channel_exports(&before).await {
+            Ok(exports) => {
+                for export in exports {
+                    let id = export.id.clone();
+                    if let Err(err) = export.delete(&db).await {
+                        error!("Failed to delete expired channel export {id}: {err:?}");
+                    }
+                }
+            }
+            Err(err) => error!("Failed to fetch expired channel exports: {err:?}"),
+        }
+
+        task::sleep(POLL_INTERVAL).await;
+    }
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        let id = Q.pop().await;
+        let mut export = match db.fetch_channel_export(&id).await {
+            Ok(export) => export,
+            Err(err) => {
+                error!("Failed to fetch channel export {id}: {err:?}");
+                continue;
+            }
+        };
+
+        if let Err(err) = run(&db, &mut export).await {
+            error!("Encountered an error exporting channel: {err:?}");
+
+            // Nothing can be downloaded from a failed export
+            if let Err(err) = db.delete_channel_export_parts(&export.id).await {
+                error!("Failed to remove parts of channel export {id}: {err:?}");
+            }
+
+            export
+                .update(
+                    &db,
+                    PartialChannelExport {
+                        status: Some(ExportStatus::Failed),
+                        ..Default::default()
+                    },
+                )
+                .await
+                .ok();
+        }
+    }
+}
+
+/// Walk the channel history from oldest to newest, rendering one part per page
+async fn run(db: &Database, export: &mut ChannelExport) -> Result<()> {
+    // Start over if a previous attempt was interrupted part way through
+    db.delete_channel_export_parts(&export.id).await?;
+    export
+        .update(
+            db,
+            PartialChannelExport {
+                status: Some(ExportStatus::Running),
+                processed: Some(0),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let mut after = None;
+    let mut index = 0;
+
+    loop {
+        let messages = db
+            .fetch_messages(MessageQuery {
+                limit: Some(PAGE_SIZE),
+                filter: MessageFilter {
+                    channel: Some(export.channel.clone()),
+                    author: None,
+                    query: None,
+                },
+                time_period: MessageTimePeriod::Absolute {
+                    before: None,
+                    after: after.clone(),
+                    sort: Some(MessageSort::Oldest),
+                },
+            })
+            .await?;
+
+        let Some(last) = messages.last() else {
+            break;
+        };
+
+        after = Some(last.id.clone());
+
+        db.insert_channel_export_part(&ChannelExportPart {
+            id: Ulid::new().to_string(),
+            export: export.id.clone(),
+            index,
+            data: export.render_page(db, &messages).await?,
+        })
+        .await?;
+
+        index += 1;
+        export
+            .update(
+                db,
+                PartialChannelExport {
+                    processed: Some(export.processed + messages.len() as u32),
+                    ..Default::default()
+                },
+            )
+            .await?;
+
+        if (messages.len() as i64) < PAGE_SIZE {
+            break;
+        }
+    }
+
+    export
+        .update(
+            db,
+            PartialChannelExport {
+                status: Some(ExportStatus::Completed),
+                completed_at: Some(Timestamp::now_utc()),
+                ..Default::default()
+            },
+        )
+        .await
+}
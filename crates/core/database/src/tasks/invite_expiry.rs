@@ -0,0 +1,25 @@
+use async_std::task;
+use std::time::Duration;
+
+use crate::Database;
+
+/// How often to look for invites which have expired
+const POLL_INTERVAL: Duration = Duration::from_secs(300);
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match db.fetch_expired_invites().await {
+            Ok(invites) => {
+                for invite in invites {
+                    if let Err(err) = db.delete_invite(invite.code()).await {
+                        error!("Failed to delete expired invite {}: {err:?}", invite.code());
+                    }
+                }
+            }
+            Err(err) => error!("Failed to fetch expired invites: {err:?}"),
+        }
+
+        task::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -8,18 +8,38 @@ use std::time::Instant;
 const WORKER_COUNT: usize = 5;
 
 pub mod ack;
+pub mod auto_roles;
+pub mod ban_expiry;
+pub mod channel_export;
+pub mod invite_expiry;
 pub mod last_message_id;
 pub mod process_embeds;
+pub mod role_jobs;
+pub mod server_events;
+pub mod suspension_expiry;
+pub mod timeout_expiry;
 pub mod web_push;
 
 /// Spawn background workers
 pub async fn start_workers(db: Database, authifier_db: authifier::Database) {
     for _ in 0..WORKER_COUNT {
         task::spawn(ack::worker(db.clone()));
+        task::spawn(channel_export::worker(db.clone()));
         task::spawn(last_message_id::worker(db.clone()));
         task::spawn(process_embeds::worker(db.clone()));
+        task::spawn(role_jobs::worker(db.clone()));
         task::spawn(web_push::worker(authifier_db.clone()));
     }
+
+    task::spawn(auto_roles::worker(db.clone()));
+    task::spawn(ban_expiry::worker(db.clone()));
+    task::spawn(channel_export::expiry_worker(db.clone()));
+    task::spawn(channel_export::recover(db.clone()));
+    task::spawn(invite_expiry::worker(db.clone()));
+    task::spawn(role_jobs::recover(db.clone()));
+    task::spawn(server_events::worker(db.clone()));
+    task::spawn(suspension_expiry::worker(db.clone()));
+    task::spawn(timeout_expiry::worker(db));
 }
 
 /// Task with additional information on when it should run
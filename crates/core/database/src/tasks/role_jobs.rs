@@ -0,0 +1,206 @@
+use crate::{
+    util::permissions::DatabasePermissionQuery, Database, Member, MemberQuery, PartialRoleJob,
+    RoleJob,
+};
+
+use deadqueue::limited::Queue;
+use iso8601_timestamp::Timestamp;
+use once_cell::sync::Lazy;
+use revolt_models::v0::{MemberSelection, RoleJobAction, RoleJobStatus};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+
+/// Number of members updated between progress reports
+const PAGE_SIZE: i64 = 100;
+
+static Q: Lazy<Queue<String>> = Lazy::new(|| Queue::new(1_000));
+
+/// Queue a new role job for a worker
+///
+/// Jobs which don't fit in the queue stay pending until the next startup picks them up.
+pub async fn queue(id: String) {
+    if Q.try_push(id.clone()).is_err() {
+        warn!("Role job queue is full, {id} will be picked up on the next startup.");
+    }
+
+    info!("Queue is using {} slots from {}.", Q.len(), Q.capacity());
+}
+
+/// Re-queue role jobs which were interrupted or never picked up before a restart
+pub async fn recover(db: Database) {
+    match db.fetch_unfinished_role_jobs(None).await {
+        Ok(jobs) => {
+            for job in jobs {
+                queue(job.id).await;
+            }
+        }
+        Err(err) => error!("Failed to fetch unfinished role jobs: {err:?}"),
+    }
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        let id = Q.pop().await;
+        let mut job = match db.fetch_role_job(&id).await {
+            Ok(job) => job,
+            Err(err) => {
+                error!("Failed to fetch role job {id}: {err:?}");
+                continue;
+            }
+        };
+
+        if let Err(err) = run(&db, &mut job).await {
+            error!("Encountered an error running role job: {err:?}");
+
+            job.update(
+                &db,
+                PartialRoleJob {
+                    status: Some(RoleJobStatus::Failed),
+                    ..Default::default()
+                },
+            )
+            .await
+            .ok();
+        }
+    }
+}
+
+/// Fetch the next page of selected members, ordered by user id
+///
+/// Also returns the cursor for the page after, if there might be one.
+async fn next_page(
+    db: &Database,
+    job: &RoleJob,
+    after: Option<String>,
+) -> Result<(Vec<Member>, Option<String>)> {
+    let (role, joined_after, joined_before) = match &job.selection {
+        MemberSelection::Members { ids } => {
+            let mut ids: Vec<String> = ids
+                .iter()
+                .filter(|id| after.as_ref().map_or(true, |after| *id > after))
+                .cloned()
+                .collect();
+
+            ids.sort();
+            ids.dedup();
+            ids.truncate(PAGE_SIZE as usize);
+
+            if ids.is_empty() {
+                return Ok((vec![], None));
+            }
+
+            // Page on the requested ids so that users who are not members are skipped over
+            let cursor = (ids.len() as i64 == PAGE_SIZE).then(|| ids[ids.len() - 1].clone());
+            return Ok((db.fetch_members(&job.server, &ids).await?, cursor));
+        }
+        MemberSelection::Role { id } => (Some(id.clone()), None, None),
+        MemberSelection::JoinDate { after, before } => (None, *after, *before),
+    };
+
+    let members = db
+        .fetch_members_by_query(MemberQuery {
+            server: job.server.clone(),
+            role,
+            joined_after,
+            joined_before,
+            after,
+            limit: PAGE_SIZE,
+        })
+        .await?;
+
+    let cursor = (members.len() as i64 == PAGE_SIZE)
+        .then(|| members.last().map(|member| member.id.user.clone()))
+        .flatten();
+
+    Ok((members, cursor))
+}
+
+/// Walk through the selected members, updating one page at a time
+///
+/// Interrupted jobs start over, members they already changed are skipped.
+async fn run(db: &Database, job: &mut RoleJob) -> Result<()> {
+    job.update(
+        db,
+        PartialRoleJob {
+            status: Some(RoleJobStatus::Running),
+            processed: Some(0),
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    let server = db.fetch_server(&job.server).await?;
+    let role = server
+        .roles
+        .get(&job.role)
+        .ok_or_else(|| create_error!(InvalidRole))?;
+
+    // The creator may have lost their permissions since the job was queued
+    let creator = db.fetch_user(&job.creator).await?;
+    let mut query = DatabasePermissionQuery::new(db, &creator).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::AssignRoles)?;
+
+    let our_ranking = server.get_member_ranking(db, &job.creator).await?;
+    if role.rank <= our_ranking {
+        return Err(create_error!(NotElevated));
+    }
+
+    let mut after = None;
+
+    loop {
+        let (members, cursor) = next_page(db, job, after).await?;
+        let count = members.len();
+        let mut affected = 0;
+
+        for mut member in members {
+            let ranking = if member.id.user == server.owner {
+                i64::MIN
+            } else {
+                member.get_ranking(&server)
+            };
+
+            // Members ranked at or above the creator are left alone
+            if member.id.user != job.creator && ranking <= our_ranking {
+                continue;
+            }
+
+            let has_role = member.roles.contains(&job.role);
+            match job.action {
+                RoleJobAction::Add if !has_role => member.add_role(db, &job.role).await?,
+                RoleJobAction::Remove if has_role => member.remove_role(db, &job.role).await?,
+                _ => continue,
+            }
+
+            affected += 1;
+        }
+
+        job.update(
+            db,
+            PartialRoleJob {
+                processed: Some(job.processed + count as u32),
+                affected: Some(job.affected + affected),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        if cursor.is_none() {
+            break;
+        }
+
+        after = cursor;
+    }
+
+    job.update(
+        db,
+        PartialRoleJob {
+            status: Some(RoleJobStatus::Completed),
+            completed_at: Some(Timestamp::now_utc()),
+            ..Default::default()
+        },
+    )
+    .await
+}
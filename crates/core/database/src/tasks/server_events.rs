@@ -0,0 +1,117 @@
+use async_std::task;
+use iso8601_timestamp::Timestamp;
+use revolt_config::config;
+use revolt_models::v0::{PushNotification, ServerEventStatus};
+use revolt_result::ErrorType;
+use std::time::{Duration, SystemTime};
+
+use crate::{tasks::web_push, Database, PartialServerEvent, Server, ServerEvent};
+
+/// How often to look for events which are starting or ending
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long before an event starts to remind attendees
+const REMINDER_LEAD: Duration = Duration::from_secs(15 * 60);
+
+/// Send a push notification about an event to everyone attending it
+async fn remind(server: &Server, event: &ServerEvent, body: String) {
+    let config = config().await;
+
+    let icon = if let Some(icon) = &server.icon {
+        format!("{}/icons/{}", config.hosts.autumn, icon.id)
+    } else {
+        format!("{}/assets/logo.png", config.hosts.app)
+    };
+
+    let timestamp = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .expect("Time went backwards")
+        .as_secs();
+
+    web_push::queue(
+        event.attendees.clone(),
+        PushNotification {
+            author: server.name.clone(),
+            icon,
+            image: None,
+            body,
+            tag: event.id.clone(),
+            timestamp,
+            url: format!("{}/server/{}", config.hosts.app, server.id),
+        },
+    )
+    .await;
+}
+
+/// Move an event along its lifecycle
+async fn advance(db: &Database, mut event: ServerEvent) {
+    let server = match db.fetch_server(&event.server).await {
+        Ok(server) => server,
+        Err(err) if matches!(err.error_type, ErrorType::NotFound) => {
+            // Server no longer exists
+            event.delete(db).await.ok();
+            return;
+        }
+        Err(err) => {
+            error!("Failed to fetch server for event {}: {err:?}", event.id);
+            return;
+        }
+    };
+
+    let now = Timestamp::now_utc();
+    let mut partial = PartialServerEvent::default();
+
+    match event.status {
+        ServerEventStatus::Scheduled => {
+            let started = *event.starts_at <= *now;
+
+            if !event.reminded {
+                let body = if started {
+                    format!("{} is starting now.", event.name)
+                } else {
+                    format!("{} is starting soon.", event.name)
+                };
+
+                remind(&server, &event, body).await;
+                partial.reminded = Some(true);
+            }
+
+            if started {
+                partial.status = Some(ServerEventStatus::Active);
+            }
+        }
+        ServerEventStatus::Active => partial.status = Some(ServerEventStatus::Completed),
+        ServerEventStatus::Completed => return,
+    }
+
+    // Clients only need to hear about changes in status
+    let result = if partial.status.is_some() {
+        event.update(db, partial, vec![]).await
+    } else if partial.reminded.is_some() {
+        db.update_server_event(&event.id, &partial, &[]).await
+    } else {
+        return;
+    };
+
+    if let Err(err) = result {
+        error!("Failed to advance server event {}: {err:?}", event.id);
+    }
+}
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        let until = Timestamp::from(SystemTime::now() + REMINDER_LEAD);
+
+        match db.fetch_due_server_events(until).await {
+            Ok(events) => {
+                for event in events {
+                    advance(&db, event).await;
+                }
+            }
+            Err(err) => error!("Failed to fetch due server events: {err:?}"),
+        }
+
+        task::sleep(POLL_INTERVAL).await;
+    }
+}
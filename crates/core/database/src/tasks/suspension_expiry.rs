@@ -0,0 +1,28 @@
+use async_std::task;
+use std::time::Duration;
+
+use crate::Database;
+
+/// How often to look for suspensions which have run their course
+const POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match db.fetch_expired_strikes().await {
+            Ok(strikes) => {
+                for mut strike in strikes {
+                    if let Err(err) = strike.lift(&db).await {
+                        error!("Failed to lift expired suspension {}: {err:?}", strike.id);
+                        continue;
+                    }
+
+                    info!("Lifted expired suspension of {}.", strike.user);
+                }
+            }
+            Err(err) => error!("Failed to fetch expired suspensions: {err:?}"),
+        }
+
+        task::sleep(POLL_INTERVAL).await;
+    }
+}
@@ -0,0 +1,29 @@
+use async_std::task;
+use std::time::Duration;
+
+use crate::{Database, FieldsMember, PartialMember};
+
+/// How often to look for timeouts which have elapsed
+const POLL_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Start a new worker
+pub async fn worker(db: Database) {
+    loop {
+        match db.fetch_expired_timeouts().await {
+            Ok(members) => {
+                for mut member in members {
+                    // Clearing the field publishes a `ServerMemberUpdate`
+                    if let Err(err) = member
+                        .update(&db, PartialMember::default(), vec![FieldsMember::Timeout])
+                        .await
+                    {
+                        error!("Failed to clear elapsed timeout {:?}: {err:?}", member.id);
+                    }
+                }
+            }
+            Err(err) => error!("Failed to fetch elapsed timeouts: {err:?}"),
+        }
+
+        task::sleep(POLL_INTERVAL).await;
+    }
+}
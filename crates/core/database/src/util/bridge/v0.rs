@@ -62,26 +62,67 @@ impl From<crate::Invite> for Invite {
                 code,
                 creator,
                 channel,
+                expires_at,
+                max_uses,
+                uses,
             } => Invite::Group {
                 code,
                 creator,
                 channel,
+                expires_at,
+                max_uses,
+                uses,
             },
             crate::Invite::Server {
                 code,
                 server,
                 creator,
                 channel,
+                expires_at,
+                max_uses,
+                uses,
+                temporary,
+                roles,
+                vanity,
             } => Invite::Server {
                 code,
                 server,
                 creator,
                 channel,
+                expires_at,
+                max_uses,
+                uses,
+                temporary,
+                roles,
+                vanity,
             },
         }
     }
 }
 
+impl From<crate::InviteUse> for InviteUse {
+    fn from(value: crate::InviteUse) -> Self {
+        InviteUse {
+            user: value.user,
+            used_at: value.used_at,
+        }
+    }
+}
+
+impl From<crate::ChannelExport> for ChannelExport {
+    fn from(value: crate::ChannelExport) -> Self {
+        ChannelExport {
+            id: value.id,
+            channel: value.channel,
+            user: value.user,
+            format: value.format,
+            status: value.status,
+            processed: value.processed,
+            completed_at: value.completed_at,
+        }
+    }
+}
+
 impl From<crate::ChannelUnread> for ChannelUnread {
     fn from(value: crate::ChannelUnread) -> Self {
         ChannelUnread {
@@ -139,6 +180,19 @@ impl From<crate::FieldsWebhook> for FieldsWebhook {
     }
 }
 
+impl From<crate::ChannelFollower> for ChannelFollower {
+    fn from(value: crate::ChannelFollower) -> Self {
+        ChannelFollower {
+            id: value.id,
+            channel: value.channel,
+            target: value.target,
+            target_server: value.target_server,
+            webhook: value.webhook,
+            creator: value.creator,
+        }
+    }
+}
+
 impl From<crate::Channel> for Channel {
     fn from(value: crate::Channel) -> Self {
         match value {
@@ -148,11 +202,13 @@ impl From<crate::Channel> for Channel {
                 active,
                 recipients,
                 last_message_id,
+                requested_by,
             } => Channel::DirectMessage {
                 id,
                 active,
                 recipients,
                 last_message_id,
+                requested_by,
             },
             crate::Channel::Group {
                 id,
@@ -185,6 +241,8 @@ impl From<crate::Channel> for Channel {
                 default_permissions,
                 role_permissions,
                 nsfw,
+                slowmode_seconds,
+                announcement,
             } => Channel::TextChannel {
                 id,
                 server,
@@ -195,6 +253,8 @@ impl From<crate::Channel> for Channel {
                 default_permissions,
                 role_permissions,
                 nsfw,
+                slowmode_seconds,
+                announcement,
             },
             crate::Channel::VoiceChannel {
                 id,
@@ -215,6 +275,46 @@ impl From<crate::Channel> for Channel {
                 role_permissions,
                 nsfw,
             },
+            crate::Channel::ForumChannel {
+                id,
+                server,
+                name,
+                description,
+                icon,
+                available_tags,
+                default_sort_order,
+                default_permissions,
+                role_permissions,
+                nsfw,
+            } => Channel::ForumChannel {
+                id,
+                server,
+                name,
+                description,
+                icon: icon.map(|file| file.into()),
+                available_tags,
+                default_sort_order,
+                default_permissions,
+                role_permissions,
+                nsfw,
+            },
+            crate::Channel::ForumPost {
+                id,
+                server,
+                forum,
+                author,
+                name,
+                tags,
+                last_message_id,
+            } => Channel::ForumPost {
+                id,
+                server,
+                forum,
+                author,
+                name,
+                tags,
+                last_message_id,
+            },
         }
     }
 }
@@ -232,6 +332,11 @@ impl From<crate::PartialChannel> for PartialChannel {
             role_permissions: value.role_permissions,
             default_permissions: value.default_permissions,
             last_message_id: value.last_message_id,
+            slowmode_seconds: value.slowmode_seconds,
+            announcement: value.announcement,
+            available_tags: value.available_tags,
+            default_sort_order: value.default_sort_order,
+            tags: value.tags,
         }
     }
 }
@@ -242,6 +347,7 @@ impl From<FieldsChannel> for crate::FieldsChannel {
             FieldsChannel::Description => crate::FieldsChannel::Description,
             FieldsChannel::Icon => crate::FieldsChannel::Icon,
             FieldsChannel::DefaultPermissions => crate::FieldsChannel::DefaultPermissions,
+            FieldsChannel::RequestedBy => crate::FieldsChannel::RequestedBy,
         }
     }
 }
@@ -252,6 +358,7 @@ impl From<crate::FieldsChannel> for FieldsChannel {
             crate::FieldsChannel::Description => FieldsChannel::Description,
             crate::FieldsChannel::Icon => FieldsChannel::Icon,
             crate::FieldsChannel::DefaultPermissions => FieldsChannel::DefaultPermissions,
+            crate::FieldsChannel::RequestedBy => FieldsChannel::RequestedBy,
         }
     }
 }
@@ -358,6 +465,7 @@ impl From<crate::Message> for Message {
             edited: value.edited,
             embeds: value.embeds,
             mentions: value.mentions,
+            role_mentions: value.role_mentions,
             replies: value.replies,
             reactions: value
                 .reactions
@@ -371,6 +479,10 @@ impl From<crate::Message> for Message {
                 .map(|component| component.into_iter().map(|x| x.into()).collect()),
             session_id: value.session_id,
             is_stream: value.is_stream,
+            ephemeral: value.ephemeral,
+            forwarded: value.forwarded,
+            pinned: value.pinned,
+            published: value.published,
         }
     }
 }
@@ -391,6 +503,7 @@ impl From<crate::PartialMessage> for PartialMessage {
             edited: value.edited,
             embeds: value.embeds,
             mentions: value.mentions,
+            role_mentions: value.role_mentions,
             replies: value.replies,
             reactions: value.reactions.map(|reactions| {
                 reactions
@@ -405,6 +518,10 @@ impl From<crate::PartialMessage> for PartialMessage {
                 .map(|component| component.into_iter().map(|x| x.into()).collect()),
             session_id: value.session_id,
             is_stream: value.is_stream,
+            ephemeral: value.ephemeral,
+            forwarded: value.forwarded,
+            pinned: value.pinned,
+            published: value.published,
         }
     }
 }
@@ -481,11 +598,220 @@ impl From<Masquerade> for crate::Masquerade {
     }
 }
 
+impl From<crate::AccountStrike> for AccountStrike {
+    fn from(value: crate::AccountStrike) -> Self {
+        AccountStrike {
+            id: value.id,
+            user: value.user,
+            moderator: value.moderator,
+            reason: value.reason,
+            action: value.action,
+            expires: value.expires,
+            active: value.active,
+            appeal: value.appeal,
+        }
+    }
+}
+
+impl From<crate::AuditLogEntry> for AuditLogEntry {
+    fn from(value: crate::AuditLogEntry) -> Self {
+        AuditLogEntry {
+            id: value.id,
+            server: value.server,
+            actor: value.actor,
+            action: value.action,
+            target: value.target,
+            reason: value.reason,
+            changes: value.changes,
+        }
+    }
+}
+
+impl From<crate::AutomodRule> for AutomodRule {
+    fn from(value: crate::AutomodRule) -> Self {
+        AutomodRule {
+            id: value.id,
+            server: value.server,
+            name: value.name,
+            enabled: value.enabled,
+            trigger: value.trigger,
+            actions: value.actions,
+            exempt_roles: value.exempt_roles,
+            exempt_channels: value.exempt_channels,
+        }
+    }
+}
+
+impl From<crate::AutoRoleRule> for AutoRoleRule {
+    fn from(value: crate::AutoRoleRule) -> Self {
+        AutoRoleRule {
+            id: value.id,
+            server: value.server,
+            creator: value.creator,
+            role: value.role,
+            trigger: value.trigger,
+        }
+    }
+}
+
+impl From<crate::RoleJob> for RoleJob {
+    fn from(value: crate::RoleJob) -> Self {
+        RoleJob {
+            id: value.id,
+            server: value.server,
+            creator: value.creator,
+            role: value.role,
+            action: value.action,
+            selection: value.selection,
+            status: value.status,
+            processed: value.processed,
+            affected: value.affected,
+            completed_at: value.completed_at,
+        }
+    }
+}
+
 impl From<crate::ServerBan> for ServerBan {
     fn from(value: crate::ServerBan) -> Self {
         ServerBan {
             id: value.id.into(),
             reason: value.reason,
+            expires: value.expires,
+        }
+    }
+}
+
+impl From<crate::RaidProtection> for RaidProtection {
+    fn from(value: crate::RaidProtection) -> Self {
+        RaidProtection {
+            id: value.id,
+            join_threshold: value.join_threshold,
+            join_window: value.join_window,
+            lockdown_duration: value.lockdown_duration,
+            min_account_age: value.min_account_age,
+            require_verified_email: value.require_verified_email,
+            alert_channel: value.alert_channel,
+            lockdown: value.lockdown,
+        }
+    }
+}
+
+impl From<crate::ContentFilter> for ContentFilter {
+    fn from(value: crate::ContentFilter) -> Self {
+        ContentFilter {
+            id: value.id,
+            words: value.words,
+            hide_stranger_attachments: value.hide_stranger_attachments,
+            direct_messages: value.direct_messages,
+        }
+    }
+}
+
+impl From<crate::ServerScreening> for ServerScreening {
+    fn from(value: crate::ServerScreening) -> Self {
+        ServerScreening {
+            id: value.id,
+            enabled: value.enabled,
+            rules: value.rules,
+            questions: value.questions,
+            require_approval: value.require_approval,
+        }
+    }
+}
+
+impl From<crate::ServerTemplate> for ServerTemplate {
+    fn from(value: crate::ServerTemplate) -> Self {
+        ServerTemplate {
+            code: value.code,
+            server: value.server,
+            creator: value.creator,
+            name: value.name,
+            description: value.description,
+            created_at: value.created_at,
+            snapshot: value.snapshot.into(),
+        }
+    }
+}
+
+impl From<crate::ServerTemplateSnapshot> for ServerTemplateSnapshot {
+    fn from(value: crate::ServerTemplateSnapshot) -> Self {
+        ServerTemplateSnapshot {
+            channels: value.channels.into_iter().map(|c| c.into()).collect(),
+            categories: value.categories.into_iter().map(|c| c.into()).collect(),
+            system_messages: value.system_messages.map(|v| v.into()),
+            roles: value
+                .roles
+                .into_iter()
+                .map(|(k, v)| (k, v.into()))
+                .collect(),
+            default_permissions: value.default_permissions,
+        }
+    }
+}
+
+impl From<crate::TemplateChannel> for TemplateChannel {
+    fn from(value: crate::TemplateChannel) -> Self {
+        TemplateChannel {
+            id: value.id,
+            channel_type: value.channel_type,
+            name: value.name,
+            description: value.description,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
+            nsfw: value.nsfw,
+            slowmode_seconds: value.slowmode_seconds,
+        }
+    }
+}
+
+impl From<crate::ServerEvent> for ServerEvent {
+    fn from(value: crate::ServerEvent) -> Self {
+        ServerEvent {
+            id: value.id,
+            server: value.server,
+            creator: value.creator,
+            name: value.name,
+            description: value.description,
+            starts_at: value.starts_at,
+            ends_at: value.ends_at,
+            location: value.location,
+            status: value.status,
+            attendees: value.attendees,
+        }
+    }
+}
+
+impl From<crate::PartialServerEvent> for PartialServerEvent {
+    fn from(value: crate::PartialServerEvent) -> Self {
+        PartialServerEvent {
+            id: value.id,
+            server: value.server,
+            creator: value.creator,
+            name: value.name,
+            description: value.description,
+            starts_at: value.starts_at,
+            ends_at: value.ends_at,
+            location: value.location,
+            status: value.status,
+            attendees: value.attendees,
+        }
+    }
+}
+
+impl From<FieldsServerEvent> for crate::FieldsServerEvent {
+    fn from(value: FieldsServerEvent) -> Self {
+        match value {
+            FieldsServerEvent::Description => crate::FieldsServerEvent::Description,
+            FieldsServerEvent::EndsAt => crate::FieldsServerEvent::EndsAt,
+        }
+    }
+}
+
+impl From<crate::FieldsServerEvent> for FieldsServerEvent {
+    fn from(value: crate::FieldsServerEvent) -> Self {
+        match value {
+            crate::FieldsServerEvent::Description => FieldsServerEvent::Description,
+            crate::FieldsServerEvent::EndsAt => FieldsServerEvent::EndsAt,
         }
     }
 }
@@ -499,6 +825,8 @@ impl From<crate::Member> for Member {
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
             timeout: value.timeout,
+            pending: value.pending.map(|pending| pending.into()),
+            temporary: value.temporary,
         }
     }
 }
@@ -512,6 +840,16 @@ impl From<crate::PartialMember> for PartialMember {
             avatar: value.avatar.map(|f| f.into()),
             roles: value.roles,
             timeout: value.timeout,
+            pending: value.pending.map(|pending| pending.into()),
+            temporary: value.temporary,
+        }
+    }
+}
+
+impl From<crate::MemberScreening> for MemberScreening {
+    fn from(value: crate::MemberScreening) -> Self {
+        MemberScreening {
+            submitted_at: value.submitted_at,
         }
     }
 }
@@ -532,6 +870,7 @@ impl From<crate::FieldsMember> for FieldsMember {
             crate::FieldsMember::Nickname => FieldsMember::Nickname,
             crate::FieldsMember::Roles => FieldsMember::Roles,
             crate::FieldsMember::Timeout => FieldsMember::Timeout,
+            crate::FieldsMember::Pending => FieldsMember::Pending,
         }
     }
 }
@@ -570,6 +909,7 @@ impl From<crate::Server> for Server {
             nsfw: value.nsfw,
             analytics: value.analytics,
             discoverable: value.discoverable,
+            pending_owner: value.pending_owner,
         }
     }
 }
@@ -596,6 +936,7 @@ impl From<crate::PartialServer> for PartialServer {
             nsfw: value.nsfw,
             analytics: value.analytics,
             discoverable: value.discoverable,
+            pending_owner: value.pending_owner,
         }
     }
 }
@@ -608,6 +949,7 @@ impl From<crate::FieldsServer> for FieldsServer {
             crate::FieldsServer::Description => FieldsServer::Description,
             crate::FieldsServer::Icon => FieldsServer::Icon,
             crate::FieldsServer::SystemMessages => FieldsServer::SystemMessages,
+            crate::FieldsServer::PendingOwner => FieldsServer::PendingOwner,
         }
     }
 }
@@ -618,6 +960,9 @@ impl From<crate::Category> for Category {
             id: value.id,
             title: value.title,
             channels: value.channels,
+            default_permissions: value.default_permissions,
+            role_permissions: value.role_permissions,
+            synced: value.synced,
         }
     }
 }
@@ -639,7 +984,10 @@ impl From<crate::Role> for Role {
             name: value.name,
             permissions: value.permissions,
             colour: value.colour,
+            icon: value.icon.map(|file| file.into()),
+            emoji: value.emoji,
             hoist: value.hoist,
+            mentionable: value.mentionable,
             rank: value.rank,
         }
     }
@@ -651,7 +999,10 @@ impl From<crate::PartialRole> for PartialRole {
             name: value.name,
             permissions: value.permissions,
             colour: value.colour,
+            icon: value.icon.map(|file| file.into()),
+            emoji: value.emoji,
             hoist: value.hoist,
+            mentionable: value.mentionable,
             rank: value.rank,
         }
     }
@@ -661,6 +1012,8 @@ impl From<crate::FieldsRole> for FieldsRole {
     fn from(value: crate::FieldsRole) -> Self {
         match value {
             crate::FieldsRole::Colour => FieldsRole::Colour,
+            crate::FieldsRole::Icon => FieldsRole::Icon,
+            crate::FieldsRole::Emoji => FieldsRole::Emoji,
         }
     }
 }
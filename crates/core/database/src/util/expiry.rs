@@ -0,0 +1,30 @@
+use std::time::{Duration, SystemTime};
+
+use iso8601_timestamp::Timestamp;
+use revolt_result::{create_error, Result};
+
+/// Work out when something lasting the given number of seconds from now expires
+///
+/// Fails if the resulting time can't be represented.
+pub fn expires_in(seconds: u64) -> Result<Timestamp> {
+    SystemTime::now()
+        .checked_add(Duration::from_secs(seconds))
+        .map(Timestamp::from)
+        .ok_or_else(|| create_error!(InvalidProperty))
+}
+
+#[cfg(test)]
+mod tests {
+    use revolt_result::ErrorType;
+
+    use super::expires_in;
+
+    #[test]
+    fn reject_overflowing_duration() {
+        assert!(expires_in(3600).is_ok());
+        assert_eq!(
+            expires_in(u64::MAX).unwrap_err().error_type,
+            ErrorType::InvalidProperty
+        );
+    }
+}
@@ -1,4 +1,6 @@
+pub mod audit_log_reason;
 pub mod bridge;
+pub mod expiry;
 pub mod idempotency;
 pub mod permissions;
 pub mod reference;
@@ -166,10 +166,31 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
         }
     }
 
+    /// Has our perspective user yet to pass membership screening on this server?
+    async fn are_we_pending_screening(&mut self) -> bool {
+        if let Some(member) = &self.member {
+            member.is_pending()
+        } else {
+            false
+        }
+    }
+
     // * For calculating channel permission
 
     /// Get the type of the channel
     async fn get_channel_type(&mut self) -> ChannelType {
+        // Forum posts inherit all permissions from their forum
+        if let Some(
+            Cow::Borrowed(Channel::ForumPost { forum, .. })
+            | Cow::Owned(Channel::ForumPost { forum, .. }),
+        ) = &self.channel
+        {
+            let forum = forum.clone();
+            if let Ok(forum) = self.database.fetch_channel(&forum).await {
+                self.channel.replace(Cow::Owned(forum));
+            }
+        }
+
         if let Some(channel) = &self.channel {
             match channel {
                 Cow::Borrowed(Channel::DirectMessage { .. })
@@ -182,7 +203,11 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
                 Cow::Borrowed(Channel::TextChannel { .. })
                 | Cow::Owned(Channel::TextChannel { .. })
                 | Cow::Borrowed(Channel::VoiceChannel { .. })
-                | Cow::Owned(Channel::VoiceChannel { .. }) => ChannelType::ServerChannel,
+                | Cow::Owned(Channel::VoiceChannel { .. })
+                | Cow::Borrowed(Channel::ForumChannel { .. })
+                | Cow::Owned(Channel::ForumChannel { .. })
+                | Cow::Borrowed(Channel::ForumPost { .. })
+                | Cow::Owned(Channel::ForumPost { .. }) => ChannelType::ServerChannel,
             }
         } else {
             ChannelType::Unknown
@@ -200,21 +225,43 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
                     deny: 0,
                 },
                 Cow::Borrowed(Channel::TextChannel {
+                    id,
                     default_permissions,
                     ..
                 })
                 | Cow::Owned(Channel::TextChannel {
+                    id,
                     default_permissions,
                     ..
                 })
                 | Cow::Borrowed(Channel::VoiceChannel {
+                    id,
                     default_permissions,
                     ..
                 })
                 | Cow::Owned(Channel::VoiceChannel {
+                    id,
+                    default_permissions,
+                    ..
+                })
+                | Cow::Borrowed(Channel::ForumChannel {
+                    id,
                     default_permissions,
                     ..
-                }) => default_permissions.unwrap_or_default().into(),
+                })
+                | Cow::Owned(Channel::ForumChannel {
+                    id,
+                    default_permissions,
+                    ..
+                }) => self
+                    .server
+                    .as_ref()
+                    .and_then(|server| server.synced_category(id))
+                    .map_or(*default_permissions, |category| {
+                        category.default_permissions
+                    })
+                    .unwrap_or_default()
+                    .into(),
                 _ => Default::default(),
             }
         } else {
@@ -227,18 +274,40 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
         if let Some(channel) = &self.channel {
             match channel {
                 Cow::Borrowed(Channel::TextChannel {
-                    role_permissions, ..
+                    id,
+                    role_permissions,
+                    ..
                 })
                 | Cow::Owned(Channel::TextChannel {
-                    role_permissions, ..
+                    id,
+                    role_permissions,
+                    ..
                 })
                 | Cow::Borrowed(Channel::VoiceChannel {
-                    role_permissions, ..
+                    id,
+                    role_permissions,
+                    ..
                 })
                 | Cow::Owned(Channel::VoiceChannel {
-                    role_permissions, ..
+                    id,
+                    role_permissions,
+                    ..
+                })
+                | Cow::Borrowed(Channel::ForumChannel {
+                    id,
+                    role_permissions,
+                    ..
+                })
+                | Cow::Owned(Channel::ForumChannel {
+                    id,
+                    role_permissions,
+                    ..
                 }) => {
                     if let Some(server) = &self.server {
+                        let role_permissions = server
+                            .synced_category(id)
+                            .map_or(role_permissions, |category| &category.role_permissions);
+
                         let member_roles = self
                             .member
                             .as_ref()
@@ -330,7 +399,11 @@ impl PermissionQuery for DatabasePermissionQuery<'_> {
                 Cow::Borrowed(Channel::TextChannel { server, .. })
                 | Cow::Owned(Channel::TextChannel { server, .. })
                 | Cow::Borrowed(Channel::VoiceChannel { server, .. })
-                | Cow::Owned(Channel::VoiceChannel { server, .. }) => {
+                | Cow::Owned(Channel::VoiceChannel { server, .. })
+                | Cow::Borrowed(Channel::ForumChannel { server, .. })
+                | Cow::Owned(Channel::ForumChannel { server, .. })
+                | Cow::Borrowed(Channel::ForumPost { server, .. })
+                | Cow::Owned(Channel::ForumPost { server, .. }) => {
                     if let Ok(server) = self.database.fetch_server(server).await {
                         self.server.replace(Cow::Owned(server));
                     }
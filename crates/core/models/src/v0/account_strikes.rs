@@ -0,0 +1,101 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Account Strike
+    pub struct AccountStrike {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the user this strike was issued to
+        pub user: String,
+        /// Id of the moderator who issued this strike
+        pub moderator: String,
+        /// Reason given to the user
+        pub reason: String,
+        /// Action taken against the account
+        pub action: StrikeAction,
+        /// Time at which the suspension is lifted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub expires: Option<Timestamp>,
+        /// Whether the action is still in effect
+        pub active: bool,
+        /// Appeal submitted by the user
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub appeal: Option<StrikeAppeal>,
+    }
+
+    /// Action taken against an account alongside a strike
+    pub enum StrikeAction {
+        /// Recorded against the account with no further effect
+        Warning,
+        /// Account is locked out until the strike expires or is lifted
+        Suspension,
+        /// Account is locked out permanently
+        Ban,
+    }
+
+    /// Appeal against a strike
+    pub struct StrikeAppeal {
+        /// Explanation provided by the user
+        pub content: String,
+        /// Current state of the appeal
+        pub status: AppealStatus,
+        /// Response from the moderation team
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub response: Option<String>,
+    }
+
+    /// State of an appeal
+    pub enum AppealStatus {
+        /// Waiting for review
+        Pending,
+        /// Strike was lifted
+        Accepted,
+        /// Strike stands
+        Rejected,
+    }
+
+    /// Standing of an account on the platform
+    pub struct AccountStanding {
+        /// Whether the account is currently suspended
+        pub suspended: bool,
+        /// Time at which the current suspension is lifted, if it is not indefinite
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub suspended_until: Option<Timestamp>,
+        /// Whether the account is banned
+        pub banned: bool,
+        /// Strikes issued to this account
+        pub strikes: Vec<AccountStrike>,
+    }
+
+    /// New strike information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateStrike {
+        /// Reason given to the user
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 1000)))]
+        pub reason: String,
+        /// Action to take against the account
+        pub action: StrikeAction,
+        /// Duration of a suspension in seconds, indefinite if not present
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 31536000)))]
+        pub duration: Option<u64>,
+    }
+
+    /// Appeal information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataAppealStrike {
+        /// Explanation of why the strike should be lifted
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 2000)))]
+        pub content: String,
+    }
+
+    /// Decision on an appeal
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataRespondAppeal {
+        /// Whether to lift the strike
+        pub accept: bool,
+        /// Response shown to the user
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 1000)))]
+        pub response: Option<String>,
+    }
+);
@@ -0,0 +1,51 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Channel Export
+    pub struct ChannelExport {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the channel being exported
+        pub channel: String,
+        /// Id of the user who requested this export
+        pub user: String,
+        /// Format the archive is produced in
+        pub format: ExportFormat,
+        /// Current state of the export
+        pub status: ExportStatus,
+        /// Number of messages processed so far
+        pub processed: u32,
+        /// Time at which the export finished
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub completed_at: Option<Timestamp>,
+    }
+
+    /// Format of a channel export
+    pub enum ExportFormat {
+        /// Machine-readable JSON document
+        Json,
+        /// Self-contained HTML page
+        Html,
+        /// Plain text transcript
+        PlainText,
+    }
+
+    /// State of a channel export
+    pub enum ExportStatus {
+        /// Waiting to be picked up by a worker
+        Pending,
+        /// Messages are currently being collected
+        Running,
+        /// Archive is ready to download
+        Completed,
+        /// Export could not be completed
+        Failed,
+    }
+
+    /// Create a new channel export
+    pub struct DataCreateExport {
+        /// Format to produce the archive in
+        pub format: ExportFormat,
+    }
+);
@@ -0,0 +1,24 @@
+auto_derived!(
+    /// Subscription delivering messages published in an announcement channel to another channel
+    pub struct ChannelFollower {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the announcement channel being followed
+        pub channel: String,
+        /// Id of the channel receiving published messages
+        pub target: String,
+        /// Id of the server the receiving channel belongs to
+        pub target_server: String,
+        /// Id of the webhook used to deliver messages
+        pub webhook: String,
+        /// Id of the user who followed the channel
+        pub creator: String,
+    }
+
+    /// Follow an announcement channel
+    pub struct DataFollowChannel {
+        /// Id of the channel published messages should be delivered to
+        pub channel: String,
+    }
+);
@@ -1,3 +1,12 @@
+use iso8601_timestamp::Timestamp;
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Regex for valid vanity invite codes
+///
+/// Lowercase alphanumeric and hyphens
+pub static RE_VANITY: Lazy<Regex> = Lazy::new(|| Regex::new(r"^[a-z0-9-]+$").unwrap());
+
 auto_derived!(
     /// Invite
     pub enum Invite {
@@ -12,6 +21,37 @@ auto_derived!(
             creator: String,
             /// Id of the server channel this invite points to
             channel: String,
+            /// Time at which this invite stops working
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            expires_at: Option<Timestamp>,
+            /// Maximum number of times this invite may be used
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            max_uses: Option<u32>,
+            /// Number of times this invite has been used
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_zero_u32", default)
+            )]
+            uses: u32,
+            /// Whether members who join through this invite are removed
+            /// once they go offline, unless they have since been given a role
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            temporary: bool,
+            /// Roles given to members who join through this invite
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            roles: Vec<String>,
+            /// Whether this is the server's custom vanity invite
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            vanity: bool,
         },
         /// Invite to a group channel
         Group {
@@ -22,6 +62,64 @@ auto_derived!(
             creator: String,
             /// Id of the group channel this invite points to
             channel: String,
+            /// Time at which this invite stops working
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            expires_at: Option<Timestamp>,
+            /// Maximum number of times this invite may be used
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            max_uses: Option<u32>,
+            /// Number of times this invite has been used
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_zero_u32", default)
+            )]
+            uses: u32,
         },
     }
+
+    /// Record of a user joining through an invite
+    pub struct InviteUse {
+        /// Id of the user who used the invite
+        pub user: String,
+        /// Time at which the invite was used
+        pub used_at: Timestamp,
+    }
+
+    /// Options for a new invite
+    #[derive(Default)]
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateInvite {
+        /// Number of seconds after which the invite stops working
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 2592000)))]
+        pub max_age: Option<u64>,
+        /// Maximum number of times the invite may be used
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 1000)))]
+        pub max_uses: Option<u32>,
+        /// Whether members who join are removed once they go offline,
+        /// unless they have since been given a role
+        ///
+        /// Only applies to server invites.
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub temporary: bool,
+        /// Roles given to members who join through the invite
+        ///
+        /// Only applies to server invites.
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "validator", validate(length(max = 10)))]
+        pub roles: Vec<String>,
+    }
+
+    /// Vanity invite code to claim for a server
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditVanity {
+        /// Custom invite code
+        #[cfg_attr(
+            feature = "validator",
+            validate(length(min = 3, max = 32), regex = "RE_VANITY")
+        )]
+        pub code: String,
+        /// Id of the channel the invite points to,
+        /// defaults to the first channel of the server
+        pub channel: Option<String>,
+    }
 );
@@ -1,4 +1,4 @@
-use super::File;
+use super::{DataMessageSend, File, Message};
 
 use revolt_permissions::OverrideField;
 use std::collections::{HashMap, HashSet};
@@ -28,6 +28,9 @@ auto_derived!(
             /// Id of the last message sent in this channel
             #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
             last_message_id: Option<String>,
+            /// Id of the user who started this conversation, present while it is a message request
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            requested_by: Option<String>,
         },
         /// Group channel between 1 or more participants
         Group {
@@ -104,6 +107,20 @@ auto_derived!(
                 serde(skip_serializing_if = "crate::if_false", default)
             )]
             nsfw: bool,
+
+            /// Seconds a member has to wait between sending messages
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_zero_u32", default)
+            )]
+            slowmode_seconds: u32,
+
+            /// Whether messages in this channel can be published to followers
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            announcement: bool,
         },
         /// Voice channel belonging to a server
         VoiceChannel {
@@ -142,6 +159,100 @@ auto_derived!(
             )]
             nsfw: bool,
         },
+        /// Forum channel belonging to a server
+        ForumChannel {
+            /// Unique Id
+            #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+            id: String,
+            /// Id of the server this channel belongs to
+            server: String,
+
+            /// Display name of the channel
+            name: String,
+            /// Channel description
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            description: Option<String>,
+            /// Custom icon attachment
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            icon: Option<File>,
+
+            /// Tags which can be applied to posts
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            available_tags: Vec<ForumTag>,
+            /// Order posts are listed in by default
+            #[cfg_attr(feature = "serde", serde(default))]
+            default_sort_order: ForumSortOrder,
+
+            /// Default permissions assigned to users in this channel
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            default_permissions: Option<OverrideField>,
+            /// Permissions assigned based on role to this channel
+            #[cfg_attr(
+                feature = "serde",
+                serde(
+                    default = "HashMap::<String, OverrideField>::new",
+                    skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+                )
+            )]
+            role_permissions: HashMap<String, OverrideField>,
+
+            /// Whether this channel is marked as not safe for work
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "crate::if_false", default)
+            )]
+            nsfw: bool,
+        },
+        /// Post within a forum channel
+        ///
+        /// Permissions are inherited from the forum.
+        ForumPost {
+            /// Unique Id
+            #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+            id: String,
+            /// Id of the server this post belongs to
+            server: String,
+            /// Id of the forum channel this post belongs to
+            forum: String,
+            /// Id of the user who created this post
+            author: String,
+
+            /// Title of the post
+            name: String,
+            /// Ids of the forum tags applied to this post
+            #[cfg_attr(
+                feature = "serde",
+                serde(skip_serializing_if = "Vec::is_empty", default)
+            )]
+            tags: Vec<String>,
+            /// Id of the last message sent in this post
+            #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+            last_message_id: Option<String>,
+        },
+    }
+
+    /// Tag which can be applied to posts in a forum channel
+    pub struct ForumTag {
+        /// Unique Id
+        pub id: String,
+        /// Tag name
+        pub name: String,
+        /// Emoji shown alongside the tag
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub emoji: Option<String>,
+    }
+
+    /// Order in which forum posts are listed
+    #[derive(Default)]
+    pub enum ForumSortOrder {
+        /// Most recently active posts first
+        #[default]
+        LatestActivity,
+        /// Most recently created posts first
+        CreationDate,
     }
 
     /// Partial representation of a channel
@@ -167,6 +278,16 @@ auto_derived!(
         pub default_permissions: Option<OverrideField>,
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub last_message_id: Option<String>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub slowmode_seconds: Option<u32>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub announcement: Option<bool>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub available_tags: Option<Vec<ForumTag>>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_sort_order: Option<ForumSortOrder>,
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub tags: Option<Vec<String>>,
     }
 
     /// Optional fields on channel object
@@ -174,6 +295,7 @@ auto_derived!(
         Description,
         Icon,
         DefaultPermissions,
+        RequestedBy,
     }
 
     /// New webhook information
@@ -202,11 +324,79 @@ auto_derived!(
         /// Whether this channel is archived
         pub archived: Option<bool>,
 
+        /// Seconds a member has to wait between sending messages
+        ///
+        /// Set to zero to disable slow mode.
+        #[cfg_attr(feature = "validator", validate(range(max = 21600)))]
+        pub slowmode_seconds: Option<u32>,
+
+        /// Whether this is an announcement channel
+        pub announcement: Option<bool>,
+
         /// Fields to remove from channel
         #[cfg_attr(feature = "serde", serde(default))]
         pub remove: Option<Vec<FieldsChannel>>,
     }
 
+    /// Forum settings
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditForum {
+        /// Tags which can be applied to posts
+        ///
+        /// Replaces the existing set of tags, omit `id` to create a new tag.
+        #[cfg_attr(feature = "validator", validate)]
+        pub available_tags: Option<Vec<DataForumTag>>,
+        /// Order posts are listed in by default
+        pub default_sort_order: Option<ForumSortOrder>,
+    }
+
+    /// Forum tag information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataForumTag {
+        /// Id of an existing tag to keep
+        pub id: Option<String>,
+        /// Tag name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 20)))]
+        pub name: String,
+        /// Emoji shown alongside the tag
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 128)))]
+        pub emoji: Option<String>,
+    }
+
+    /// New forum post
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateForumPost {
+        /// Title of the post
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub title: String,
+        /// Ids of the forum tags to apply
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub tags: Vec<String>,
+        /// Starter message of the post
+        #[cfg_attr(feature = "validator", validate)]
+        pub message: DataMessageSend,
+    }
+
+    /// Forum post changes
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditForumPost {
+        /// Title of the post
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub title: Option<String>,
+        /// Ids of the forum tags to apply
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        pub tags: Option<Vec<String>>,
+    }
+
+    /// Newly created forum post
+    pub struct ForumPostResponse {
+        /// Post channel
+        pub post: Channel,
+        /// Starter message of the post
+        pub message: Message,
+    }
+
     /// Create new group
     #[derive(Default)]
     #[cfg_attr(feature = "validator", derive(validator::Validate))]
@@ -240,7 +430,9 @@ impl Channel {
             | Channel::Group { id, .. }
             | Channel::SavedMessages { id, .. }
             | Channel::TextChannel { id, .. }
-            | Channel::VoiceChannel { id, .. } => id,
+            | Channel::VoiceChannel { id, .. }
+            | Channel::ForumChannel { id, .. }
+            | Channel::ForumPost { id, .. } => id,
         }
     }
 }
@@ -15,6 +15,9 @@ use super::{Embed, File, MessageWebhook, User, Webhook, RE_COLOUR};
 pub static RE_MENTION: Lazy<Regex> =
     Lazy::new(|| Regex::new(r"<@([0-9A-HJKMNP-TV-Z]{26})>").unwrap());
 
+pub static RE_ROLE_MENTION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"<%([0-9A-HJKMNP-TV-Z]{26})>").unwrap());
+
 auto_derived_partial!(
     /// Message
     pub struct Message {
@@ -49,6 +52,9 @@ auto_derived_partial!(
         /// Array of user ids mentioned in this message
         #[serde(skip_serializing_if = "Option::is_none")]
         pub mentions: Option<Vec<String>>,
+        /// Array of role ids mentioned in this message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub role_mentions: Option<Vec<String>>,
         /// Array of message ids this message is replying to
         #[serde(skip_serializing_if = "Option::is_none")]
         pub replies: Option<Vec<String>>,
@@ -71,11 +77,32 @@ auto_derived_partial!(
         pub session_id: Option<String>,
         #[serde(skip_serializing_if = "Option::is_none")]
         pub is_stream: Option<bool>,
+        /// Id of the only user this message is visible to
+        ///
+        /// Ephemeral messages are never stored and cannot be fetched again.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub ephemeral: Option<String>,
+        /// Original message this message was forwarded from
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub forwarded: Option<MessageForward>,
+        /// Whether this message is pinned in its channel
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub pinned: bool,
+        /// Whether this message has been published to channel followers
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub published: bool,
     },
     "PartialMessage"
 );
 
 auto_derived!(
+    /// Optional fields on message object
+    pub enum FieldsMessage {
+        Content,
+        Attachments,
+        Embeds,
+    }
+
     /// System Event
     #[serde(tag = "type")]
     pub enum SystemMessage {
@@ -133,6 +160,24 @@ auto_derived!(
         pub restrict_reactions: bool,
     }
 
+    /// Reference to the original message of a forwarded message
+    pub struct MessageForward {
+        /// Id of the original message
+        pub id: String,
+        /// Id of the channel the original message was sent in
+        pub channel: String,
+        /// Id of the user or webhook that sent the original message
+        pub author: String,
+        /// The webhook that sent the original message
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub webhook: Option<MessageWebhook>,
+        /// Whether the original message has since been deleted
+        ///
+        /// Content of the forwarded message is removed when this is set.
+        #[serde(skip_serializing_if = "crate::if_false", default)]
+        pub deleted: bool,
+    }
+
     /// Appended Information
     pub struct AppendMessage {
         /// Additional embeds to include in this message
@@ -211,6 +256,62 @@ auto_derived!(
         pub colour: Option<String>,
     }
 
+    /// Options for searching messages across a server
+    #[derive(Validate)]
+    pub struct DataMessageSearch {
+        /// Full-text search query
+        #[validate(length(min = 1, max = 64))]
+        pub query: Option<String>,
+        /// Only include messages sent by this user
+        #[validate(length(min = 26, max = 26))]
+        pub author: Option<String>,
+        /// Only include messages mentioning this user
+        #[validate(length(min = 26, max = 26))]
+        pub mentions: Option<String>,
+        /// Only search within these channels
+        #[validate(length(min = 1, max = 50))]
+        pub channels: Option<Vec<String>>,
+        /// Content every matched message must contain
+        #[serde(default)]
+        pub has: Vec<MessageSearchHas>,
+        /// Only include messages sent before this time
+        pub before: Option<Timestamp>,
+        /// Only include messages sent after this time
+        pub after: Option<Timestamp>,
+        /// Only include messages which are (or are not) pinned
+        pub pinned: Option<bool>,
+        /// Maximum number of messages to fetch
+        #[validate(range(min = 1, max = 100))]
+        pub limit: Option<i64>,
+        /// Message sort direction
+        ///
+        /// By default, it will be sorted by relevance.
+        #[serde(default)]
+        pub sort: MessageSort,
+    }
+
+    /// Kind of content a searched message must contain
+    pub enum MessageSearchHas {
+        Attachment,
+        Embed,
+        Link,
+    }
+
+    /// Message matched by a search
+    pub struct MessageSearchResult {
+        /// Matched message
+        pub message: Message,
+        /// Excerpt of the message content with matched terms emphasised
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub highlight: Option<String>,
+    }
+
+    /// Message forward information
+    pub struct DataMessageForward {
+        /// Id of the channel to forward the message to
+        pub channel: String,
+    }
+
     /// What this message should reply to and how
     pub struct ReplyIntent {
         /// Message Id
@@ -248,6 +349,11 @@ auto_derived!(
         /// Session ID of the message which created by bot's developer
         pub session_id: Option<String>,
         pub is_stream: Option<bool>,
+        /// Id of the user this message should only be shown to
+        ///
+        /// Only bots and webhooks may send ephemeral messages.
+        #[validate(length(min = 26, max = 26))]
+        pub ephemeral: Option<String>,
     }
 );
 
@@ -1,4 +1,7 @@
+mod account_strikes;
 mod bots;
+mod channel_exports;
+mod channel_followers;
 mod channel_invites;
 mod channel_unreads;
 mod channel_webhooks;
@@ -7,14 +10,26 @@ mod embeds;
 mod emojis;
 mod files;
 mod messages;
+mod server_audit_log;
+mod server_auto_roles;
+mod server_automod;
 mod server_bans;
+mod server_events;
 mod server_members;
+mod server_raid_protection;
+mod server_role_jobs;
+mod server_screening;
+mod server_templates;
 mod servers;
+mod user_content_filters;
 mod user_settings;
 mod users;
 mod uuap;
 
+pub use account_strikes::*;
 pub use bots::*;
+pub use channel_exports::*;
+pub use channel_followers::*;
 pub use channel_invites::*;
 pub use channel_unreads::*;
 pub use channel_webhooks::*;
@@ -23,9 +38,18 @@ pub use embeds::*;
 pub use emojis::*;
 pub use files::*;
 pub use messages::*;
+pub use server_audit_log::*;
+pub use server_auto_roles::*;
+pub use server_automod::*;
 pub use server_bans::*;
+pub use server_events::*;
 pub use server_members::*;
+pub use server_raid_protection::*;
+pub use server_role_jobs::*;
+pub use server_screening::*;
+pub use server_templates::*;
 pub use servers::*;
+pub use user_content_filters::*;
 pub use user_settings::*;
 pub use users::*;
 pub use uuap::*;
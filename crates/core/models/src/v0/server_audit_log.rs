@@ -0,0 +1,48 @@
+auto_derived!(
+    /// Audit Log Entry
+    pub struct AuditLogEntry {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this entry belongs to
+        pub server: String,
+        /// Id of the user who performed the action
+        pub actor: String,
+        /// Type of action that was performed
+        pub action: AuditLogAction,
+        /// Id of the object the action was performed on
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub target: Option<String>,
+        /// Reason given for the action
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub reason: Option<String>,
+        /// Fields which were changed by the action
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub changes: Option<AuditLogChanges>,
+    }
+
+    /// Values of changed fields before and after an action
+    pub struct AuditLogChanges {
+        /// Previous values
+        pub before: serde_json::Value,
+        /// New values
+        pub after: serde_json::Value,
+    }
+
+    /// Type of action recorded in the audit log
+    pub enum AuditLogAction {
+        ChannelDelete,
+        ChannelPermissionsUpdate,
+        RoleCreate,
+        RoleUpdate,
+        RoleDelete,
+        ServerPermissionsUpdate,
+        MemberKick,
+        MemberBan,
+        MemberUnban,
+        WebhookCreate,
+        VanityUpdate,
+        OwnershipTransfer,
+        CategoryPermissionsUpdate,
+    }
+);
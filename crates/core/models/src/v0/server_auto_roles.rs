@@ -0,0 +1,45 @@
+auto_derived!(
+    /// Rule which gives members a role automatically
+    pub struct AutoRoleRule {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this rule belongs to
+        pub server: String,
+        /// Id of the user who created this rule
+        pub creator: String,
+        /// Id of the role given out by this rule
+        pub role: String,
+        /// Condition under which the role is given out
+        pub trigger: AutoRoleTrigger,
+    }
+
+    /// Condition under which an auto role rule gives out its role
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum AutoRoleTrigger {
+        /// Member joins the server
+        Join,
+        /// Member has been in the server for some time
+        Tenure {
+            /// Number of days since the member joined
+            days: u32,
+        },
+        /// Member reacts to a message
+        Reaction {
+            /// Id of the channel the message is in
+            channel: String,
+            /// Id of the message
+            message: String,
+            /// Emoji which must be reacted with
+            emoji: String,
+        },
+    }
+
+    /// New auto role rule information
+    pub struct DataCreateAutoRoleRule {
+        /// Id of the role to give out
+        pub role: String,
+        /// Condition under which the role is given out
+        pub trigger: AutoRoleTrigger,
+    }
+);
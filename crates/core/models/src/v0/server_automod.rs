@@ -0,0 +1,121 @@
+auto_derived!(
+    /// Automod Rule
+    pub struct AutomodRule {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this rule belongs to
+        pub server: String,
+        /// Name of the rule
+        pub name: String,
+        /// Whether this rule is currently being enforced
+        pub enabled: bool,
+        /// Condition which causes this rule to fire
+        pub trigger: AutomodTrigger,
+        /// Actions taken when this rule fires
+        pub actions: Vec<AutomodAction>,
+        /// Roles which are not subject to this rule
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub exempt_roles: Vec<String>,
+        /// Channels in which this rule is not enforced
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub exempt_channels: Vec<String>,
+    }
+
+    /// Condition which causes an automod rule to fire
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum AutomodTrigger {
+        /// Message content contains a keyword or matches a regular expression
+        Keyword {
+            /// Case-insensitive words or phrases to look for
+            #[cfg_attr(feature = "serde", serde(default))]
+            keywords: Vec<String>,
+            /// Regular expressions to match against
+            #[cfg_attr(feature = "serde", serde(default))]
+            patterns: Vec<String>,
+        },
+        /// Message mentions more users than allowed
+        MentionSpam {
+            /// Maximum number of unique mentions
+            limit: u32,
+        },
+        /// Message contains an invite link
+        InviteLink,
+        /// Author's account is younger than allowed
+        NewAccount {
+            /// Minimum account age in seconds
+            min_age: u64,
+        },
+        /// Message includes an attachment of a blocked type
+        Attachment {
+            /// Blocked MIME types (e.g. `image/gif`, `video/`) or file extensions (e.g. `.exe`)
+            blocked: Vec<String>,
+        },
+    }
+
+    /// Action taken when an automod rule fires
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum AutomodAction {
+        /// Reject the message and tell the author why
+        Block,
+        /// Remove the message, new messages are never delivered
+        Delete,
+        /// Time out the author
+        Timeout {
+            /// Duration of the timeout in seconds
+            duration: u64,
+        },
+        /// Post a notice in a moderation channel
+        Alert {
+            /// Id of the channel to alert in
+            channel: String,
+        },
+    }
+
+    /// New automod rule information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateAutomodRule {
+        /// Rule name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Whether the rule should be enforced immediately
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub enabled: Option<bool>,
+        /// Condition which causes the rule to fire
+        pub trigger: AutomodTrigger,
+        /// Actions taken when the rule fires
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 4)))]
+        pub actions: Vec<AutomodAction>,
+        /// Roles which are not subject to the rule
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub exempt_roles: Option<Vec<String>>,
+        /// Channels in which the rule is not enforced
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub exempt_channels: Option<Vec<String>>,
+    }
+
+    /// Changes to an automod rule
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditAutomodRule {
+        /// Rule name
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: Option<String>,
+        /// Whether the rule is enforced
+        pub enabled: Option<bool>,
+        /// Condition which causes the rule to fire
+        pub trigger: Option<AutomodTrigger>,
+        /// Actions taken when the rule fires
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 4)))]
+        pub actions: Option<Vec<AutomodAction>>,
+        /// Roles which are not subject to the rule
+        pub exempt_roles: Option<Vec<String>>,
+        /// Channels in which the rule is not enforced
+        pub exempt_channels: Option<Vec<String>>,
+    }
+);
@@ -1,5 +1,7 @@
 use super::MemberCompositeKey;
 
+use iso8601_timestamp::Timestamp;
+
 auto_derived!(
     /// Server Ban
     pub struct ServerBan {
@@ -8,5 +10,8 @@ auto_derived!(
         pub id: MemberCompositeKey,
         /// Reason for ban creation
         pub reason: Option<String>,
+        /// Time at which this ban is lifted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub expires: Option<Timestamp>,
     }
 );
@@ -0,0 +1,111 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived_partial!(
+    /// Scheduled server event
+    pub struct ServerEvent {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this event belongs to
+        pub server: String,
+        /// Id of the user who scheduled this event
+        pub creator: String,
+
+        /// Title of the event
+        pub name: String,
+        /// Description of the event
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+
+        /// Time at which this event starts
+        pub starts_at: Timestamp,
+        /// Time at which this event ends
+        ///
+        /// Events without an end time complete a day after they start.
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub ends_at: Option<Timestamp>,
+
+        /// Where this event takes place
+        pub location: ServerEventLocation,
+        /// Current status of this event
+        pub status: ServerEventStatus,
+
+        /// Ids of members who are attending this event
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub attendees: Vec<String>,
+    },
+    "PartialServerEvent"
+);
+
+auto_derived!(
+    /// Location of a server event
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum ServerEventLocation {
+        /// Voice channel within the server
+        Channel {
+            /// Id of the voice channel
+            id: String,
+        },
+        /// Somewhere outside of the platform
+        External {
+            /// Link to the external location
+            url: String,
+        },
+    }
+
+    /// Status of a server event
+    pub enum ServerEventStatus {
+        /// Event has yet to start
+        Scheduled,
+        /// Event is currently taking place
+        Active,
+        /// Event has ended
+        Completed,
+    }
+
+    /// Optional fields on server event object
+    pub enum FieldsServerEvent {
+        Description,
+        EndsAt,
+    }
+
+    /// New server event
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateServerEvent {
+        /// Title of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub name: String,
+        /// Description of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 1024)))]
+        pub description: Option<String>,
+        /// Time at which this event starts
+        pub starts_at: Timestamp,
+        /// Time at which this event ends
+        pub ends_at: Option<Timestamp>,
+        /// Where this event takes place
+        pub location: ServerEventLocation,
+    }
+
+    /// Server event changes
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditServerEvent {
+        /// Title of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 100)))]
+        pub name: Option<String>,
+        /// Description of the event
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 1024)))]
+        pub description: Option<String>,
+        /// Time at which this event starts
+        pub starts_at: Option<Timestamp>,
+        /// Time at which this event ends
+        pub ends_at: Option<Timestamp>,
+        /// Where this event takes place
+        pub location: Option<ServerEventLocation>,
+        /// Fields to remove from server event
+        #[cfg_attr(feature = "serde", serde(default))]
+        pub remove: Vec<FieldsServerEvent>,
+    }
+);
@@ -49,6 +49,15 @@ auto_derived_partial!(
         /// Timestamp this member is timed out until
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub timeout: Option<Timestamp>,
+        /// Screening this member has yet to pass before they may participate
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub pending: Option<MemberScreening>,
+        /// Whether this member is removed once they go offline, unless given a role
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub temporary: bool,
     },
     "PartialMember"
 );
@@ -63,12 +72,22 @@ auto_derived!(
         pub user: String,
     }
 
+    /// Screening state of a member who has not yet been let into a server
+    #[derive(Default)]
+    pub struct MemberScreening {
+        /// Time at which the member accepted the rules and submitted their answers,
+        /// not present until they have done so
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub submitted_at: Option<Timestamp>,
+    }
+
     /// Optional fields on server member object
     pub enum FieldsMember {
         Nickname,
         Avatar,
         Roles,
         Timeout,
+        Pending,
     }
 
     /// Member removal intention
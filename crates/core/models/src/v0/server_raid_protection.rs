@@ -0,0 +1,82 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Raid protection settings of a server
+    pub struct RaidProtection {
+        /// Id of the server
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Number of joins within the join window which starts a lockdown
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub join_threshold: Option<u32>,
+        /// Length of the window over which joins are counted, in seconds
+        pub join_window: u64,
+        /// How long an automatic lockdown lasts, in seconds
+        pub lockdown_duration: u64,
+        /// Minimum age of an account in seconds before it may join
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub min_account_age: Option<u64>,
+        /// Whether only accounts with a verified email may join
+        pub require_verified_email: bool,
+        /// Channel in which moderators are alerted when a lockdown starts
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub alert_channel: Option<String>,
+        /// Lockdown currently in effect
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub lockdown: Option<Lockdown>,
+    }
+
+    /// Server lockdown
+    ///
+    /// While a server is locked down no new invites may be created
+    /// and only accounts with a verified email may join.
+    pub struct Lockdown {
+        /// Id of the user who started the lockdown, not present if it started automatically
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub started_by: Option<String>,
+        /// Time at which the lockdown started
+        pub since: Timestamp,
+        /// Time at which the lockdown is lifted
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub expires: Option<Timestamp>,
+    }
+
+    /// Optional fields on raid protection settings
+    pub enum FieldsRaidProtection {
+        JoinThreshold,
+        MinAccountAge,
+        AlertChannel,
+    }
+
+    /// Changes to raid protection settings
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditRaidProtection {
+        /// Number of joins within the join window which starts a lockdown
+        #[cfg_attr(feature = "validator", validate(range(min = 2, max = 1000)))]
+        pub join_threshold: Option<u32>,
+        /// Length of the window over which joins are counted, in seconds
+        #[cfg_attr(feature = "validator", validate(range(min = 10, max = 3600)))]
+        pub join_window: Option<u64>,
+        /// How long an automatic lockdown lasts, in seconds
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 86400)))]
+        pub lockdown_duration: Option<u64>,
+        /// Minimum age of an account in seconds before it may join
+        #[cfg_attr(feature = "validator", validate(range(min = 1, max = 2592000)))]
+        pub min_account_age: Option<u64>,
+        /// Whether only accounts with a verified email may join
+        pub require_verified_email: Option<bool>,
+        /// Channel in which moderators are alerted when a lockdown starts
+        pub alert_channel: Option<String>,
+        /// Fields to remove from the settings
+        #[cfg_attr(feature = "validator", validate(length(min = 1)))]
+        pub remove: Option<Vec<FieldsRaidProtection>>,
+    }
+
+    /// Lockdown information
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataStartLockdown {
+        /// How long the lockdown lasts in seconds, until lifted if not present
+        #[cfg_attr(feature = "validator", validate(range(min = 60, max = 86400)))]
+        pub duration: Option<u64>,
+    }
+);
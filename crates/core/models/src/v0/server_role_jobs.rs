@@ -0,0 +1,81 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Bulk role assignment job
+    pub struct RoleJob {
+        /// Unique Id
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Id of the server this job runs in
+        pub server: String,
+        /// Id of the user who started this job
+        pub creator: String,
+        /// Id of the role being added or removed
+        pub role: String,
+        /// Whether the role is being added or removed
+        pub action: RoleJobAction,
+        /// Members this job applies to
+        pub selection: MemberSelection,
+        /// Current state of the job
+        pub status: RoleJobStatus,
+        /// Number of selected members processed so far
+        pub processed: u32,
+        /// Number of members whose roles were changed
+        pub affected: u32,
+        /// Time at which the job finished
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub completed_at: Option<Timestamp>,
+    }
+
+    /// Change made to each selected member
+    pub enum RoleJobAction {
+        /// Give the role to members who do not have it
+        Add,
+        /// Take the role away from members who have it
+        Remove,
+    }
+
+    /// Selection of server members
+    #[cfg_attr(feature = "serde", serde(tag = "type"))]
+    pub enum MemberSelection {
+        /// Members who have a given role
+        Role {
+            /// Id of the role
+            id: String,
+        },
+        /// Members who joined within a period of time
+        JoinDate {
+            /// Only select members who joined after this time
+            #[cfg_attr(feature = "serde", serde(default))]
+            after: Option<Timestamp>,
+            /// Only select members who joined before this time
+            #[cfg_attr(feature = "serde", serde(default))]
+            before: Option<Timestamp>,
+        },
+        /// Explicit list of members
+        Members {
+            /// Ids of the users, at most 1,000 may be given
+            ids: Vec<String>,
+        },
+    }
+
+    /// State of a bulk role assignment job
+    pub enum RoleJobStatus {
+        /// Waiting to be picked up by a worker
+        Pending,
+        /// Members are currently being updated
+        Running,
+        /// Every selected member has been processed
+        Completed,
+        /// Job could not be completed
+        Failed,
+    }
+
+    /// Start a new bulk role assignment job
+    pub struct DataCreateRoleJob {
+        /// Whether the role should be added or removed
+        pub action: RoleJobAction,
+        /// Members to apply the change to
+        pub selection: MemberSelection,
+    }
+);
@@ -0,0 +1,71 @@
+use iso8601_timestamp::Timestamp;
+
+auto_derived!(
+    /// Membership screening settings of a server
+    pub struct ServerScreening {
+        /// Id of the server
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Whether new members must pass screening before they may participate
+        pub enabled: bool,
+        /// Rules new members must accept
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub rules: Vec<String>,
+        /// Questions new members are asked
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub questions: Vec<ScreeningQuestion>,
+        /// Whether a moderator must approve members after they submit
+        pub require_approval: bool,
+    }
+
+    /// Question asked to new members during screening
+    pub struct ScreeningQuestion {
+        /// Question text
+        pub question: String,
+        /// Whether the question must be answered
+        pub required: bool,
+    }
+
+    /// Screening submitted by a member and awaiting approval
+    pub struct ScreeningSubmission {
+        /// Id of the member
+        pub user: String,
+        /// Answers in the same order as the server's questions
+        pub answers: Vec<String>,
+        /// Time at which the member submitted
+        pub submitted_at: Timestamp,
+    }
+
+    /// Changes to membership screening settings
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditScreening {
+        /// Whether new members must pass screening
+        pub enabled: Option<bool>,
+        /// Rules new members must accept
+        #[cfg_attr(feature = "validator", validate(length(max = 20)))]
+        pub rules: Option<Vec<String>>,
+        /// Questions new members are asked
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        pub questions: Option<Vec<ScreeningQuestion>>,
+        /// Whether a moderator must approve members after they submit
+        pub require_approval: Option<bool>,
+    }
+
+    /// Rules acceptance and answers to screening questions
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataSubmitScreening {
+        /// Must be true to accept the server's rules
+        pub accept_rules: bool,
+        /// Answers in the same order as the server's questions,
+        /// use an empty string to skip an optional question
+        #[cfg_attr(feature = "serde", serde(default))]
+        #[cfg_attr(feature = "validator", validate(length(max = 5)))]
+        pub answers: Vec<String>,
+    }
+);
@@ -0,0 +1,109 @@
+use super::{Category, Role, SystemMessageChannels};
+
+use iso8601_timestamp::Timestamp;
+use revolt_permissions::OverrideField;
+use std::collections::HashMap;
+
+auto_derived!(
+    /// Snapshot of a server's structure which new servers can be created from
+    pub struct ServerTemplate {
+        /// Code used to share this template
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub code: String,
+        /// Id of the server this template was created from
+        pub server: String,
+        /// Id of the user who created this template
+        pub creator: String,
+
+        /// Name of the template
+        pub name: String,
+        /// Description of the template
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Time at which this template was created
+        pub created_at: Timestamp,
+
+        /// Captured server structure
+        pub snapshot: ServerTemplateSnapshot,
+    }
+
+    /// Structure of a server captured by a template
+    pub struct ServerTemplateSnapshot {
+        /// Channels in the order they appeared on the server
+        pub channels: Vec<TemplateChannel>,
+        /// Categories for the channels
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub categories: Vec<Category>,
+        /// Configuration for sending system event messages
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub system_messages: Option<SystemMessageChannels>,
+        /// Roles and their permissions
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "HashMap::<String, Role>::new",
+                skip_serializing_if = "HashMap::<String, Role>::is_empty"
+            )
+        )]
+        pub roles: HashMap<String, Role>,
+        /// Default set of server and channel permissions
+        pub default_permissions: i64,
+    }
+
+    /// Channel captured by a template
+    pub struct TemplateChannel {
+        /// Id of the channel within the template
+        pub id: String,
+        /// Type of channel
+        pub channel_type: TemplateChannelType,
+        /// Display name of the channel
+        pub name: String,
+        /// Channel description
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub description: Option<String>,
+        /// Default permissions assigned to users in this channel
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this channel
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+        /// Whether this channel is marked as not safe for work
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub nsfw: bool,
+        /// Seconds a member has to wait between sending messages
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_zero_u32", default)
+        )]
+        pub slowmode_seconds: u32,
+    }
+
+    /// Type of channel captured by a template
+    pub enum TemplateChannelType {
+        Text,
+        Voice,
+    }
+
+    /// New server template
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataCreateServerTemplate {
+        /// Name of the template
+        #[cfg_attr(feature = "validator", validate(length(min = 1, max = 32)))]
+        pub name: String,
+        /// Description of the template
+        #[cfg_attr(feature = "validator", validate(length(min = 0, max = 1024)))]
+        pub description: Option<String>,
+    }
+);
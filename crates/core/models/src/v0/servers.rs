@@ -72,6 +72,10 @@ auto_derived_partial!(
             serde(skip_serializing_if = "crate::if_false", default)
         )]
         pub discoverable: bool,
+
+        /// Id of the member an ownership transfer is awaiting acceptance from
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub pending_owner: Option<String>,
     },
     "PartialServer"
 );
@@ -88,12 +92,24 @@ auto_derived_partial!(
         /// This can be any valid CSS colour
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub colour: Option<String>,
+        /// Icon shown next to members with this role
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub icon: Option<File>,
+        /// Unicode emoji shown next to members with this role
+        #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+        pub emoji: Option<String>,
         /// Whether this role should be shown separately on the member sidebar
         #[cfg_attr(
             feature = "serde",
             serde(skip_serializing_if = "crate::if_false", default)
         )]
         pub hoist: bool,
+        /// Whether anyone can mention this role
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "crate::if_false", default)
+        )]
+        pub mentionable: bool,
         /// Ranking of this role
         #[cfg_attr(feature = "serde", serde(default))]
         pub rank: i64,
@@ -109,11 +125,14 @@ auto_derived!(
         SystemMessages,
         Icon,
         Banner,
+        PendingOwner,
     }
 
     /// Optional fields on server object
     pub enum FieldsRole {
         Colour,
+        Icon,
+        Emoji,
     }
 
     /// Channel category
@@ -124,6 +143,28 @@ auto_derived!(
         pub title: String,
         /// Channels in this category
         pub channels: Vec<String>,
+
+        /// Default permissions assigned to users in this category
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Option::is_none", default)
+        )]
+        pub default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this category
+        #[cfg_attr(
+            feature = "serde",
+            serde(
+                default = "HashMap::<String, OverrideField>::new",
+                skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+            )
+        )]
+        pub role_permissions: HashMap<String, OverrideField>,
+        /// Channels which use this category's permissions in place of their own
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub synced: Vec<String>,
     }
 
     /// System message channel assignments
@@ -141,4 +182,28 @@ auto_derived!(
         #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
         pub user_banned: Option<String>,
     }
+
+    /// Ownership transfer request
+    pub struct DataTransferServerOwnership {
+        /// Id of the member who should become the new owner
+        pub user: String,
+    }
+
+    /// Channel move
+    pub struct DataMoveChannel {
+        /// Category to place the channel in, leave empty to uncategorise it
+        pub category: Option<String>,
+        /// Position within the category, defaults to the end
+        pub position: Option<usize>,
+        /// Whether the channel should use the category's permissions
+        ///
+        /// Defaults to the current state when staying in the same category and to `false` otherwise.
+        pub sync: Option<bool>,
+    }
+
+    /// Category order
+    pub struct DataReorderCategories {
+        /// Ids of every category in the desired order
+        pub categories: Vec<String>,
+    }
 );
@@ -0,0 +1,42 @@
+auto_derived!(
+    /// Personal content filter applied to direct messages and groups
+    pub struct ContentFilter {
+        /// Id of the user this filter belongs to
+        #[cfg_attr(feature = "serde", serde(rename = "_id"))]
+        pub id: String,
+        /// Messages containing any of these words are hidden
+        #[cfg_attr(
+            feature = "serde",
+            serde(skip_serializing_if = "Vec::is_empty", default)
+        )]
+        pub words: Vec<String>,
+        /// Whether attachments sent by users who are not friends are hidden
+        pub hide_stranger_attachments: bool,
+        /// Who may send direct messages
+        pub direct_messages: DirectMessagePrivacy,
+    }
+
+    /// Who may send someone direct messages
+    #[derive(Default)]
+    pub enum DirectMessagePrivacy {
+        /// Anyone who shares a server or group may message directly
+        Everyone,
+        /// Users who are not friends start out in the message requests inbox
+        #[default]
+        Requests,
+        /// Only friends may send direct messages
+        Friends,
+    }
+
+    /// Changes to a content filter
+    #[cfg_attr(feature = "validator", derive(validator::Validate))]
+    pub struct DataEditContentFilter {
+        /// Messages containing any of these words are hidden
+        #[cfg_attr(feature = "validator", validate(length(max = 100)))]
+        pub words: Option<Vec<String>>,
+        /// Whether attachments sent by users who are not friends are hidden
+        pub hide_stranger_attachments: Option<bool>,
+        /// Who may send direct messages
+        pub direct_messages: Option<DirectMessagePrivacy>,
+    }
+);
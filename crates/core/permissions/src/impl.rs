@@ -54,7 +54,7 @@ pub async fn calculate_server_permissions<P: PermissionQuery>(query: &mut P) ->
         permissions.apply(role_override);
     }
 
-    if query.are_we_timed_out().await {
+    if query.are_we_timed_out().await || query.are_we_pending_screening().await {
         permissions.restrict(*ALLOW_IN_TIMEOUT);
     }
 
@@ -1,7 +1,7 @@
 use crate::{
-    calculate_channel_permissions, calculate_user_permissions, ChannelPermission, ChannelType,
-    Override, PermissionQuery, RelationshipStatus, DEFAULT_PERMISSION_DIRECT_MESSAGE,
-    DEFAULT_PERMISSION_SERVER, DEFAULT_PERMISSION_VIEW_ONLY,
+    calculate_channel_permissions, calculate_server_permissions, calculate_user_permissions,
+    ChannelPermission, ChannelType, Override, PermissionQuery, RelationshipStatus,
+    DEFAULT_PERMISSION_DIRECT_MESSAGE, DEFAULT_PERMISSION_SERVER, DEFAULT_PERMISSION_VIEW_ONLY,
 };
 
 #[async_std::test]
@@ -64,6 +64,10 @@ async fn validate_user_permissions() {
             unreachable!()
         }
 
+        async fn are_we_pending_screening(&mut self) -> bool {
+            unreachable!()
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::DirectMessage
         }
@@ -153,6 +157,10 @@ async fn validate_group_permissions() {
             unreachable!()
         }
 
+        async fn are_we_pending_screening(&mut self) -> bool {
+            unreachable!()
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::Group
         }
@@ -254,6 +262,10 @@ async fn validate_server_permissions() {
             false
         }
 
+        async fn are_we_pending_screening(&mut self) -> bool {
+            false
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::ServerChannel
         }
@@ -346,6 +358,10 @@ async fn validate_timed_out_member() {
             true
         }
 
+        async fn are_we_pending_screening(&mut self) -> bool {
+            false
+        }
+
         async fn get_channel_type(&mut self) -> ChannelType {
             ChannelType::ServerChannel
         }
@@ -375,3 +391,95 @@ async fn validate_timed_out_member() {
         }
     }
 }
+
+#[async_std::test]
+async fn validate_pending_member() {
+    /// Scenario in which we have joined a server but not yet passed its screening
+    struct Scenario {}
+    let mut query = Scenario {};
+
+    let perms = calculate_server_permissions(&mut query).await;
+    assert!(!perms.has_channel_permission(ChannelPermission::SendMessage));
+
+    let value: u64 = perms.into();
+    assert_eq!(value, *DEFAULT_PERMISSION_VIEW_ONLY);
+
+    #[async_trait]
+    impl PermissionQuery for Scenario {
+        async fn are_we_privileged(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_the_users_same(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn user_relationship(&mut self) -> RelationshipStatus {
+            unreachable!()
+        }
+
+        async fn user_is_bot(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn have_mutual_connection(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_server_owner(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_a_member(&mut self) -> bool {
+            true
+        }
+
+        async fn get_default_server_permissions(&mut self) -> u64 {
+            *DEFAULT_PERMISSION_SERVER
+        }
+
+        async fn get_our_server_role_overrides(&mut self) -> Vec<Override> {
+            vec![]
+        }
+
+        async fn are_we_timed_out(&mut self) -> bool {
+            false
+        }
+
+        async fn are_we_pending_screening(&mut self) -> bool {
+            true
+        }
+
+        async fn get_channel_type(&mut self) -> ChannelType {
+            unreachable!()
+        }
+
+        async fn get_default_channel_permissions(&mut self) -> Override {
+            unreachable!()
+        }
+
+        async fn get_our_channel_role_overrides(&mut self) -> Vec<Override> {
+            unreachable!()
+        }
+
+        async fn do_we_own_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn are_we_part_of_the_channel(&mut self) -> bool {
+            unreachable!()
+        }
+
+        async fn set_recipient_as_user(&mut self) {
+            unreachable!()
+        }
+
+        async fn set_server_from_channel(&mut self) {
+            unreachable!()
+        }
+    }
+}
@@ -39,6 +39,9 @@ pub trait PermissionQuery {
     /// Is our perspective user timed out on this server?
     async fn are_we_timed_out(&mut self) -> bool;
 
+    /// Has our perspective user yet to pass membership screening on this server?
+    async fn are_we_pending_screening(&mut self) -> bool;
+
     // * For calculating channel permission
 
     /// Get the type of the channel
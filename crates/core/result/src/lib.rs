@@ -77,6 +77,15 @@ pub enum ErrorType {
     },
     AlreadyInGroup,
     NotInGroup,
+    InSlowMode {
+        retry_after: u64,
+    },
+    TooManyPendingExports {
+        max: usize,
+    },
+    BlockedByAutomod {
+        rule: String,
+    },
 
     // ? Server related errors
     UnknownServer,
@@ -91,8 +100,25 @@ pub enum ErrorType {
     TooManyRoles {
         max: usize,
     },
+    TooManyAutomodRules {
+        max: usize,
+    },
+    TooManyServerTemplates {
+        max: usize,
+    },
+    TooManyAutoRoleRules {
+        max: usize,
+    },
+    TooManyPendingRoleJobs {
+        max: usize,
+    },
     AlreadyInServer,
     ServerHasDefaultBotAlive,
+    VanityCodeTaken,
+    VanityCodeRatelimited,
+    InTimeout {
+        until: String,
+    },
 
     // ? Bot related errors
     ReachedMaximumBots,
@@ -102,6 +128,8 @@ pub enum ErrorType {
 
     // ? User safety related errors
     CannotReportYourself,
+    AccountSuspended,
+    AccountBanned,
 
     // ? Permission errors
     MissingPermission {
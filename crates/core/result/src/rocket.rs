@@ -39,18 +39,28 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::GroupTooLarge { .. } => Status::Forbidden,
             ErrorType::AlreadyInGroup => Status::Conflict,
             ErrorType::NotInGroup => Status::NotFound,
+            ErrorType::InSlowMode { .. } => Status::TooManyRequests,
+            ErrorType::TooManyPendingExports { .. } => Status::TooManyRequests,
+            ErrorType::BlockedByAutomod { .. } => Status::Forbidden,
 
             ErrorType::UnknownServer => Status::NotFound,
             ErrorType::InvalidRole => Status::NotFound,
             ErrorType::Banned => Status::Forbidden,
             ErrorType::AlreadyInServer => Status::Conflict,
             ErrorType::ServerHasDefaultBotAlive => Status::Forbidden,
+            ErrorType::VanityCodeTaken => Status::Conflict,
+            ErrorType::VanityCodeRatelimited => Status::TooManyRequests,
 
             ErrorType::TooManyServers { .. } => Status::BadRequest,
             ErrorType::TooManyEmbeds { .. } => Status::BadRequest,
             ErrorType::TooManyEmoji { .. } => Status::BadRequest,
             ErrorType::TooManyChannels { .. } => Status::BadRequest,
             ErrorType::TooManyRoles { .. } => Status::BadRequest,
+            ErrorType::TooManyAutomodRules { .. } => Status::BadRequest,
+            ErrorType::TooManyServerTemplates { .. } => Status::BadRequest,
+            ErrorType::TooManyAutoRoleRules { .. } => Status::BadRequest,
+            ErrorType::TooManyPendingRoleJobs { .. } => Status::TooManyRequests,
+            ErrorType::InTimeout { .. } => Status::Forbidden,
 
             ErrorType::ReachedMaximumBots => Status::BadRequest,
             ErrorType::IsBot => Status::BadRequest,
@@ -58,6 +68,8 @@ impl<'r> Responder<'r, 'static> for Error {
             ErrorType::DuplicatePublicBotName => Status::Forbidden,
 
             ErrorType::CannotReportYourself => Status::BadRequest,
+            ErrorType::AccountSuspended => Status::Forbidden,
+            ErrorType::AccountBanned => Status::Forbidden,
 
             ErrorType::MissingPermission { .. } => Status::Forbidden,
             ErrorType::MissingUserPermission { .. } => Status::Forbidden,
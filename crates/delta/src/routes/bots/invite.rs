@@ -142,6 +142,7 @@ mod test {
             flags: None,
             roles: Default::default(),
             system_messages: None,
+            pending_owner: None,
         };
 
         server.create(&harness.db).await.unwrap();
@@ -1,7 +1,13 @@
+use revolt_database::{
+    util::audit_log_reason::AuditLogReason, AuditLogEntry, ChannelFollower, Database,
+    ForumPostQuery,
+};
+use revolt_models::v0::{AuditLogAction, ForumSortOrder};
 use revolt_quark::{
     models::{channel::PartialChannel, Channel, User},
     perms, Db, EmptyResponse, Error, Permission, Ref, Result,
 };
+use rocket::State;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -19,9 +25,11 @@ pub struct OptionsChannelDelete {
 #[delete("/<target>?<options..>")]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     options: OptionsChannelDelete,
+    reason: AuditLogReason,
 ) -> Result<EmptyResponse> {
     let mut channel = target.as_channel(db).await?;
     let mut perms = perms(&user).channel(&channel);
@@ -49,12 +57,62 @@ pub async fn req(
             )
             .await
             .map(|_| EmptyResponse),
-        Channel::TextChannel { .. } | Channel::VoiceChannel { .. } => {
-            perms
-                .throw_permission(db, Permission::ManageChannel)
-                .await?;
+        Channel::TextChannel { id, server, .. }
+        | Channel::VoiceChannel { id, server, .. }
+        | Channel::ForumChannel { id, server, .. }
+        | Channel::ForumPost { id, server, .. } => {
+            // Authors may always remove their own posts
+            if !matches!(&channel, Channel::ForumPost { author, .. } if author == &user.id) {
+                perms
+                    .throw_permission(db, Permission::ManageChannel)
+                    .await?;
+            }
+
+            // Posts go along with their forum
+            if let Channel::ForumChannel { .. } = &channel {
+                loop {
+                    let posts = database
+                        .fetch_forum_posts(
+                            id,
+                            ForumPostQuery {
+                                limit: 100,
+                                before: None,
+                                tag: None,
+                                sort: ForumSortOrder::CreationDate,
+                            },
+                        )
+                        .await
+                        .map_err(Error::from_core)?;
+
+                    if posts.is_empty() {
+                        break;
+                    }
+
+                    for post in posts {
+                        post.delete(database).await.map_err(Error::from_core)?;
+                    }
+                }
+            }
+
+            let (server, target) = (server.clone(), id.clone());
+
+            channel.delete(db).await?;
+            ChannelFollower::delete_for_channels(database, &[target.clone()])
+                .await
+                .map_err(Error::from_core)?;
+
+            AuditLogEntry::record(
+                database,
+                server,
+                user.id.clone(),
+                AuditLogAction::ChannelDelete,
+                Some(target),
+                reason.into_inner(),
+                None,
+            )
+            .await;
 
-            channel.delete(db).await.map(|_| EmptyResponse)
+            Ok(EmptyResponse)
         }
     }
 }
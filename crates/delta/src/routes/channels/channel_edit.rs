@@ -31,6 +31,13 @@ pub struct DataEditChannel {
     nsfw: Option<bool>,
     /// Whether this channel is archived
     archived: Option<bool>,
+    /// Seconds a member has to wait between sending messages
+    ///
+    /// Set to zero to disable slow mode.
+    #[validate(range(max = 21600))]
+    slowmode_seconds: Option<u32>,
+    /// Whether this is an announcement channel
+    announcement: Option<bool>,
     #[validate(length(min = 1))]
     remove: Option<Vec<FieldsChannel>>,
 }
@@ -61,11 +68,20 @@ pub async fn req(
         && data.icon.is_none()
         && data.nsfw.is_none()
         && data.owner.is_none()
+        && data.slowmode_seconds.is_none()
+        && data.announcement.is_none()
         && data.remove.is_none()
     {
         return Ok(Json(channel));
     }
 
+    // Slow mode and announcements only apply to server text channels
+    if (data.slowmode_seconds.is_some() || data.announcement.is_some())
+        && !matches!(channel, Channel::TextChannel { .. })
+    {
+        return Err(Error::InvalidOperation);
+    }
+
     let mut partial: PartialChannel = Default::default();
 
     // Transfer group ownership
@@ -126,6 +142,14 @@ pub async fn req(
             icon,
             nsfw,
             ..
+        }
+        | Channel::ForumChannel {
+            id,
+            name,
+            description,
+            icon,
+            nsfw,
+            ..
         } => {
             if let Some(fields) = &data.remove {
                 if fields.contains(&FieldsChannel::Icon) {
@@ -167,6 +191,9 @@ pub async fn req(
                 partial.nsfw = Some(new_nsfw);
             }
 
+            partial.slowmode_seconds = data.slowmode_seconds;
+            partial.announcement = data.announcement;
+
             // Send out mutation system messages.
             if let Channel::Group { .. } = &channel {
                 if let Some(name) = &partial.name {
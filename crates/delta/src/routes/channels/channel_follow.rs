@@ -0,0 +1,62 @@
+use revolt_database::{
+    util::{
+        audit_log_reason::AuditLogReason, permissions::DatabasePermissionQuery,
+        reference::Reference,
+    },
+    AuditLogEntry, Channel, ChannelFollower, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Follow Channel
+///
+/// Follow an announcement channel, delivering messages published in it to a channel in another server.
+#[openapi(tag = "Channel Followers")]
+#[post("/<target>/followers", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataFollowChannel>,
+    reason: AuditLogReason,
+) -> Result<Json<v0::ChannelFollower>> {
+    let data = data.into_inner();
+
+    // Ensure we can see the announcement channel
+    let source = target.as_channel(db).await?;
+    let server = if let Channel::TextChannel { server, .. } = &source {
+        db.fetch_server(server).await?
+    } else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&source);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    // Ensure we can create webhooks where messages will be delivered
+    let destination = db.fetch_channel(&data.channel).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&destination);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageWebhooks)?;
+
+    let follower =
+        ChannelFollower::create(db, &source, &server, &destination, user.id.clone()).await?;
+
+    AuditLogEntry::record(
+        db,
+        follower.target_server.clone(),
+        user.id,
+        v0::AuditLogAction::WebhookCreate,
+        Some(follower.webhook.clone()),
+        reason.into_inner(),
+        None,
+    )
+    .await;
+
+    Ok(Json(follower.into()))
+}
@@ -0,0 +1,38 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    ChannelExport, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Export Channel
+///
+/// Start exporting the message history of a channel.
+///
+/// Progress is reported through `ChannelExportUpdate` events.
+#[openapi(tag = "Channel Information")]
+#[post("/<target>/exports", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataCreateExport>,
+) -> Result<Json<v0::ChannelExport>> {
+    if user.bot.is_some() {
+        return Err(create_error!(IsBot));
+    }
+
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ReadMessageHistory)?;
+
+    Ok(Json(
+        ChannelExport::create(db, channel.id(), user.id, data.into_inner().format)
+            .await?
+            .into(),
+    ))
+}
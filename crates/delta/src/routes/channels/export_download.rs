@@ -0,0 +1,55 @@
+use futures::stream::BoxStream;
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, User,
+};
+use revolt_models::v0::ExportFormat;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{http::ContentType, response::stream::TextStream, State};
+
+/// # Download Channel Export
+///
+/// Download the archive produced by a completed export.
+///
+/// The archive is streamed as it is read back, one rendered part at a time.
+#[openapi(tag = "Channel Information")]
+#[get("/<target>/exports/<export_id>/download")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    export_id: String,
+) -> Result<(ContentType, TextStream<BoxStream<'static, String>>)> {
+    let export = db.fetch_channel_export(&export_id).await?;
+    if export.channel != target.id || export.user != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    // Access may have been revoked since the export was requested
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ReadMessageHistory)?;
+
+    let title = match &channel {
+        Channel::Group { name, .. }
+        | Channel::TextChannel { name, .. }
+        | Channel::VoiceChannel { name, .. }
+        | Channel::ForumChannel { name, .. }
+        | Channel::ForumPost { name, .. } => name.clone(),
+        Channel::DirectMessage { .. } => "Direct Messages".to_string(),
+        Channel::SavedMessages { .. } => "Saved Notes".to_string(),
+    };
+
+    let archive = export.assemble(db.inner().clone(), &title)?;
+    Ok((
+        match export.format {
+            ExportFormat::Json => ContentType::JSON,
+            ExportFormat::Html => ContentType::HTML,
+            ExportFormat::PlainText => ContentType::Plain,
+        },
+        TextStream(archive),
+    ))
+}
@@ -0,0 +1,23 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Channel Export
+///
+/// Fetch the status of an export you requested.
+#[openapi(tag = "Channel Information")]
+#[get("/<target>/exports/<export_id>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    export_id: String,
+) -> Result<Json<v0::ChannelExport>> {
+    let export = db.fetch_channel_export(&export_id).await?;
+    if export.channel != target.id || export.user != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(export.into()))
+}
@@ -0,0 +1,42 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Unfollow Channel
+///
+/// Stop delivering messages from an announcement channel.
+///
+/// Either the announcement channel's or the receiving channel's managers may remove a follower.
+#[openapi(tag = "Channel Followers")]
+#[delete("/<target>/followers/<follower_id>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    follower_id: String,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let follower = db.fetch_channel_follower(&follower_id).await?;
+    if follower.channel != channel.id() {
+        return Err(create_error!(NotFound));
+    }
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    if !calculate_channel_permissions(&mut query)
+        .await
+        .has_channel_permission(ChannelPermission::ManageChannel)
+    {
+        let destination = db.fetch_channel(&follower.target).await?;
+        let mut query = DatabasePermissionQuery::new(db, &user).channel(&destination);
+        calculate_channel_permissions(&mut query)
+            .await
+            .throw_if_lacking_channel_permission(ChannelPermission::ManageWebhooks)?;
+    }
+
+    follower.delete(db).await.map(|_| EmptyResponse)
+}
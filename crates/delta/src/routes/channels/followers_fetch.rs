@@ -0,0 +1,34 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Followers
+///
+/// Fetch all channels following an announcement channel.
+#[openapi(tag = "Channel Followers")]
+#[get("/<target>/followers")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<Vec<v0::ChannelFollower>>> {
+    let channel = target.as_channel(db).await?;
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    Ok(Json(
+        db.fetch_channel_followers(&channel.id())
+            .await?
+            .into_iter()
+            .map(|follower| follower.into())
+            .collect(),
+    ))
+}
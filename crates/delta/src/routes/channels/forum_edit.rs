@@ -0,0 +1,82 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, PartialChannel, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Edit Forum
+///
+/// Edit the tags and default sort order of a forum channel.
+#[openapi(tag = "Forums")]
+#[patch("/<target>/forum", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataEditForum>,
+) -> Result<Json<v0::Channel>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut channel = target.as_channel(db).await?;
+    let existing_tags = if let Channel::ForumChannel { available_tags, .. } = &channel {
+        available_tags.clone()
+    } else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+
+    // Tags keep their id if it is known, otherwise they are created fresh
+    let available_tags = if let Some(tags) = data.available_tags {
+        let mut available_tags = Vec::with_capacity(tags.len());
+        for tag in tags {
+            let id = match tag.id {
+                Some(id) => {
+                    if !existing_tags.iter().any(|existing| existing.id == id) {
+                        return Err(create_error!(InvalidProperty));
+                    }
+
+                    id
+                }
+                None => Ulid::new().to_string(),
+            };
+
+            available_tags.push(v0::ForumTag {
+                id,
+                name: tag.name,
+                emoji: tag.emoji,
+            });
+        }
+
+        Some(available_tags)
+    } else {
+        None
+    };
+
+    channel
+        .update(
+            db,
+            PartialChannel {
+                available_tags,
+                default_sort_order: data.default_sort_order,
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    Ok(Json(channel.into()))
+}
@@ -1,9 +1,12 @@
+use revolt_database::{Database, RaidProtection};
+use revolt_models::v0;
 use revolt_quark::{
-    models::{Invite, User},
+    models::{Channel, User},
     perms, Db, Error, Permission, Ref, Result,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
+use validator::Validate;
 
 /// # Create Invite
 ///
@@ -11,17 +14,82 @@ use rocket::serde::json::Json;
 ///
 /// Channel must be a `TextChannel`.
 #[openapi(tag = "Channel Invites")]
-#[post("/<target>/invites")]
-pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<Invite>> {
+#[post("/<target>/invites", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    data: Option<Json<v0::DataCreateInvite>>,
+) -> Result<Json<v0::Invite>> {
+    let data = data.map(Json::into_inner).unwrap_or_default();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
     if user.bot.is_some() {
         return Err(Error::IsBot);
     }
 
     let channel = target.as_channel(db).await?;
-    perms(&user)
-        .channel(&channel)
+    channel.throw_if_timed_out(db, &user.id).await?;
+    let mut permissions = perms(&user).channel(&channel);
+    permissions
         .throw_permission_and_view_channel(db, Permission::InviteOthers)
         .await?;
 
-    Invite::create(db, &user, &channel).await.map(Json)
+    // New invites are paused while the server is locked down
+    if let Channel::TextChannel { server, .. }
+    | Channel::VoiceChannel { server, .. }
+    | Channel::ForumChannel { server, .. } = &channel
+    {
+        if RaidProtection::fetch(database, server)
+            .await
+            .map_err(Error::from_core)?
+            .is_locked_down()
+        {
+            return Err(Error::ServerLockedDown);
+        }
+    }
+
+    // Membership options only make sense for servers, and roles given on
+    // join would make a temporary membership permanent straight away
+    if (data.temporary || !data.roles.is_empty())
+        && (!matches!(
+            channel,
+            Channel::TextChannel { .. }
+                | Channel::VoiceChannel { .. }
+                | Channel::ForumChannel { .. }
+        ) || (data.temporary && !data.roles.is_empty()))
+    {
+        return Err(Error::InvalidOperation);
+    }
+
+    // We may only hand out roles we could assign ourselves
+    if !data.roles.is_empty() {
+        permissions
+            .throw_permission(db, Permission::AssignRoles)
+            .await?;
+
+        let our_ranking = permissions.get_member_rank().unwrap_or(i64::MIN);
+        let server = permissions.server.get().unwrap();
+        for role_id in &data.roles {
+            if let Some(role) = server.roles.get(role_id) {
+                if role.rank <= our_ranking {
+                    return Err(Error::NotElevated);
+                }
+            } else {
+                return Err(Error::InvalidRole);
+            }
+        }
+    }
+
+    let channel = database
+        .fetch_channel(channel.id())
+        .await
+        .map_err(Error::from_core)?;
+
+    revolt_database::Invite::create_channel_invite(database, user.id, &channel, data)
+        .await
+        .map(|invite| Json(invite.into()))
+        .map_err(Error::from_core)
 }
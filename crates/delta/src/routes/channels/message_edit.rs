@@ -1,13 +1,17 @@
-use revolt_models::v0::Component;
+use std::collections::HashSet;
+
+use revolt_database::{AutomodRule, AutomodSubject, AutomodVerdict, Database};
+use revolt_models::v0::{Component, RE_MENTION};
 use revolt_quark::{
     models::message::{PartialMessage, SendableEmbed},
-    models::{Message, User},
+    models::{Channel, Message, User},
     perms,
     types::january::Embed,
     Db, Error, Permission, Ref, Result, Timestamp,
 };
+use revolt_result::create_error;
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -32,6 +36,7 @@ pub struct DataEditMessage {
 #[patch("/<target>/messages/<msg>", data = "<edit>")]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     msg: Ref,
@@ -43,6 +48,7 @@ pub async fn req(
 
     // Ensure we have permissions to send a message
     let channel = target.as_channel(db).await?;
+    channel.throw_if_timed_out(db, &user.id).await?;
     let mut permissions = perms(&user).channel(&channel);
     permissions
         .throw_permission_and_view_channel(db, Permission::SendMessage)
@@ -59,6 +65,45 @@ pub async fn req(
 
     Message::validate_sum(&edit.content, edit.embeds.as_deref().unwrap_or_default())?;
 
+    // Run the new content through server automod
+    if let (
+        Some(content),
+        Channel::TextChannel { server, .. }
+        | Channel::VoiceChannel { server, .. }
+        | Channel::ForumPost { server, .. },
+    ) = (&edit.content, &channel)
+    {
+        let mentions: HashSet<&str> = RE_MENTION
+            .captures_iter(content)
+            .filter_map(|capture| capture.get(1))
+            .map(|mention| mention.as_str())
+            .collect();
+
+        match AutomodRule::enforce(
+            database,
+            server,
+            AutomodSubject {
+                channel: &message.channel,
+                author: &message.author,
+                content: Some(content),
+                mentions: mentions.len(),
+                attachments: &[],
+            },
+        )
+        .await
+        .map_err(Error::from_core)?
+        {
+            AutomodVerdict::Allow => {}
+            AutomodVerdict::Block { rule } => {
+                return Err(Error::from_core(create_error!(BlockedByAutomod { rule })));
+            }
+            AutomodVerdict::Delete { rule } => {
+                message.delete(db).await?;
+                return Err(Error::from_core(create_error!(BlockedByAutomod { rule })));
+            }
+        }
+    }
+
     message.edited = Some(Timestamp::now_utc());
     let mut partial = PartialMessage {
         edited: message.edited,
@@ -107,3 +152,83 @@ pub async fn req(
 
     Ok(Json(message))
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{AutomodRule, Channel, Member};
+    use revolt_models::v0::{self, AutomodAction, AutomodTrigger};
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn edit_runs_automod_in_voice_channel() {
+        let harness = TestHarness::new().await;
+        let (_, _, owner) = harness.new_user().await;
+        let (_, session, member) = harness.new_user().await;
+
+        let server = harness.new_server(&owner).await;
+        Member::create(&harness.db, &server, &member).await.unwrap();
+
+        let voice = Channel::VoiceChannel {
+            id: ulid::Ulid::new().to_string(),
+            server: server.id.clone(),
+            name: TestHarness::rand_string(),
+            description: None,
+            icon: None,
+            default_permissions: None,
+            role_permissions: Default::default(),
+            nsfw: false,
+        };
+
+        voice.create(&harness.db).await.unwrap();
+
+        AutomodRule {
+            id: ulid::Ulid::new().to_string(),
+            server: server.id.clone(),
+            name: "Banned words".to_string(),
+            enabled: true,
+            trigger: AutomodTrigger::Keyword {
+                keywords: vec!["forbidden".to_string()],
+                patterns: vec![],
+            },
+            actions: vec![AutomodAction::Block],
+            exempt_roles: vec![],
+            exempt_channels: vec![],
+        }
+        .create(&harness.db)
+        .await
+        .unwrap();
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/messages", voice.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "content": "hello" }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let message: v0::Message = response.into_json().await.unwrap();
+
+        let response = harness
+            .client
+            .patch(format!("/channels/{}/messages/{}", voice.id(), message.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "content": "forbidden" }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+        assert_eq!(
+            harness
+                .db
+                .fetch_message(&message.id)
+                .await
+                .unwrap()
+                .content
+                .as_deref(),
+            Some("hello")
+        );
+    }
+}
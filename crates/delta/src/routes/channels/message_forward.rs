@@ -0,0 +1,228 @@
+use revolt_database::{
+    util::{
+        idempotency::IdempotencyKey, permissions::DatabasePermissionQuery, reference::Reference,
+    },
+    Database, Message, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Forward Message
+///
+/// Forwards a message to another channel, keeping a reference to the original.
+#[openapi(tag = "Messaging")]
+#[post("/<target>/messages/<msg>/forward", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    msg: Reference,
+    data: Json<v0::DataMessageForward>,
+    idempotency: IdempotencyKey,
+) -> Result<Json<v0::Message>> {
+    let data = data.into_inner();
+
+    // Ensure we can read the original message
+    let source = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&source);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ReadMessageHistory)?;
+
+    let message = msg.as_message(db).await?;
+    if message.channel != source.id() {
+        return Err(create_error!(NotFound));
+    }
+
+    // Ensure we can send the copy into the destination
+    let destination = db.fetch_channel(&data.channel).await?;
+    destination.throw_if_timed_out(db, &user.id).await?;
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&destination);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    // Enforce slow mode unless we can manage messages
+    if !permissions.has_channel_permission(ChannelPermission::ManageMessages) {
+        destination.throw_if_in_slowmode(db, &user.id).await?;
+    }
+
+    if message.embeds.as_ref().is_some_and(|v| !v.is_empty()) {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::SendEmbeds)?;
+    }
+
+    if message.attachments.as_ref().is_some_and(|v| !v.is_empty()) {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::UploadFiles)?;
+    }
+
+    let author: v0::User = user.clone().into(Some(&user)).await;
+    Ok(Json(
+        Message::create_forward(
+            db,
+            &message,
+            &destination,
+            v0::MessageAuthor::User(&author),
+            idempotency,
+        )
+        .await?
+        .into(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{events::client::EventV1, Channel, Member};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn clear_forward_of_deleted_message() {
+        let mut harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+
+        let mut groups = vec![];
+        for _ in 0..2 {
+            groups.push(
+                Channel::create_group(
+                    &harness.db,
+                    v0::DataCreateGroup {
+                        name: TestHarness::rand_string(),
+                        ..Default::default()
+                    },
+                    user.id.to_string(),
+                )
+                .await
+                .unwrap(),
+            );
+        }
+
+        let (source, destination) = (groups[0].id(), groups[1].id());
+
+        let response = harness
+            .client
+            .post(format!("/channels/{source}/messages"))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "content": "Hello" }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let original: v0::Message = response.into_json().await.expect("`Message`");
+
+        let response = harness
+            .client
+            .post(format!(
+                "/channels/{source}/messages/{}/forward",
+                original.id
+            ))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "channel": destination }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let forward: v0::Message = response.into_json().await.expect("`Message`");
+        assert_eq!(forward.content.as_deref(), Some("Hello"));
+
+        let response = harness
+            .client
+            .delete(format!("/channels/{source}/messages/{}", original.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        drop(response);
+
+        let event = harness
+            .wait_for_event(&destination, |event| match event {
+                EventV1::MessageUpdate { id, .. } => id == &forward.id,
+                _ => false,
+            })
+            .await;
+
+        match event {
+            EventV1::MessageUpdate { data, clear, .. } => {
+                assert!(data.forwarded.is_some_and(|forwarded| forwarded.deleted));
+                assert!(clear.contains(&v0::FieldsMessage::Content));
+                assert!(clear.contains(&v0::FieldsMessage::Attachments));
+                assert!(clear.contains(&v0::FieldsMessage::Embeds));
+            }
+            _ => unreachable!(),
+        };
+
+        let forward = harness.db.fetch_message(&forward.id).await.unwrap();
+        assert!(forward.content.is_none());
+    }
+
+    #[rocket::async_test]
+    async fn fail_forward_in_slowmode() {
+        let harness = TestHarness::new().await;
+        let (_, _, owner) = harness.new_user().await;
+        let (_, session, user) = harness.new_user().await;
+
+        let channel_id = ulid::Ulid::new().to_string();
+        let server = harness
+            .new_server_with(&owner, |server| server.channels = vec![channel_id.clone()])
+            .await;
+
+        let channel = Channel::TextChannel {
+            id: channel_id.clone(),
+            server: server.id.to_string(),
+            name: TestHarness::rand_string(),
+            description: None,
+            icon: None,
+            last_message_id: None,
+            default_permissions: None,
+            role_permissions: Default::default(),
+            nsfw: false,
+            slowmode_seconds: 60,
+            announcement: false,
+        };
+
+        channel.create(&harness.db).await.unwrap();
+        Member::create(&harness.db, &server, &user).await.unwrap();
+
+        let group = Channel::create_group(
+            &harness.db,
+            v0::DataCreateGroup {
+                name: TestHarness::rand_string(),
+                ..Default::default()
+            },
+            user.id.to_string(),
+        )
+        .await
+        .unwrap();
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/messages", group.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "content": "Hello" }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let original: v0::Message = response.into_json().await.expect("`Message`");
+
+        for status in [Status::Ok, Status::TooManyRequests] {
+            let response = harness
+                .client
+                .post(format!(
+                    "/channels/{}/messages/{}/forward",
+                    group.id(),
+                    original.id
+                ))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .json(&json!({ "channel": channel_id }))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), status);
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Pin Message
+///
+/// Pin a message in its channel.
+#[openapi(tag = "Messaging")]
+#[post("/<target>/messages/<msg>/pin")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    msg: Reference,
+) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+
+    let mut message = msg.as_message(db).await?;
+    if message.channel != channel.id() {
+        return Err(create_error!(NotFound));
+    }
+
+    message.set_pinned(db, true).await.map(|_| EmptyResponse)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Channel, Member};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn pin_and_unpin() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+        let (_, other_session, other) = harness.new_user().await;
+
+        let server = harness.new_server(&owner).await;
+        Member::create(&harness.db, &server, &other).await.unwrap();
+
+        let channel = Channel::TextChannel {
+            id: ulid::Ulid::new().to_string(),
+            server: server.id.clone(),
+            name: TestHarness::rand_string(),
+            description: None,
+            icon: None,
+            last_message_id: None,
+            default_permissions: None,
+            role_permissions: Default::default(),
+            nsfw: false,
+            slowmode_seconds: 0,
+            announcement: false,
+        };
+
+        channel.create(&harness.db).await.unwrap();
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/messages", channel.id()))
+            .header(Header::new(
+                "x-session-token",
+                other_session.token.to_string(),
+            ))
+            .json(&json!({ "content": "Hello" }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let message: v0::Message = response.into_json().await.unwrap();
+        let path = format!("/channels/{}/messages/{}/pin", channel.id(), message.id);
+
+        // Members need permission to manage messages
+        let response = harness
+            .client
+            .post(path.clone())
+            .header(Header::new(
+                "x-session-token",
+                other_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+
+        let response = harness
+            .client
+            .post(path.clone())
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        assert!(harness.db.fetch_message(&message.id).await.unwrap().pinned);
+
+        let response = harness
+            .client
+            .delete(path)
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NoContent);
+        assert!(!harness.db.fetch_message(&message.id).await.unwrap().pinned);
+    }
+}
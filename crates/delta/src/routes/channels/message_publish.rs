@@ -0,0 +1,50 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Publish Message
+///
+/// Publish a message from an announcement channel to every channel following it.
+///
+/// Publishing someone else's message requires the Manage Messages permission.
+#[openapi(tag = "Channel Followers")]
+#[post("/<target>/messages/<msg>/publish")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    msg: Reference,
+) -> Result<Json<v0::Message>> {
+    let channel = target.as_channel(db).await?;
+    let server = if let Channel::TextChannel {
+        server,
+        announcement: true,
+        ..
+    } = &channel
+    {
+        db.fetch_server(server).await?
+    } else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut message = msg.as_message(db).await?;
+    if message.channel != channel.id() {
+        return Err(create_error!(NotFound));
+    }
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    if message.author != user.id {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageMessages)?;
+    }
+
+    message.publish(db, &server).await?;
+    Ok(Json(message.into()))
+}
@@ -1,13 +1,14 @@
+use revolt_database::ContentFilter;
 use revolt_quark::{
     models::{
         message::{
             BulkMessageResponse, MessageFilter, MessageQuery, MessageSort, MessageTimePeriod,
         },
-        User,
+        Channel, User,
     },
     perms, Db, Error, Permission, Ref, Result,
 };
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -45,6 +46,7 @@ pub struct OptionsQueryMessages {
 #[get("/<target>/messages?<options..>")]
 pub async fn req(
     db: &Db,
+    database: &State<revolt_database::Database>,
     user: User,
     target: Ref,
     options: OptionsQueryMessages,
@@ -72,7 +74,7 @@ pub async fn req(
         include_users,
     } = options;
 
-    let messages = db
+    let mut messages = db
         .fetch_messages(MessageQuery {
             filter: MessageFilter {
                 channel: Some(channel.id().to_string()),
@@ -91,6 +93,18 @@ pub async fn req(
         })
         .await?;
 
+    // Personal content filters only apply outside of servers
+    if matches!(
+        channel,
+        Channel::DirectMessage { .. } | Channel::Group { .. }
+    ) {
+        let filter = ContentFilter::fetch(database, &user.id)
+            .await
+            .map_err(Error::from_core)?;
+
+        messages.retain_mut(|message| message.apply_content_filter(&filter, &user));
+    }
+
     BulkMessageResponse::transform(db, Some(&channel), messages, include_users)
         .await
         .map(Json)
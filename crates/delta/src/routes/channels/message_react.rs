@@ -1,4 +1,9 @@
-use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Result};
+use revolt_database::{AutoRoleRule, Database};
+use revolt_quark::{
+    models::{Channel, User},
+    perms, Db, EmptyResponse, Permission, Ref, Result,
+};
+use rocket::State;
 
 /// # Add Reaction to Message
 ///
@@ -7,12 +12,14 @@ use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Resu
 #[put("/<target>/messages/<msg>/reactions/<emoji>")]
 pub async fn react_message(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     msg: Ref,
     emoji: Ref,
 ) -> Result<EmptyResponse> {
     let channel = target.as_channel(db).await?;
+    channel.throw_if_timed_out(db, &user.id).await?;
     perms(&user)
         .channel(&channel)
         .throw_permission_and_view_channel(db, Permission::React)
@@ -22,8 +29,17 @@ pub async fn react_message(
     let message = msg.as_message_in(db, channel.id()).await?;
 
     // Add the reaction
-    message
-        .add_reaction(db, &user, &emoji.id)
-        .await
-        .map(|_| EmptyResponse)
+    message.add_reaction(db, &user, &emoji.id).await?;
+
+    // Hand out any reaction roles, the reaction stands even if this fails
+    if let Channel::TextChannel { server, .. }
+    | Channel::VoiceChannel { server, .. }
+    | Channel::ForumPost { server, .. } = &channel
+    {
+        AutoRoleRule::apply_reaction(database, server, &message.id, &emoji.id, &user.id)
+            .await
+            .ok();
+    }
+
+    Ok(EmptyResponse)
 }
@@ -1,6 +1,7 @@
 use revolt_database::util::permissions::DatabasePermissionQuery;
 use revolt_database::{
-    util::idempotency::IdempotencyKey, util::reference::Reference, Database, User,
+    util::idempotency::IdempotencyKey, util::reference::Reference, Channel, ContentFilter,
+    Database, User,
 };
 use revolt_database::{Interactions, Message};
 use revolt_models::v0;
@@ -30,12 +31,30 @@ pub async fn message_send(
     })?;
 
     // Ensure we have permissions to send a message
-    let channel = target.as_channel(db).await?;
+    let mut channel = target.as_channel(db).await?;
+    channel.throw_if_timed_out(db, &user.id).await?;
 
     let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
     let permissions = calculate_channel_permissions(&mut query).await;
     permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
 
+    // Strangers may not message users who have opted out of hearing from them
+    if let Channel::DirectMessage { recipients, .. } = &channel {
+        if let Some(recipient) = recipients.iter().find(|id| *id != &user.id) {
+            if !ContentFilter::fetch(db, recipient)
+                .await?
+                .accepts_request_from(user.is_friends_with(recipient))
+            {
+                return Err(create_error!(NotFriends));
+            }
+        }
+    }
+
+    // Enforce slow mode unless we can manage messages
+    if !permissions.has_channel_permission(ChannelPermission::ManageMessages) {
+        channel.throw_if_in_slowmode(db, &user.id).await?;
+    }
+
     // Verify permissions for masquerade
     if let Some(masq) = &data.masquerade {
         permissions.throw_if_lacking_channel_permission(ChannelPermission::Masquerade)?;
@@ -61,6 +80,11 @@ pub async fn message_send(
         interactions.validate(db, &permissions).await?;
     }
 
+    // Replying to a message request accepts it
+    if channel.is_message_request_for(&user.id) {
+        channel.accept_message_request(db).await?;
+    }
+
     // Create the message
     let author: v0::User = user.clone().into(Some(&user)).await;
     Ok(Json(
@@ -5,6 +5,13 @@ mod channel_ack;
 mod channel_delete;
 mod channel_edit;
 mod channel_fetch;
+mod channel_follow;
+mod export_create;
+mod export_download;
+mod export_fetch;
+mod follower_delete;
+mod followers_fetch;
+mod forum_edit;
 mod group_add_member;
 mod group_create;
 mod group_remove_member;
@@ -15,14 +22,24 @@ mod message_clear_reactions;
 mod message_delete;
 mod message_edit;
 mod message_fetch;
+mod message_forward;
 mod message_interactions;
+mod message_pin;
+mod message_publish;
 mod message_query;
 mod message_react;
 mod message_search;
 mod message_send;
+mod message_unpin;
 mod message_unreact;
 mod permissions_set;
 mod permissions_set_default;
+mod post_create;
+mod post_edit;
+mod posts_fetch;
+mod request_accept;
+mod request_block;
+mod request_decline;
 mod stream_message;
 mod voice_join;
 mod webhook_create;
@@ -35,17 +52,27 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         members_fetch::req,
         channel_delete::req,
         channel_edit::req,
+        export_create::req,
+        export_fetch::req,
+        export_download::req,
         invite_create::req,
         message_send::message_send,
         message_query::req,
         message_search::req,
         message_fetch::req,
+        message_forward::req,
+        message_publish::req,
+        message_pin::req,
+        message_unpin::req,
         message_edit::req,
         message_bulk_delete::req,
         message_delete::req,
         group_create::create_group,
         group_add_member::req,
         group_remove_member::req,
+        request_accept::req,
+        request_decline::req,
+        request_block::req,
         voice_join::req,
         permissions_set::req,
         permissions_set_default::req,
@@ -54,6 +81,13 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         message_clear_reactions::clear_reactions,
         webhook_create::req,
         webhook_fetch_all::req,
+        channel_follow::req,
+        followers_fetch::req,
+        follower_delete::req,
+        forum_edit::req,
+        posts_fetch::req,
+        post_create::req,
+        post_edit::req,
         message_interactions::req,
         stream_message::req,
     ]
@@ -1,4 +1,8 @@
-use rocket::serde::json::Json;
+use std::collections::HashMap;
+
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
+use rocket::{serde::json::Json, State};
 use serde::Deserialize;
 
 use revolt_quark::{
@@ -22,10 +26,12 @@ pub struct Data {
 #[put("/<target>/permissions/<role_id>", data = "<data>", rank = 2)]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     role_id: String,
     data: Json<Data>,
+    reason: AuditLogReason,
 ) -> Result<Json<Channel>> {
     let mut channel = target.as_channel(db).await?;
     let mut permissions = perms(&user).channel(&channel);
@@ -45,10 +51,41 @@ pub async fn req(
                 .throw_permission_override(db, current_value, data.permissions)
                 .await?;
 
+            let before = match &channel {
+                Channel::TextChannel {
+                    role_permissions, ..
+                }
+                | Channel::VoiceChannel {
+                    role_permissions, ..
+                }
+                | Channel::ForumChannel {
+                    role_permissions, ..
+                } => role_permissions.get(&role_id).copied(),
+                _ => None,
+            };
+
+            let server_id = server.id.clone();
+            let mut server = server.clone();
+            server.desync_channel(db, &mut channel).await?;
+
             channel
                 .set_role_permission(db, &role_id, data.permissions.into())
                 .await?;
 
+            AuditLogEntry::record(
+                database,
+                server_id,
+                user.id,
+                AuditLogAction::ChannelPermissionsUpdate,
+                Some(channel.id().to_string()),
+                reason.into_inner(),
+                AuditLogEntry::diff(
+                    &HashMap::from([(&role_id, before)]),
+                    &HashMap::from([(&role_id, Some(data.permissions.into()))]),
+                ),
+            )
+            .await;
+
             Ok(Json(channel))
         } else {
             Err(Error::NotFound)
@@ -1,4 +1,6 @@
-use rocket::serde::json::Json;
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
+use rocket::{serde::json::Json, State};
 use serde::Deserialize;
 
 use revolt_quark::{
@@ -29,9 +31,11 @@ pub enum DataDefaultChannelPermissions {
 #[put("/<target>/permissions/default", data = "<data>", rank = 1)]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     data: Json<DataDefaultChannelPermissions>,
+    reason: AuditLogReason,
 ) -> Result<Json<Channel>> {
     let data = data.into_inner();
 
@@ -59,10 +63,17 @@ pub async fn req(
             }
         }
         Channel::TextChannel {
+            server,
             default_permissions,
             ..
         }
         | Channel::VoiceChannel {
+            server,
+            default_permissions,
+            ..
+        }
+        | Channel::ForumChannel {
+            server,
             default_permissions,
             ..
         } => {
@@ -74,6 +85,13 @@ pub async fn req(
                 )
                 .await?;
 
+                let server_id = server.clone();
+                let changes = AuditLogEntry::diff(default_permissions, &Some(permissions.into()));
+
+                if let Some(mut server) = perm.server.get().cloned() {
+                    server.desync_channel(db, &mut channel).await?;
+                }
+
                 channel
                     .update(
                         db,
@@ -84,6 +102,17 @@ pub async fn req(
                         vec![],
                     )
                     .await?;
+
+                AuditLogEntry::record(
+                    database,
+                    server_id,
+                    user.id.clone(),
+                    AuditLogAction::ChannelPermissionsUpdate,
+                    Some(channel.id().to_string()),
+                    reason.into_inner(),
+                    changes,
+                )
+                .await;
             } else {
                 return Err(Error::InvalidOperation);
             }
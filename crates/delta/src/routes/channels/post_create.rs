@@ -0,0 +1,99 @@
+use revolt_database::{
+    util::{
+        idempotency::IdempotencyKey, permissions::DatabasePermissionQuery, reference::Reference,
+    },
+    Channel, Database, Message, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Post
+///
+/// Create a new post in a forum channel along with its starter message.
+#[openapi(tag = "Forums")]
+#[post("/<target>/posts", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataCreateForumPost>,
+    idempotency: IdempotencyKey,
+) -> Result<Json<v0::ForumPostResponse>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let forum = target.as_channel(db).await?;
+    let server = if let Channel::ForumChannel { server, .. } = &forum {
+        server.clone()
+    } else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    forum.throw_if_timed_out(db, &user.id).await?;
+    forum.throw_if_unknown_forum_tags(&data.tags)?;
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&forum);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::SendMessage)?;
+
+    // Check permissions for the starter message
+    if data.message.masquerade.is_some() {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::Masquerade)?;
+    }
+
+    if data.message.embeds.as_ref().is_some_and(|v| !v.is_empty()) {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::SendEmbeds)?;
+    }
+
+    if data
+        .message
+        .attachments
+        .as_ref()
+        .is_some_and(|v| !v.is_empty())
+    {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::UploadFiles)?;
+    }
+
+    let post = Channel::ForumPost {
+        id: Ulid::new().to_string(),
+        server,
+        forum: forum.id(),
+        author: user.id.clone(),
+        name: data.title,
+        tags: data.tags,
+        last_message_id: None,
+    };
+
+    post.create(db).await?;
+
+    let author: v0::User = user.clone().into(Some(&user)).await;
+    let message = match Message::create_from_api(
+        db,
+        post.clone(),
+        data.message,
+        v0::MessageAuthor::User(&author),
+        idempotency,
+        true,
+    )
+    .await
+    {
+        Ok(message) => message,
+        Err(error) => {
+            post.delete(db).await.ok();
+            return Err(error);
+        }
+    };
+
+    Ok(Json(v0::ForumPostResponse {
+        post: post.into(),
+        message: message.into(),
+    }))
+}
@@ -0,0 +1,62 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, PartialChannel, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Post
+///
+/// Edit the title or tags of a forum post.
+///
+/// Editing someone else's post requires the Manage Channel permission.
+#[openapi(tag = "Forums")]
+#[patch("/<target>/post", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataEditForumPost>,
+) -> Result<Json<v0::Channel>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut post = target.as_channel(db).await?;
+    let (forum, author) = if let Channel::ForumPost { forum, author, .. } = &post {
+        (db.fetch_channel(forum).await?, author.clone())
+    } else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&forum);
+    let permissions = calculate_channel_permissions(&mut query).await;
+    permissions.throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    if author != user.id {
+        permissions.throw_if_lacking_channel_permission(ChannelPermission::ManageChannel)?;
+    }
+
+    if let Some(tags) = &data.tags {
+        forum.throw_if_unknown_forum_tags(tags)?;
+    }
+
+    post.update(
+        db,
+        PartialChannel {
+            name: data.title,
+            tags: data.tags,
+            ..Default::default()
+        },
+        vec![],
+    )
+    .await?;
+
+    Ok(Json(post.into()))
+}
@@ -0,0 +1,85 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Channel, Database, ForumPostQuery, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, FromFormField, State};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Post Sort
+#[derive(Serialize, Deserialize, JsonSchema, FromFormField)]
+pub enum PostSort {
+    /// Most recently active posts first
+    LatestActivity,
+    /// Most recently created posts first
+    CreationDate,
+}
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsFetchPosts {
+    /// Maximum number of posts to fetch
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<i64>,
+    /// Post id before which posts should be fetched, relative to the sort order
+    #[validate(length(min = 26, max = 26))]
+    before: Option<String>,
+    /// Only fetch posts with this tag applied
+    tag: Option<String>,
+    /// Post sort order, defaults to the forum's sort order
+    sort: Option<PostSort>,
+}
+
+/// # Fetch Posts
+///
+/// Fetch posts from a forum channel.
+#[openapi(tag = "Forums")]
+#[get("/<target>/posts?<options..>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    options: OptionsFetchPosts,
+) -> Result<Json<Vec<v0::Channel>>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let channel = target.as_channel(db).await?;
+    let default_sort_order = if let Channel::ForumChannel {
+        default_sort_order, ..
+    } = &channel
+    {
+        default_sort_order.clone()
+    } else {
+        return Err(create_error!(InvalidOperation));
+    };
+
+    let mut query = DatabasePermissionQuery::new(db, &user).channel(&channel);
+    calculate_channel_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let posts = db
+        .fetch_forum_posts(
+            &channel.id(),
+            ForumPostQuery {
+                limit: options.limit.unwrap_or(50),
+                before: options.before,
+                tag: options.tag,
+                sort: match options.sort {
+                    Some(PostSort::LatestActivity) => v0::ForumSortOrder::LatestActivity,
+                    Some(PostSort::CreationDate) => v0::ForumSortOrder::CreationDate,
+                    None => default_sort_order,
+                },
+            },
+        )
+        .await?;
+
+    Ok(Json(posts.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,21 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Accept Message Request
+///
+/// Accept a message request, moving the conversation into your direct messages.
+#[openapi(tag = "Direct Messaging")]
+#[put("/<target>/request")]
+pub async fn req(db: &State<Database>, user: User, target: Reference) -> Result<EmptyResponse> {
+    let mut channel = target.as_channel(db).await?;
+    if !channel.is_message_request_for(&user.id) {
+        return Err(create_error!(NotFound));
+    }
+
+    channel
+        .accept_message_request(db)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -0,0 +1,30 @@
+use revolt_database::{util::reference::Reference, Channel, Database, User};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Block Message Request
+///
+/// Decline a message request and block the user who sent it.
+#[openapi(tag = "Direct Messaging")]
+#[put("/<target>/request/block")]
+pub async fn req(db: &State<Database>, mut user: User, target: Reference) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    if !channel.is_message_request_for(&user.id) {
+        return Err(create_error!(NotFound));
+    }
+
+    let Channel::DirectMessage {
+        requested_by: Some(requested_by),
+        ..
+    } = &channel
+    else {
+        return Err(create_error!(NotFound));
+    };
+
+    let mut requester = db.fetch_user(requested_by).await?;
+    channel.delete(db).await?;
+    user.block_user(db, &mut requester)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -0,0 +1,18 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Decline Message Request
+///
+/// Decline a message request, deleting the conversation.
+#[openapi(tag = "Direct Messaging")]
+#[delete("/<target>/request")]
+pub async fn req(db: &State<Database>, user: User, target: Reference) -> Result<EmptyResponse> {
+    let channel = target.as_channel(db).await?;
+    if !channel.is_message_request_for(&user.id) {
+        return Err(create_error!(NotFound));
+    }
+
+    channel.delete(db).await.map(|_| EmptyResponse)
+}
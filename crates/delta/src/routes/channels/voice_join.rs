@@ -22,6 +22,7 @@ pub struct CreateVoiceUserResponse {
 #[post("/<target>/join_call")]
 pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUserResponse>> {
     let channel = target.as_channel(db).await?;
+    channel.throw_if_timed_out(db, &user.id).await?;
     let mut permissions = perms(&user).channel(&channel);
 
     permissions
@@ -33,9 +34,10 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<CreateVoiceUse
     }
 
     match channel {
-        Channel::SavedMessages { .. } | Channel::TextChannel { .. } => {
-            return Err(Error::CannotJoinCall)
-        }
+        Channel::SavedMessages { .. }
+        | Channel::TextChannel { .. }
+        | Channel::ForumChannel { .. }
+        | Channel::ForumPost { .. } => return Err(Error::CannotJoinCall),
         _ => {}
     }
 
@@ -1,6 +1,9 @@
 use revolt_database::{
-    util::{permissions::DatabasePermissionQuery, reference::Reference},
-    Channel, Database, User, Webhook,
+    util::{
+        audit_log_reason::AuditLogReason, permissions::DatabasePermissionQuery,
+        reference::Reference,
+    },
+    AuditLogEntry, Channel, Database, User, Webhook,
 };
 use revolt_models::v0;
 use revolt_permissions::{
@@ -21,6 +24,7 @@ pub async fn req(
     user: User,
     target: Reference,
     data: Json<v0::CreateWebhookBody>,
+    reason: AuditLogReason,
 ) -> Result<Json<v0::Webhook>> {
     let data = data.into_inner();
     data.validate().map_err(|error| {
@@ -61,5 +65,18 @@ pub async fn req(
 
     webhook.create(db).await?;
 
+    if let Channel::TextChannel { server, .. } = &channel {
+        AuditLogEntry::record(
+            db,
+            server.clone(),
+            user.id,
+            v0::AuditLogAction::WebhookCreate,
+            Some(webhook.id.clone()),
+            reason.into_inner(),
+            None,
+        )
+        .await;
+    }
+
     Ok(Json(webhook.into()))
 }
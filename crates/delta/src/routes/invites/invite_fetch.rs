@@ -87,6 +87,13 @@ pub async fn req(db: &Db, target: Ref) -> Result<Json<InviteResponse>> {
                     name,
                     description,
                     ..
+                }
+                | Channel::ForumChannel {
+                    id,
+                    server,
+                    name,
+                    description,
+                    ..
                 } => {
                     let server = db.fetch_server(&server).await?;
 
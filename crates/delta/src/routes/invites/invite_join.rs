@@ -1,10 +1,16 @@
+use revolt_database::{AutoRoleRule, Database, Invite, RaidProtection, ServerScreening};
 use revolt_quark::{
-    models::{Channel, Invite, Server, User},
+    authifier::{models::EmailVerification, Authifier},
+    models::{
+        server_member::{PartialMember, RemovalIntention},
+        Channel, Server, User,
+    },
     variables::delta::MAX_SERVER_COUNT,
     Db, Error, Ref, Result,
 };
+use revolt_result::ErrorType;
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::Serialize;
 
 /// # Join Response
@@ -24,7 +30,13 @@ pub enum InviteJoinResponse {
 /// Join an invite by its ID.
 #[openapi(tag = "Invites")]
 #[post("/<target>")]
-pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<InviteJoinResponse>> {
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    authifier: &State<Authifier>,
+    user: User,
+    target: Ref,
+) -> Result<Json<InviteJoinResponse>> {
     if user.bot.is_some() {
         return Err(Error::IsBot);
     }
@@ -35,13 +47,189 @@ pub async fn req(db: &Db, user: User, target: Ref) -> Result<Json<InviteJoinResp
         });
     }
 
-    let invite = target.as_invite(db).await?;
+    let invite = Invite::find(database, &target.id)
+        .await
+        .map_err(|_| Error::NotFound)?;
+
     match &invite {
-        Invite::Server { server, .. } => {
+        Invite::Server {
+            server,
+            temporary,
+            roles,
+            ..
+        } => {
             let server = db.fetch_server(server).await?;
-            let channels = server.create_member(db, user, None).await?;
+            let mut protection = RaidProtection::fetch(database, &server.id)
+                .await
+                .map_err(Error::from_core)?;
+
+            if protection.is_account_too_new(&user.id) {
+                return Err(Error::AccountTooNew);
+            }
+
+            if protection.requires_verified_email()
+                && !authifier
+                    .database
+                    .find_account(&user.id)
+                    .await
+                    .is_ok_and(|account| {
+                        matches!(account.verification, EmailVerification::Verified)
+                    })
+            {
+                return Err(Error::VerifiedEmailRequired);
+            }
+
+            let screening = ServerScreening::fetch(database, &server.id)
+                .await
+                .map_err(Error::from_core)?;
+
+            if db.fetch_member(&server.id, &user.id).await.is_ok() {
+                return Err(Error::AlreadyInServer);
+            }
+
+            // Expired and used up invites are treated as if they no longer exist
+            if invite.is_expired() || invite.is_used_up() {
+                return Err(Error::NotFound);
+            }
+
+            // Roles come from both the invite and the server's auto role rules,
+            // the latter are held back until screening has been passed and are
+            // never given to temporary members as they would keep them around
+            let mut member_roles = roles.clone();
+            if !screening.enabled && !*temporary {
+                let server = database
+                    .fetch_server(&server.id)
+                    .await
+                    .map_err(Error::from_core)?;
+
+                for role in AutoRoleRule::join_roles(database, &server)
+                    .await
+                    .map_err(Error::from_core)?
+                {
+                    if !member_roles.contains(&role) {
+                        member_roles.push(role);
+                    }
+                }
+            }
+
+            member_roles.retain(|role| server.roles.contains_key(role));
+
+            let partial = PartialMember {
+                roles: Some(member_roles),
+                temporary: Some(*temporary),
+                pending: screening.enabled.then(Default::default),
+                ..Default::default()
+            };
+
+            let user_id = user.id.clone();
+            let channels = server.create_member(db, user, None, partial).await?;
+
+            // Only count the use once the member exists, backing out if someone took the last one
+            if let Err(error) = invite.record_use(database, &user_id).await {
+                if let Ok(member) = db.fetch_member(&server.id, &user_id).await {
+                    server
+                        .remove_member(db, member, RemovalIntention::Leave, true)
+                        .await
+                        .ok();
+                }
+
+                return Err(match error.error_type {
+                    ErrorType::NotFound => Error::NotFound,
+                    _ => Error::from_core(error),
+                });
+            }
+
+            protection.record_join(database).await.ok();
             Ok(Json(InviteJoinResponse::Server { channels, server }))
         }
         _ => unreachable!(),
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use rocket::http::{Header, Status};
+    use serde_json::{json, Value};
+
+    #[rocket::async_test]
+    async fn failed_join_keeps_invite_use() {
+        let harness = TestHarness::new().await;
+        let (_, session, _) = harness.new_user().await;
+        let (_, banned_session, banned) = harness.new_user().await;
+        let (_, joiner_session, _) = harness.new_user().await;
+        let (_, late_session, _) = harness.new_user().await;
+
+        let response = harness
+            .client
+            .post("/servers/create")
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "name": TestHarness::rand_string() }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let created: Value = response.into_json().await.unwrap();
+        let server_id = created["server"]["_id"].as_str().unwrap().to_string();
+        let channel_id = created["channels"][0]["_id"].as_str().unwrap().to_string();
+
+        let response = harness
+            .client
+            .post(format!("/channels/{channel_id}/invites"))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "max_uses": 1 }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let invite: Value = response.into_json().await.unwrap();
+        let code = invite["_id"].as_str().unwrap().to_string();
+
+        let response = harness
+            .client
+            .put(format!("/servers/{server_id}/bans/{}", banned.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({}))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        // A join which fails must not use up the invite
+        let response = harness
+            .client
+            .post(format!("/invites/{code}"))
+            .header(Header::new(
+                "x-session-token",
+                banned_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+
+        let response = harness
+            .client
+            .post(format!("/invites/{code}"))
+            .header(Header::new(
+                "x-session-token",
+                joiner_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let response = harness
+            .client
+            .post(format!("/invites/{code}"))
+            .header(Header::new(
+                "x-session-token",
+                late_session.token.to_string(),
+            ))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::NotFound);
+    }
+}
@@ -0,0 +1,41 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database, Invite, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Invite Uses
+///
+/// Fetch everyone who joined through an invite.
+#[openapi(tag = "Invites")]
+#[get("/<target>/uses")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<Vec<v0::InviteUse>>> {
+    let invite = db.fetch_invite(&target.id).await?;
+
+    if user.id != invite.creator() {
+        match &invite {
+            Invite::Server { server, .. } => {
+                let server = db.fetch_server(server).await?;
+                let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+                calculate_server_permissions(&mut query)
+                    .await
+                    .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+            }
+            Invite::Group { .. } => return Err(create_error!(NotFound)),
+        }
+    }
+
+    db.fetch_invite_uses(invite.code()).await.map(|uses| {
+        Json(
+            uses.into_iter()
+                .map(|invite_use| invite_use.into())
+                .collect(),
+        )
+    })
+}
@@ -4,7 +4,13 @@ use rocket::Route;
 mod invite_delete;
 mod invite_fetch;
 mod invite_join;
+mod invite_uses;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
-    openapi_get_routes_spec![invite_fetch::req, invite_join::req, invite_delete::req]
+    openapi_get_routes_spec![
+        invite_fetch::req,
+        invite_join::req,
+        invite_delete::req,
+        invite_uses::req
+    ]
 }
@@ -17,6 +17,7 @@ mod safety;
 mod servers;
 mod session;
 mod sync;
+mod templates;
 mod users;
 mod webhooks;
 
@@ -34,6 +35,7 @@ pub fn mount(mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/channels" => channels::routes(),
             "/servers" => servers::routes(),
             "/invites" => invites::routes(),
+            "/templates" => templates::routes(),
             "/custom" => customisation::routes(),
             "/safety" => safety::routes(),
             "/auth/account" => rocket_authifier::routes::account::routes(),
@@ -57,6 +59,7 @@ pub fn mount(mut rocket: Rocket<Build>) -> Rocket<Build> {
             "/channels" => channels::routes(),
             "/servers" => servers::routes(),
             "/invites" => invites::routes(),
+            "/templates" => templates::routes(),
             "/custom" => customisation::routes(),
             "/safety" => safety::routes(),
             "/auth/account" => rocket_authifier::routes::account::routes(),
@@ -119,6 +122,8 @@ fn custom_openapi_spec() -> OpenApi {
               "Groups",
               "Voice",
               "Webhooks",
+              "Channel Followers",
+              "Forums",
             ]
           },
           {
@@ -126,7 +131,13 @@ fn custom_openapi_spec() -> OpenApi {
             "tags": [
               "Server Information",
               "Server Members",
-              "Server Permissions"
+              "Server Permissions",
+              "Server Automod",
+              "Server Audit Log",
+              "Server Raid Protection",
+              "Server Screening",
+              "Server Templates",
+              "Server Events"
             ]
           },
           {
@@ -286,6 +297,26 @@ fn custom_openapi_spec() -> OpenApi {
                 description: Some("Manage permissions for servers".to_owned()),
                 ..Default::default()
             },
+            Tag {
+                name: "Server Automod".to_owned(),
+                description: Some("Configure automated moderation rules".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Server Audit Log".to_owned(),
+                description: Some("Review moderation and configuration changes".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Server Raid Protection".to_owned(),
+                description: Some("Limit who can join and lock servers down".to_owned()),
+                ..Default::default()
+            },
+            Tag {
+                name: "Server Screening".to_owned(),
+                description: Some("Hold new members back until they accept the rules".to_owned()),
+                ..Default::default()
+            },
             Tag {
                 name: "Invites".to_owned(),
                 description: Some("View, join and delete invites".to_owned()),
@@ -0,0 +1,32 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Appeal Strike
+///
+/// Ask the moderation team to reconsider a strike on your account.
+#[openapi(tag = "User Safety")]
+#[post("/strikes/<target>/appeal", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataAppealStrike>,
+) -> Result<Json<v0::AccountStrike>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let mut strike = db.fetch_strike(&target.id).await?;
+    if strike.user != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    strike.submit_appeal(db, data.content).await?;
+    Ok(Json(strike.into()))
+}
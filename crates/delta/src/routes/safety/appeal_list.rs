@@ -0,0 +1,23 @@
+use revolt_database::{Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Pending Appeals
+///
+/// Fetch strikes with an appeal waiting for review, oldest first.
+#[openapi(tag = "User Safety")]
+#[get("/appeals")]
+pub async fn req(db: &State<Database>, user: User) -> Result<Json<Vec<v0::AccountStrike>>> {
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    Ok(Json(
+        db.fetch_strikes_pending_appeal()
+            .await?
+            .into_iter()
+            .map(|strike| strike.into())
+            .collect(),
+    ))
+}
@@ -0,0 +1,35 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Respond to Appeal
+///
+/// Accept or reject an appeal, accepting it lifts the strike.
+#[openapi(tag = "User Safety")]
+#[put("/strikes/<target>/appeal", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataRespondAppeal>,
+) -> Result<Json<v0::AccountStrike>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    let mut strike = db.fetch_strike(&target.id).await?;
+    strike
+        .respond_to_appeal(db, data.accept, data.response)
+        .await?;
+
+    Ok(Json(strike.into()))
+}
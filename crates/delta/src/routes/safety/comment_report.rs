@@ -0,0 +1,40 @@
+use revolt_quark::models::report::ReportComment;
+use revolt_quark::models::User;
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use validator::Validate;
+
+/// # Comment Data
+#[derive(Validate, Deserialize, JsonSchema)]
+pub struct DataCommentReport {
+    /// Comment content
+    #[validate(length(min = 1, max = 2000))]
+    content: String,
+}
+
+/// # Comment on Report
+///
+/// Leave a comment on a report for other moderators.
+#[openapi(tag = "User Safety")]
+#[post("/reports/<id>/comments", data = "<data>")]
+pub async fn comment_report(
+    db: &Db,
+    user: User,
+    id: String,
+    data: Json<DataCommentReport>,
+) -> Result<Json<ReportComment>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let mut report = db.fetch_report(&id).await?;
+    report
+        .add_comment(db, user.id, data.content)
+        .await
+        .map(Json)
+}
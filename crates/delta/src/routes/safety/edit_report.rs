@@ -0,0 +1,62 @@
+use revolt_quark::models::report::{FieldsReport, PartialReport};
+use revolt_quark::models::{Report, User};
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use validator::Validate;
+
+/// # Report Changes
+#[derive(Validate, Deserialize, JsonSchema)]
+pub struct DataEditReport {
+    /// Moderator to assign this report to
+    #[validate(length(min = 26, max = 26))]
+    assignee_id: Option<String>,
+    /// Internal notes about this report
+    #[validate(length(max = 2000))]
+    notes: Option<String>,
+    /// Fields to remove from report object
+    #[validate(length(min = 1))]
+    remove: Option<Vec<FieldsReport>>,
+}
+
+/// # Edit Report
+///
+/// Assign a report or change its internal notes.
+#[openapi(tag = "User Safety")]
+#[patch("/reports/<id>", data = "<data>")]
+pub async fn edit_report(
+    db: &Db,
+    user: User,
+    id: String,
+    data: Json<DataEditReport>,
+) -> Result<Json<Report>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    // Reports may only be assigned to other moderators
+    if let Some(assignee_id) = &data.assignee_id {
+        if !db.fetch_user(assignee_id).await?.privileged {
+            return Err(Error::InvalidOperation);
+        }
+    }
+
+    let mut report = db.fetch_report(&id).await?;
+    report
+        .update(
+            db,
+            PartialReport {
+                assignee_id: data.assignee_id,
+                notes: data.notes,
+                ..Default::default()
+            },
+            data.remove.unwrap_or_default(),
+        )
+        .await?;
+
+    Ok(Json(report))
+}
@@ -0,0 +1,58 @@
+use revolt_quark::models::report::{ReportQuery, ReportStatusString};
+use revolt_quark::models::{Report, User};
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsFetchReports {
+    /// Only include reports with this status
+    status: Option<ReportStatusString>,
+    /// Only include reports created by this user
+    #[validate(length(min = 26, max = 26))]
+    author_id: Option<String>,
+    /// Only include reports against this piece of content
+    #[validate(length(min = 26, max = 26))]
+    content_id: Option<String>,
+    /// Only include reports assigned to this moderator
+    #[validate(length(min = 26, max = 26))]
+    assignee_id: Option<String>,
+    /// Report id before which reports should be fetched
+    #[validate(length(min = 26, max = 26))]
+    before: Option<String>,
+    /// Maximum number of reports to fetch
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<i64>,
+}
+
+/// # Fetch Reports
+///
+/// Fetch reports in the moderation queue, newest first.
+#[openapi(tag = "User Safety")]
+#[get("/reports?<options..>")]
+pub async fn fetch_reports(
+    db: &Db,
+    user: User,
+    options: OptionsFetchReports,
+) -> Result<Json<Vec<Report>>> {
+    options
+        .validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    db.fetch_reports(ReportQuery {
+        status: options.status,
+        author_id: options.author_id,
+        content_id: options.content_id,
+        assignee_id: options.assignee_id,
+        before: options.before,
+        limit: options.limit.unwrap_or(50),
+    })
+    .await
+    .map(Json)
+}
@@ -0,0 +1,17 @@
+use revolt_quark::models::{Snapshot, User};
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+
+/// # Fetch Report Snapshots
+///
+/// Fetch the content snapshots captured when a report was made.
+#[openapi(tag = "User Safety")]
+#[get("/reports/<id>/snapshots")]
+pub async fn fetch_snapshots(db: &Db, user: User, id: String) -> Result<Json<Vec<Snapshot>>> {
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let report = db.fetch_report(&id).await?;
+    db.fetch_snapshots(&report.id).await.map(Json)
+}
@@ -1,11 +1,41 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod appeal_create;
+mod appeal_list;
+mod appeal_respond;
+mod comment_report;
+mod edit_report;
+mod fetch_report;
+mod fetch_reports;
+mod fetch_snapshots;
+mod reject_report;
 mod report_content;
+mod resolve_report;
+mod standing_fetch;
+mod standing_fetch_user;
+mod strike_create;
+mod strike_lift;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
         // Reports
         report_content::report_content,
+        // Moderation
+        fetch_reports::fetch_reports,
+        fetch_report::fetch_report,
+        fetch_snapshots::fetch_snapshots,
+        edit_report::edit_report,
+        comment_report::comment_report,
+        resolve_report::resolve_report,
+        reject_report::reject_report,
+        // Strikes
+        standing_fetch::req,
+        standing_fetch_user::req,
+        strike_create::req,
+        strike_lift::req,
+        appeal_create::req,
+        appeal_respond::req,
+        appeal_list::req,
     ]
 }
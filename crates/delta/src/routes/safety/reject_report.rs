@@ -0,0 +1,47 @@
+use revolt_quark::models::report::ReportStatus;
+use revolt_quark::models::{Report, User};
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+use serde::Deserialize;
+use validator::Validate;
+
+/// # Rejection Data
+#[derive(Validate, Deserialize, JsonSchema)]
+pub struct DataRejectReport {
+    /// Reason shown to the reporter
+    #[validate(length(min = 1, max = 1000))]
+    rejection_reason: String,
+}
+
+/// # Reject Report
+///
+/// Close a report without taking action, the reporter is notified.
+#[openapi(tag = "User Safety")]
+#[post("/reports/<id>/reject", data = "<data>")]
+pub async fn reject_report(
+    db: &Db,
+    user: User,
+    id: String,
+    data: Json<DataRejectReport>,
+) -> Result<Json<Report>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    let mut report = db.fetch_report(&id).await?;
+    report
+        .close(
+            db,
+            ReportStatus::Rejected {
+                rejection_reason: data.rejection_reason,
+                closed_at: None,
+            },
+        )
+        .await?;
+
+    Ok(Json(report))
+}
@@ -0,0 +1,335 @@
+use revolt_database::{events::client::EventV1, AccountStrike, Database};
+use revolt_models::v0::StrikeAction;
+use revolt_quark::models::report::{ReportStatus, ReportedContent};
+use revolt_quark::models::server_member::MemberCompositeKey;
+use revolt_quark::models::{Member, Message, Report, Server, User};
+use revolt_quark::{Db, Error, Result};
+use rocket::serde::json::Json;
+use rocket::State;
+use serde::Deserialize;
+use validator::Validate;
+
+/// Action to take against reported content
+#[derive(Deserialize, JsonSchema, Debug, Clone, PartialEq)]
+#[serde(tag = "type")]
+pub enum ReportAction {
+    /// Delete the reported message
+    DeleteContent,
+    /// Issue a strike suspending the user responsible for the content
+    SuspendUser,
+    /// Ban the user responsible for the content from a server
+    BanFromServer {
+        /// Id of the server
+        server: String,
+    },
+}
+
+/// # Resolution Data
+#[derive(Validate, Deserialize, JsonSchema)]
+pub struct DataResolveReport {
+    /// Actions to take, each may only be given once
+    #[validate(length(max = 3))]
+    #[serde(default)]
+    actions: Vec<ReportAction>,
+}
+
+/// Everything needed to carry out an action, fetched up front
+enum PreparedAction {
+    DeleteContent(Message),
+    SuspendUser(String),
+    BanFromServer(Server, String, Option<Member>),
+}
+
+/// Change made by an action, kept around in case it has to be taken back
+enum AppliedAction {
+    Suspension(AccountStrike),
+    Ban(MemberCompositeKey),
+    Irreversible,
+}
+
+/// # Resolve Report
+///
+/// Resolve a report and carry out moderation actions, the reporter is notified.
+///
+/// Every action is checked before anything is changed, so invalid input leaves
+/// the report and its target untouched. Deleting content cannot be undone and
+/// is carried out last; if an action fails while being applied, the actions
+/// which went through are taken back and the report is returned to the queue.
+/// The reporter is only told once everything has succeeded.
+#[openapi(tag = "User Safety")]
+#[post("/reports/<id>/resolve", data = "<data>")]
+pub async fn resolve_report(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    id: String,
+    data: Json<DataResolveReport>,
+) -> Result<Json<Report>> {
+    let data = data.into_inner();
+    data.validate()
+        .map_err(|error| Error::FailedValidation { error })?;
+
+    if !user.privileged {
+        return Err(Error::NotPrivileged);
+    }
+
+    if data
+        .actions
+        .iter()
+        .enumerate()
+        .any(|(index, action)| data.actions[..index].contains(action))
+    {
+        return Err(Error::InvalidOperation);
+    }
+
+    let mut report = db.fetch_report(&id).await?;
+    if !matches!(report.status, ReportStatus::Created {}) {
+        return Err(Error::InvalidOperation);
+    }
+
+    // Work out who is responsible for the reported content
+    let target_id = match &report.content {
+        ReportedContent::Message { id, .. } => db.fetch_message(id).await?.author,
+        ReportedContent::Server { id, .. } => db.fetch_server(id).await?.owner,
+        ReportedContent::User { id, .. } => id.clone(),
+    };
+
+    // Fetch and check everything before making any change
+    let mut prepared = vec![];
+    for action in data.actions {
+        prepared.push(match action {
+            ReportAction::DeleteContent => {
+                let message_id = match &report.content {
+                    ReportedContent::Message { id, .. } => id,
+                    ReportedContent::User {
+                        message_id: Some(id),
+                        ..
+                    } => id,
+                    _ => return Err(Error::InvalidOperation),
+                };
+
+                PreparedAction::DeleteContent(db.fetch_message(message_id).await?)
+            }
+            ReportAction::SuspendUser => {
+                let target = db.fetch_user(&target_id).await?;
+                if target.privileged {
+                    return Err(Error::InvalidOperation);
+                }
+
+                PreparedAction::SuspendUser(target.id)
+            }
+            ReportAction::BanFromServer { server } => {
+                let server = db.fetch_server(&server).await?;
+                if server.owner == target_id || db.fetch_ban(&server.id, &target_id).await.is_ok() {
+                    return Err(Error::InvalidOperation);
+                }
+
+                let member = db.fetch_member(&server.id, &target_id).await.ok();
+                PreparedAction::BanFromServer(server, target_id.clone(), member)
+            }
+        });
+    }
+
+    // Deleted content cannot be restored, so only remove it once everything else went through
+    prepared.sort_by_key(|action| matches!(action, PreparedAction::DeleteContent(_)));
+
+    // Claim the report quietly, this fails if another moderator got here first
+    report
+        .claim(db, ReportStatus::Resolved { closed_at: None })
+        .await?;
+
+    let mut applied = vec![];
+    for action in prepared {
+        match execute(db, database, action, &user.id, &report.id).await {
+            Ok(action) => applied.push(action),
+            Err(error) => {
+                for action in applied.into_iter().rev() {
+                    if let Err(err) = undo(db, database, action).await {
+                        log::error!(
+                            "Failed to take back action on report {}: {err:?}",
+                            report.id
+                        );
+                    }
+                }
+
+                report.release(db).await.ok();
+                return Err(error);
+            }
+        }
+    }
+
+    report.announce_close().await;
+    Ok(Json(report))
+}
+
+async fn execute(
+    db: &Db,
+    database: &Database,
+    action: PreparedAction,
+    moderator_id: &str,
+    report_id: &str,
+) -> Result<AppliedAction> {
+    match action {
+        PreparedAction::DeleteContent(message) => message
+            .delete(db)
+            .await
+            .map(|_| AppliedAction::Irreversible),
+        PreparedAction::SuspendUser(user_id) => AccountStrike::issue(
+            database,
+            user_id,
+            moderator_id.to_string(),
+            format!("Actioned report {report_id}"),
+            StrikeAction::Suspension,
+            None,
+        )
+        .await
+        .map(AppliedAction::Suspension)
+        .map_err(Error::from_core),
+        PreparedAction::BanFromServer(server, user_id, member) => {
+            let reason = Some(format!("Actioned report {report_id}"));
+            match member {
+                Some(member) => server.ban_member(db, member, reason, None).await,
+                None => {
+                    server
+                        .ban_user(
+                            db,
+                            MemberCompositeKey {
+                                server: server.id.clone(),
+                                user: user_id,
+                            },
+                            reason,
+                            None,
+                        )
+                        .await
+                }
+            }
+            .map(|ban| AppliedAction::Ban(ban.id))
+        }
+    }
+}
+
+async fn undo(db: &Db, database: &Database, action: AppliedAction) -> Result<()> {
+    match action {
+        AppliedAction::Suspension(mut strike) => {
+            strike.lift(database).await.map_err(Error::from_core)
+        }
+        AppliedAction::Ban(id) => {
+            db.delete_ban(&id).await?;
+
+            EventV1::ServerMemberUnban {
+                id: id.server.clone(),
+                user: id.user,
+            }
+            .p(id.server)
+            .await;
+
+            Ok(())
+        }
+        AppliedAction::Irreversible => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::PartialUser;
+    use revolt_quark::models::report::{ReportStatus, ReportedContent, UserReportReason};
+    use revolt_quark::models::Report;
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn resolve_is_atomic() {
+        let harness = TestHarness::new().await;
+        let (_, session, moderator) = harness.new_user().await;
+        let (_, _, target) = harness.new_user().await;
+
+        harness
+            .db
+            .update_user(
+                &moderator.id,
+                &PartialUser {
+                    privileged: Some(true),
+                    ..Default::default()
+                },
+                vec![],
+            )
+            .await
+            .unwrap();
+
+        let legacy_db = harness
+            .client
+            .rocket()
+            .state::<revolt_quark::Database>()
+            .expect("`Database`")
+            .clone();
+
+        let report = Report {
+            id: ulid::Ulid::new().to_string(),
+            author_id: moderator.id.to_string(),
+            content: ReportedContent::User {
+                id: target.id.to_string(),
+                report_reason: UserReportReason::NoneSpecified,
+                message_id: None,
+            },
+            additional_context: String::new(),
+            status: ReportStatus::Created {},
+            notes: String::new(),
+            assignee_id: None,
+            comments: vec![],
+        };
+
+        legacy_db.insert_report(&report).await.unwrap();
+
+        // Actions may not be repeated
+        let response = harness
+            .client
+            .post(format!("/safety/reports/{}/resolve", report.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({
+                "actions": [{ "type": "SuspendUser" }, { "type": "SuspendUser" }]
+            }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+
+        // There is no message to delete, so the suspension must not go ahead either
+        let response = harness
+            .client
+            .post(format!("/safety/reports/{}/resolve", report.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({
+                "actions": [{ "type": "SuspendUser" }, { "type": "DeleteContent" }]
+            }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+        assert!(harness
+            .db
+            .fetch_strikes(&target.id)
+            .await
+            .unwrap()
+            .is_empty());
+        assert!(matches!(
+            legacy_db.fetch_report(&report.id).await.unwrap().status,
+            ReportStatus::Created {}
+        ));
+
+        let response = harness
+            .client
+            .post(format!("/safety/reports/{}/resolve", report.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "actions": [{ "type": "SuspendUser" }] }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        assert_eq!(harness.db.fetch_strikes(&target.id).await.unwrap().len(), 1);
+        assert!(matches!(
+            legacy_db.fetch_report(&report.id).await.unwrap().status,
+            ReportStatus::Resolved { closed_at: Some(_) }
+        ));
+    }
+}
@@ -0,0 +1,59 @@
+use revolt_database::{AccountStrike, Database, User};
+use revolt_models::v0;
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Account Standing
+///
+/// Fetch the strikes issued to your account and whether it is currently restricted.
+#[openapi(tag = "User Safety")]
+#[get("/standing")]
+pub async fn req(db: &State<Database>, user: User) -> Result<Json<v0::AccountStanding>> {
+    AccountStrike::fetch_standing(db, &user.id).await.map(Json)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::AccountStrike;
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+
+    #[rocket::async_test]
+    async fn suspended_session() {
+        let harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+
+        AccountStrike::issue(
+            &harness.db,
+            user.id.to_string(),
+            "Moderator".to_string(),
+            "Reason".to_string(),
+            v0::StrikeAction::Suspension,
+            Some(3600),
+        )
+        .await
+        .unwrap();
+
+        let response = harness
+            .client
+            .get("/users/@me")
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+
+        // Suspended accounts can still review their standing
+        let response = harness
+            .client
+            .get("/safety/standing")
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let standing: v0::AccountStanding = response.into_json().await.unwrap();
+        assert!(standing.suspended);
+    }
+}
@@ -0,0 +1,23 @@
+use revolt_database::{util::reference::Reference, AccountStrike, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch User Standing
+///
+/// Fetch the strikes issued to a given account.
+#[openapi(tag = "User Safety")]
+#[get("/users/<target>/standing")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<v0::AccountStanding>> {
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    AccountStrike::fetch_standing(db, &target.id)
+        .await
+        .map(Json)
+}
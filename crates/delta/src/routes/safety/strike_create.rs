@@ -0,0 +1,40 @@
+use revolt_database::{util::reference::Reference, AccountStrike, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Issue Strike
+///
+/// Issue a strike against an account, optionally suspending or banning it.
+#[openapi(tag = "User Safety")]
+#[post("/users/<target>/strikes", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataCreateStrike>,
+) -> Result<Json<v0::AccountStrike>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    let strike = AccountStrike::issue(
+        db,
+        target.id,
+        user.id,
+        data.reason,
+        data.action,
+        data.duration,
+    )
+    .await?;
+
+    Ok(Json(strike.into()))
+}
@@ -0,0 +1,27 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Lift Strike
+///
+/// Stop enforcing the suspension or ban attached to a strike.
+#[openapi(tag = "User Safety")]
+#[delete("/strikes/<target>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<v0::AccountStrike>> {
+    if !user.privileged {
+        return Err(create_error!(NotPrivileged));
+    }
+
+    let mut strike = db.fetch_strike(&target.id).await?;
+    if !strike.active {
+        return Err(create_error!(NoEffect));
+    }
+
+    strike.lift(db).await?;
+    Ok(Json(strike.into()))
+}
@@ -0,0 +1,69 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, AuditLogQuery,
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsFetchAuditLog {
+    /// Maximum number of entries to fetch
+    ///
+    /// For fetching more entries, use the `before` parameter.
+    #[validate(range(min = 1, max = 100))]
+    limit: Option<i64>,
+    /// Entry id before which entries should be fetched
+    #[validate(length(min = 26, max = 26))]
+    before: Option<String>,
+    /// Only include actions performed by this user
+    #[validate(length(min = 26, max = 26))]
+    actor: Option<String>,
+    /// Only include actions performed on this object
+    #[validate(length(min = 26, max = 26))]
+    target: Option<String>,
+}
+
+/// # Fetch Audit Log
+///
+/// Fetch entries from a server's audit log, newest first.
+#[openapi(tag = "Server Audit Log")]
+#[get("/<target>/audit_log?<options..>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    options: OptionsFetchAuditLog,
+) -> Result<Json<Vec<v0::AuditLogEntry>>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewAuditLog)?;
+
+    Ok(Json(
+        db.fetch_audit_log_entries(
+            &server.id,
+            AuditLogQuery {
+                limit: options.limit.unwrap_or(50),
+                before: options.before,
+                actor: options.actor,
+                target: options.target,
+            },
+        )
+        .await?
+        .into_iter()
+        .map(|entry| entry.into())
+        .collect(),
+    ))
+}
@@ -0,0 +1,49 @@
+use revolt_database::{AutoRoleRule, Database};
+use revolt_models::v0;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+
+/// # Create Auto Role Rule
+///
+/// Create a rule which gives members a role when they join,
+/// once they have been in the server for some time or when
+/// they react to a message.
+///
+/// Rules stop giving out their role while their creator
+/// is no longer able to assign it.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/auto_roles", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    data: Json<v0::DataCreateAutoRoleRule>,
+) -> Result<Json<v0::AutoRoleRule>> {
+    let data = data.into_inner();
+
+    let server = target.as_server(db).await?;
+    let role = server.roles.get(&data.role).ok_or(Error::InvalidRole)?;
+    let mut permissions = perms(&user).server(&server);
+
+    permissions
+        .throw_permission(db, Permission::AssignRoles)
+        .await?;
+
+    if role.rank <= permissions.get_member_rank().unwrap_or(i64::MIN) {
+        return Err(Error::NotElevated);
+    }
+
+    let rule = AutoRoleRule {
+        id: Ulid::new().to_string(),
+        server: server.id,
+        creator: user.id,
+        role: data.role,
+        trigger: data.trigger,
+        applied_until: None,
+    };
+
+    rule.create(database).await.map_err(Error::from_core)?;
+    Ok(Json(rule.into()))
+}
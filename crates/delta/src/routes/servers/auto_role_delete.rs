@@ -0,0 +1,43 @@
+use revolt_database::Database;
+use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
+
+/// # Delete Auto Role Rule
+///
+/// Delete an auto role rule by its id.
+#[openapi(tag = "Server Members")]
+#[delete("/<target>/auto_roles/<rule_id>")]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    rule_id: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let rule = database
+        .fetch_auto_role_rule(&rule_id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if rule.server != server.id {
+        return Err(Error::NotFound);
+    }
+
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(db, Permission::AssignRoles)
+        .await?;
+
+    // Rules for roles which have since been deleted can be removed by anyone who assigns roles
+    if let Some(role) = server.roles.get(&rule.role) {
+        if role.rank <= permissions.get_member_rank().unwrap_or(i64::MIN) {
+            return Err(Error::NotElevated);
+        }
+    }
+
+    rule.delete(database)
+        .await
+        .map(|_| EmptyResponse)
+        .map_err(Error::from_core)
+}
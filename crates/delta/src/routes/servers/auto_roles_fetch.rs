@@ -0,0 +1,28 @@
+use revolt_database::Database;
+use revolt_models::v0;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Auto Role Rules
+///
+/// Fetch all rules which give members roles automatically.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/auto_roles")]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+) -> Result<Json<Vec<v0::AutoRoleRule>>> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, Permission::AssignRoles)
+        .await?;
+
+    database
+        .fetch_auto_role_rules(&server.id)
+        .await
+        .map(|rules| Json(rules.into_iter().map(Into::into).collect()))
+        .map_err(Error::from_core)
+}
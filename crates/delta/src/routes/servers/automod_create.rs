@@ -0,0 +1,49 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, AutomodRule, Database,
+    User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use ulid::Ulid;
+use validator::Validate;
+
+/// # Create Automod Rule
+///
+/// Create a new automod rule on a server.
+#[openapi(tag = "Server Automod")]
+#[post("/<target>/automod", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataCreateAutomodRule>,
+) -> Result<Json<v0::AutomodRule>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let rule = AutomodRule {
+        id: Ulid::new().to_string(),
+        server: server.id,
+        name: data.name,
+        enabled: data.enabled.unwrap_or(true),
+        trigger: data.trigger,
+        actions: data.actions,
+        exempt_roles: data.exempt_roles.unwrap_or_default(),
+        exempt_channels: data.exempt_channels.unwrap_or_default(),
+    };
+
+    rule.create(db).await?;
+    Ok(Json(rule.into()))
+}
@@ -0,0 +1,32 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Delete Automod Rule
+///
+/// Delete an automod rule by its id.
+#[openapi(tag = "Server Automod")]
+#[delete("/<target>/automod/<rule_id>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    rule_id: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let rule = db.fetch_automod_rule(&rule_id).await?;
+    if rule.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    rule.delete(db).await.map(|_| EmptyResponse)
+}
@@ -0,0 +1,65 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database,
+    PartialAutomodRule, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Automod Rule
+///
+/// Edit an automod rule by its id.
+#[openapi(tag = "Server Automod")]
+#[patch("/<target>/automod/<rule_id>", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    rule_id: String,
+    data: Json<v0::DataEditAutomodRule>,
+) -> Result<Json<v0::AutomodRule>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut rule = db.fetch_automod_rule(&rule_id).await?;
+    if rule.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    let v0::DataEditAutomodRule {
+        name,
+        enabled,
+        trigger,
+        actions,
+        exempt_roles,
+        exempt_channels,
+    } = data;
+
+    rule.update(
+        db,
+        PartialAutomodRule {
+            name,
+            enabled,
+            trigger,
+            actions,
+            exempt_roles,
+            exempt_channels,
+            ..Default::default()
+        },
+    )
+    .await?;
+
+    Ok(Json(rule.into()))
+}
@@ -1,10 +1,16 @@
+use std::time::{Duration, SystemTime};
+
+use chrono::Utc;
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
 use revolt_quark::{
-    models::{server_member::MemberCompositeKey, ServerBan, User},
-    perms, Db, Error, Permission, Ref, Result,
+    models::{server_member::MemberCompositeKey, Message, ServerBan, User},
+    perms, Db, Error, Permission, Ref, Result, Timestamp,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
+use ulid::Ulid;
 use validator::Validate;
 
 /// # Ban Information
@@ -13,6 +19,14 @@ pub struct DataBanCreate {
     /// Ban reason
     #[validate(length(min = 1, max = 1024))]
     reason: Option<String>,
+    /// Duration of the ban in seconds, the ban is permanent if not set
+    ///
+    /// Temporary bans may last at most one year.
+    #[validate(range(min = 1, max = 31536000))]
+    duration: Option<u64>,
+    /// Delete messages sent by the user within this many hours
+    #[validate(range(min = 1, max = 168))]
+    delete_message_hours: Option<u32>,
 }
 
 /// # Ban User
@@ -22,10 +36,12 @@ pub struct DataBanCreate {
 #[put("/<server>/bans/<target>", data = "<data>")]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     server: Ref,
     target: Ref,
     data: Json<DataBanCreate>,
+    reason: AuditLogReason,
 ) -> Result<Json<ServerBan>> {
     let data = data.into_inner();
     data.validate()
@@ -47,27 +63,113 @@ pub async fn req(
         .throw_permission(db, Permission::BanMembers)
         .await?;
 
+    let server_id = server.id.to_string();
+    let channels = server.channels.clone();
+    let expires = match data.duration {
+        Some(duration) => Some(
+            SystemTime::now()
+                .checked_add(Duration::from_secs(duration))
+                .map(Timestamp::from)
+                .ok_or(Error::InvalidProperty)?,
+        ),
+        None => None,
+    };
+
     // If member exists, check privileges against them
-    if let Ok(member) = target.as_member(db, &server.id).await {
+    let ban = if let Ok(member) = target.as_member(db, &server.id).await {
         if member.get_ranking(permissions.server.get().unwrap())
             <= permissions.get_member_rank().unwrap_or(i64::MIN)
         {
             return Err(Error::NotElevated);
         }
 
-        server.ban_member(db, member, data.reason).await.map(Json)
+        server
+            .ban_member(db, member, data.reason.clone(), expires)
+            .await?
     } else {
-        let server_id = server.id.to_string();
         server
             .ban_user(
                 db,
                 MemberCompositeKey {
-                    server: server_id,
-                    user: target.id,
+                    server: server_id.clone(),
+                    user: target.id.clone(),
                 },
-                data.reason,
+                data.reason.clone(),
+                expires,
             )
-            .await
-            .map(Json)
+            .await?
+    };
+
+    if let Some(hours) = data.delete_message_hours {
+        let after = Ulid::from_datetime(Utc::now() - chrono::Duration::hours(hours as i64));
+        Message::bulk_delete_by_author(db, &channels, &target.id, &after.to_string()).await?;
+    }
+
+    AuditLogEntry::record(
+        database,
+        server_id,
+        user.id,
+        AuditLogAction::MemberBan,
+        Some(target.id),
+        reason.into_inner().or(data.reason),
+        None,
+    )
+    .await;
+
+    Ok(Json(ban))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::Member;
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn ban_duration() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+        let (_, other_session, other) = harness.new_user().await;
+        let (_, _, target) = harness.new_user().await;
+
+        let server = harness.new_server(&owner).await;
+        Member::create(&harness.db, &server, &other).await.unwrap();
+        Member::create(&harness.db, &server, &target).await.unwrap();
+
+        // Members need permission to ban
+        let response = harness
+            .client
+            .put(format!("/servers/{}/bans/{}", server.id, target.id))
+            .header(Header::new(
+                "x-session-token",
+                other_session.token.to_string(),
+            ))
+            .json(&json!({ "duration": 3600 }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+
+        // Bans last at most a year
+        let response = harness
+            .client
+            .put(format!("/servers/{}/bans/{}", server.id, target.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "duration": 31536001 }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let response = harness
+            .client
+            .put(format!("/servers/{}/bans/{}", server.id, target.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "duration": 3600 }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
     }
 }
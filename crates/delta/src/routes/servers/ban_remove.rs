@@ -1,11 +1,23 @@
+use revolt_database::{
+    events::client::EventV1, util::audit_log_reason::AuditLogReason, AuditLogEntry, Database,
+};
+use revolt_models::v0::AuditLogAction;
 use revolt_quark::{models::User, perms, Db, EmptyResponse, Permission, Ref, Result};
+use rocket::State;
 
 /// # Unban user
 ///
 /// Remove a user's ban.
 #[openapi(tag = "Server Members")]
 #[delete("/<server>/bans/<target>")]
-pub async fn req(db: &Db, user: User, server: Ref, target: Ref) -> Result<EmptyResponse> {
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    server: Ref,
+    target: Ref,
+    reason: AuditLogReason,
+) -> Result<EmptyResponse> {
     let server = server.as_server(db).await?;
     perms(&user)
         .server(&server)
@@ -13,5 +25,25 @@ pub async fn req(db: &Db, user: User, server: Ref, target: Ref) -> Result<EmptyR
         .await?;
 
     let ban = target.as_ban(db, &server.id).await?;
-    db.delete_ban(&ban.id).await.map(|_| EmptyResponse)
+    db.delete_ban(&ban.id).await?;
+
+    EventV1::ServerMemberUnban {
+        id: server.id.clone(),
+        user: target.id.clone(),
+    }
+    .p(server.id.clone())
+    .await;
+
+    AuditLogEntry::record(
+        database,
+        server.id,
+        user.id,
+        AuditLogAction::MemberUnban,
+        Some(target.id),
+        reason.into_inner(),
+        None,
+    )
+    .await;
+
+    Ok(EmptyResponse)
 }
@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
+use rocket::{serde::json::Json, State};
+use serde::Deserialize;
+
+use revolt_quark::{
+    models::{server::PartialServer, Server, User},
+    perms, Db, Error, Override, Permission, Ref, Result,
+};
+
+/// # Permission Value
+#[derive(Deserialize, JsonSchema)]
+pub struct DataSetCategoryRolePermission {
+    /// Allow / deny values for the role in this category
+    permissions: Override,
+}
+
+/// # Set Category Role Permission
+///
+/// Sets permissions for the specified role in a category.
+///
+/// Applies to every channel synced to the category.
+#[openapi(tag = "Server Permissions")]
+#[put(
+    "/<target>/categories/<category_id>/permissions/<role_id>",
+    data = "<data>",
+    rank = 2
+)]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    category_id: String,
+    role_id: String,
+    data: Json<DataSetCategoryRolePermission>,
+    reason: AuditLogReason,
+) -> Result<Json<Server>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let category = categories
+        .iter_mut()
+        .find(|category| category.id == category_id)
+        .ok_or(Error::NotFound)?;
+
+    let role = server.roles.get(&role_id).ok_or(Error::NotFound)?;
+    let mut permissions = perms(&user).server(&server);
+
+    permissions
+        .throw_permission(db, Permission::ManagePermissions)
+        .await?;
+
+    if role.rank <= permissions.get_member_rank().unwrap_or(i64::MIN) {
+        return Err(Error::NotElevated);
+    }
+
+    let current_value: Override = role.permissions.into();
+    permissions
+        .throw_permission_override(db, current_value, data.permissions)
+        .await?;
+
+    let before = category
+        .role_permissions
+        .insert(role_id.clone(), data.permissions.into());
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    AuditLogEntry::record(
+        database,
+        server.id.clone(),
+        user.id,
+        AuditLogAction::CategoryPermissionsUpdate,
+        Some(category_id),
+        reason.into_inner(),
+        AuditLogEntry::diff(
+            &HashMap::from([(&role_id, before)]),
+            &HashMap::from([(&role_id, Some(data.permissions.into()))]),
+        ),
+    )
+    .await;
+
+    Ok(Json(server))
+}
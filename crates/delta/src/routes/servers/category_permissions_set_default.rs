@@ -0,0 +1,88 @@
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
+use rocket::{serde::json::Json, State};
+use serde::Deserialize;
+
+use revolt_quark::{
+    models::{server::PartialServer, Server, User},
+    perms, Db, Error, Override, Permission, Ref, Result,
+};
+
+/// # Permission Value
+#[derive(Deserialize, JsonSchema)]
+pub struct DataSetCategoryDefaultPermission {
+    /// Allow / deny values for members in this category
+    permissions: Override,
+}
+
+/// # Set Category Default Permission
+///
+/// Sets permissions for the default role in a category.
+///
+/// Applies to every channel synced to the category.
+#[openapi(tag = "Server Permissions")]
+#[put(
+    "/<target>/categories/<category_id>/permissions/default",
+    data = "<data>",
+    rank = 1
+)]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    category_id: String,
+    data: Json<DataSetCategoryDefaultPermission>,
+    reason: AuditLogReason,
+) -> Result<Json<Server>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let category = categories
+        .iter_mut()
+        .find(|category| category.id == category_id)
+        .ok_or(Error::NotFound)?;
+
+    let mut permissions = perms(&user).server(&server);
+
+    permissions
+        .throw_permission(db, Permission::ManagePermissions)
+        .await?;
+
+    permissions
+        .throw_permission_override(
+            db,
+            category.default_permissions.map(|x| x.into()),
+            data.permissions,
+        )
+        .await?;
+
+    let before = category.default_permissions;
+    category.default_permissions = Some(data.permissions.into());
+    let changes = AuditLogEntry::diff(&before, &category.default_permissions);
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    AuditLogEntry::record(
+        database,
+        server.id.clone(),
+        user.id,
+        AuditLogAction::CategoryPermissionsUpdate,
+        Some(category_id),
+        reason.into_inner(),
+        changes,
+    )
+    .await;
+
+    Ok(Json(server))
+}
@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+
+use revolt_models::v0;
+use revolt_quark::{
+    models::{server::PartialServer, Server, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Reorder Categories
+///
+/// Change the order of a server's categories.
+///
+/// Every category must be listed exactly once.
+#[openapi(tag = "Server Information")]
+#[put("/<target>/categories", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    data: Json<v0::DataReorderCategories>,
+) -> Result<Json<Server>> {
+    let order = data.into_inner().categories;
+
+    let mut server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, Permission::ManageChannel)
+        .await?;
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+    let ids: HashSet<&String> = order.iter().collect();
+    if ids.len() != order.len()
+        || categories.len() != order.len()
+        || categories
+            .iter()
+            .any(|category| !ids.contains(&category.id))
+    {
+        return Err(Error::InvalidOperation);
+    }
+
+    categories.sort_by_key(|category| order.iter().position(|id| id == &category.id));
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    Ok(Json(server))
+}
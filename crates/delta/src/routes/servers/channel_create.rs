@@ -18,6 +18,8 @@ enum ChannelType {
     Text,
     /// Voice Channel
     Voice,
+    /// Forum Channel
+    Forum,
 }
 
 /// # Channel Data
@@ -39,7 +41,7 @@ pub struct DataCreateChannel {
 
 /// # Create Channel
 ///
-/// Create a new Text or Voice channel.
+/// Create a new Text, Voice or Forum channel.
 #[openapi(tag = "Server Information")]
 #[post("/<target>/channels", data = "<info>")]
 pub async fn req(
@@ -87,6 +89,8 @@ pub async fn req(
             role_permissions: HashMap::new(),
 
             nsfw: nsfw.unwrap_or(false),
+            slowmode_seconds: 0,
+            announcement: false,
         },
         ChannelType::Voice => Channel::VoiceChannel {
             id,
@@ -99,6 +103,22 @@ pub async fn req(
             default_permissions: None,
             role_permissions: HashMap::new(),
 
+            nsfw: nsfw.unwrap_or(false),
+        },
+        ChannelType::Forum => Channel::ForumChannel {
+            id,
+            server: server.id.clone(),
+
+            name,
+            description,
+            icon: None,
+
+            available_tags: vec![],
+            default_sort_order: Default::default(),
+
+            default_permissions: None,
+            role_permissions: HashMap::new(),
+
             nsfw: nsfw.unwrap_or(false),
         },
     };
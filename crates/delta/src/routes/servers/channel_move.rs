@@ -0,0 +1,100 @@
+use revolt_models::v0;
+use revolt_quark::{
+    models::{server::PartialServer, Server, User},
+    perms, Db, Error, Permission, Ref, Result,
+};
+use rocket::serde::json::Json;
+
+/// # Move Channel
+///
+/// Move a channel into a category, out of one, or to a new position within one.
+///
+/// Changing whether a channel is synced to its category requires `ManagePermissions`.
+#[openapi(tag = "Server Information")]
+#[put("/<target>/channels/<channel_id>/category", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    user: User,
+    target: Ref,
+    channel_id: String,
+    data: Json<v0::DataMoveChannel>,
+) -> Result<Json<Server>> {
+    let data = data.into_inner();
+
+    let mut server = target.as_server(db).await?;
+    if !server.channels.contains(&channel_id) {
+        return Err(Error::UnknownChannel);
+    }
+
+    let categories = server.categories.clone().unwrap_or_default();
+    let current = categories
+        .iter()
+        .position(|category| category.channels.contains(&channel_id));
+
+    let destination = if let Some(id) = &data.category {
+        Some(
+            categories
+                .iter()
+                .position(|category| &category.id == id)
+                .ok_or(Error::NotFound)?,
+        )
+    } else {
+        None
+    };
+
+    let was_synced = server.synced_category(&channel_id).is_some();
+    let sync = data.sync.unwrap_or(was_synced && current == destination);
+
+    if sync && destination.is_none() {
+        return Err(Error::InvalidOperation);
+    }
+
+    let mut permissions = perms(&user).server(&server);
+    permissions
+        .throw_permission(db, Permission::ManageChannel)
+        .await?;
+
+    if sync != was_synced || (sync && current != destination) {
+        permissions
+            .throw_permission(db, Permission::ManagePermissions)
+            .await?;
+    }
+
+    // Keep the channel's effective permissions when it stops being synced
+    if was_synced && !sync {
+        let mut channel = db.fetch_channel(&channel_id).await?;
+        server.desync_channel(db, &mut channel).await?;
+    }
+
+    let mut categories = server.categories.clone().unwrap_or_default();
+    for category in &mut categories {
+        category.channels.retain(|id| id != &channel_id);
+        category.synced.retain(|id| id != &channel_id);
+    }
+
+    if let Some(index) = destination {
+        let category = &mut categories[index];
+        let position = data
+            .position
+            .unwrap_or(category.channels.len())
+            .min(category.channels.len());
+
+        category.channels.insert(position, channel_id.clone());
+        if sync {
+            category.synced.push(channel_id);
+        }
+    }
+
+    server
+        .update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await?;
+
+    Ok(Json(server))
+}
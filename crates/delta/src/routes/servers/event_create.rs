@@ -0,0 +1,38 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database, ServerEvent,
+    User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Create Event
+///
+/// Schedule a new event in a server.
+#[openapi(tag = "Server Events")]
+#[post("/<target>/events", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataCreateServerEvent>,
+) -> Result<Json<v0::ServerEvent>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    ServerEvent::create(db, &server, user.id, data)
+        .await
+        .map(|event| Json(event.into()))
+}
@@ -0,0 +1,91 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database,
+    PartialServerEvent, ServerEvent, User,
+};
+use revolt_models::v0::{self, FieldsServerEvent, ServerEventStatus};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Event
+///
+/// Edit an event scheduled in a server.
+///
+/// The start time can only be changed before the event has started.
+#[openapi(tag = "Server Events")]
+#[patch("/<target>/events/<event_id>", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    event_id: String,
+    data: Json<v0::DataEditServerEvent>,
+) -> Result<Json<v0::ServerEvent>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut event = db.fetch_server_event(&event_id).await?;
+    if event.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    if event.status == ServerEventStatus::Completed {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    let mut partial = PartialServerEvent {
+        name: data.name,
+        description: data.description,
+        starts_at: data.starts_at,
+        ends_at: data.ends_at,
+        location: data.location,
+        ..Default::default()
+    };
+
+    if let Some(starts_at) = &partial.starts_at {
+        if event.status != ServerEventStatus::Scheduled {
+            return Err(create_error!(InvalidOperation));
+        }
+
+        ServerEvent::validate_start(starts_at)?;
+
+        // Attendees should be reminded again for the new time
+        partial.reminded = Some(false);
+    }
+
+    let ends_at = if data.remove.contains(&FieldsServerEvent::EndsAt) {
+        None
+    } else {
+        partial.ends_at.or(event.ends_at)
+    };
+
+    ServerEvent::validate_schedule(
+        partial.starts_at.as_ref().unwrap_or(&event.starts_at),
+        &ends_at,
+    )?;
+
+    if let Some(location) = &partial.location {
+        ServerEvent::validate_location(db, &server.id, location).await?;
+    }
+
+    event
+        .update(
+            db,
+            partial,
+            data.remove.into_iter().map(Into::into).collect(),
+        )
+        .await?;
+
+    Ok(Json(event.into()))
+}
@@ -0,0 +1,40 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database, User,
+};
+use revolt_models::v0::ServerEventStatus;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # RSVP to Event
+///
+/// Mark yourself as attending an event.
+#[openapi(tag = "Server Events")]
+#[put("/<target>/events/<event_id>/rsvp")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    event_id: String,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    let mut event = db.fetch_server_event(&event_id).await?;
+    if event.server != server.id {
+        return Err(create_error!(NotFound));
+    }
+
+    if event.status == ServerEventStatus::Completed {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    event
+        .set_attending(db, &user.id, true)
+        .await
+        .map(|_| EmptyResponse)
+}
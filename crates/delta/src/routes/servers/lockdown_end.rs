@@ -0,0 +1,28 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database,
+    RaidProtection, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # End Lockdown
+///
+/// Lift a server's lockdown.
+#[openapi(tag = "Server Raid Protection")]
+#[delete("/<target>/lockdown")]
+pub async fn req(db: &State<Database>, user: User, target: Reference) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut protection = RaidProtection::fetch(db, &server.id).await?;
+    if !protection.is_locked_down() {
+        return Err(create_error!(NoEffect));
+    }
+
+    protection.end_lockdown(db).await.map(|_| EmptyResponse)
+}
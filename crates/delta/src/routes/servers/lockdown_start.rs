@@ -0,0 +1,130 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database,
+    RaidProtection, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Start Lockdown
+///
+/// Lock a server down, pausing new invites and requiring a verified email to join.
+#[openapi(tag = "Server Raid Protection")]
+#[put("/<target>/lockdown", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataStartLockdown>,
+) -> Result<Json<v0::RaidProtection>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let mut protection = RaidProtection::fetch(db, &server.id).await?;
+    protection
+        .start_lockdown(db, Some(user.id), data.duration)
+        .await?;
+
+    Ok(Json(protection.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::{Channel, Member};
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn lockdown_duration() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+        let (_, other_session, other) = harness.new_user().await;
+
+        let server = harness.new_server(&owner).await;
+        Member::create(&harness.db, &server, &other).await.unwrap();
+
+        let voice = Channel::VoiceChannel {
+            id: ulid::Ulid::new().to_string(),
+            server: server.id.clone(),
+            name: TestHarness::rand_string(),
+            description: None,
+            icon: None,
+            default_permissions: None,
+            role_permissions: Default::default(),
+            nsfw: false,
+        };
+
+        voice.create(&harness.db).await.unwrap();
+
+        let response = harness
+            .client
+            .post(format!("/channels/{}/invites", voice.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        // Members need permission to lock the server down
+        let response = harness
+            .client
+            .put(format!("/servers/{}/lockdown", server.id))
+            .header(Header::new(
+                "x-session-token",
+                other_session.token.to_string(),
+            ))
+            .json(&json!({ "duration": 3600 }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+
+        let response = harness
+            .client
+            .put(format!("/servers/{}/lockdown", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "duration": 86401 }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::BadRequest);
+
+        let response = harness
+            .client
+            .put(format!("/servers/{}/lockdown", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "duration": 3600 }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let protection: v0::RaidProtection = response.into_json().await.unwrap();
+        assert!(protection
+            .lockdown
+            .is_some_and(|lockdown| lockdown.expires.is_some()));
+
+        // Invites are paused in every kind of server channel
+        let response = harness
+            .client
+            .post(format!("/channels/{}/invites", voice.id()))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+}
@@ -50,6 +50,16 @@ pub async fn req(
     let mut member = target.as_member(db, &server.id).await?;
     let mut permissions = perms(&user).server(&server);
 
+    // Screening can only be cleared by passing it or through the approval queue
+    if data
+        .remove
+        .as_ref()
+        .map(|x| x.contains(&FieldsMember::Pending))
+        .unwrap_or_default()
+    {
+        return Err(Error::InvalidOperation);
+    }
+
     // Check permissions in server
     let mut required = vec![];
 
@@ -1,14 +1,24 @@
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
 use revolt_quark::{
     models::{server_member::RemovalIntention, User},
     perms, Db, EmptyResponse, Error, Permission, Ref, Result,
 };
+use rocket::State;
 
 /// # Kick Member
 ///
 /// Removes a member from the server.
 #[openapi(tag = "Server Members")]
 #[delete("/<target>/members/<member>")]
-pub async fn req(db: &Db, user: User, target: Ref, member: Ref) -> Result<EmptyResponse> {
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    member: Ref,
+    reason: AuditLogReason,
+) -> Result<EmptyResponse> {
     let server = target.as_server(db).await?;
 
     if member.id == user.id {
@@ -33,8 +43,21 @@ pub async fn req(db: &Db, user: User, target: Ref, member: Ref) -> Result<EmptyR
         return Err(Error::NotElevated);
     }
 
+    let member_id = member.id.user.clone();
     server
         .remove_member(db, member, RemovalIntention::Kick, false)
-        .await
-        .map(|_| EmptyResponse)
+        .await?;
+
+    AuditLogEntry::record(
+        database,
+        server.id,
+        user.id,
+        AuditLogAction::MemberKick,
+        Some(member_id),
+        reason.into_inner(),
+        None,
+    )
+    .await;
+
+    Ok(EmptyResponse)
 }
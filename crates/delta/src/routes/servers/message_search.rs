@@ -0,0 +1,108 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, MessageSearchQuery, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_channel_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Search for Messages in Server
+///
+/// Searches for messages across every channel of a server you can read.
+#[openapi(tag = "Server Information")]
+#[post("/<target>/search", data = "<options>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    options: Json<v0::DataMessageSearch>,
+) -> Result<Json<Vec<v0::MessageSearchResult>>> {
+    if user.bot.is_some() {
+        return Err(create_error!(IsBot));
+    }
+
+    let options = options.into_inner();
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let member = db
+        .fetch_member(&server.id, &user.id)
+        .await
+        .map_err(|_| create_error!(NotFound))?;
+
+    // Only search channels we are able to read history from
+    let candidates = if let Some(channels) = &options.channels {
+        channels
+            .iter()
+            .filter(|id| server.channels.contains(id))
+            .cloned()
+            .collect::<Vec<String>>()
+    } else {
+        server.channels.clone()
+    };
+
+    let mut channels = vec![];
+    for channel in db.fetch_channels(&candidates).await? {
+        let mut query = DatabasePermissionQuery::new(db, &user)
+            .channel(&channel)
+            .server(&server)
+            .member(&member);
+
+        let permissions = calculate_channel_permissions(&mut query).await;
+        if permissions.has_channel_permission(ChannelPermission::ViewChannel)
+            && permissions.has_channel_permission(ChannelPermission::ReadMessageHistory)
+        {
+            channels.push(channel.id());
+        }
+    }
+
+    if channels.is_empty() {
+        return Ok(Json(vec![]));
+    }
+
+    let v0::DataMessageSearch {
+        query,
+        author,
+        mentions,
+        has,
+        before,
+        after,
+        pinned,
+        limit,
+        sort,
+        ..
+    } = options;
+
+    let messages = db
+        .search_messages(MessageSearchQuery {
+            channels,
+            query: query.clone(),
+            author,
+            mentions,
+            has_attachment: has.contains(&v0::MessageSearchHas::Attachment),
+            has_embed: has.contains(&v0::MessageSearchHas::Embed),
+            has_link: has.contains(&v0::MessageSearchHas::Link),
+            pinned,
+            before: before.map(MessageSearchQuery::id_from_timestamp),
+            after: after.map(MessageSearchQuery::id_from_timestamp),
+            limit: limit.unwrap_or(50),
+            sort,
+        })
+        .await?;
+
+    Ok(Json(
+        messages
+            .into_iter()
+            .map(|message| v0::MessageSearchResult {
+                highlight: query.as_deref().and_then(|query| message.highlight(query)),
+                message: message.into(),
+            })
+            .collect(),
+    ))
+}
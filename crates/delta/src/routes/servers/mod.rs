@@ -1,27 +1,68 @@
 use revolt_rocket_okapi::revolt_okapi::openapi3::OpenApi;
 use rocket::Route;
 
+mod audit_log_fetch;
+mod auto_role_create;
+mod auto_role_delete;
+mod auto_roles_fetch;
+mod automod_create;
+mod automod_delete;
+mod automod_edit;
+mod automod_list;
 mod ban_create;
 mod ban_list;
 mod ban_remove;
+mod category_permissions_set;
+mod category_permissions_set_default;
+mod category_reorder;
 mod channel_create;
+mod channel_move;
 mod emoji_list;
+mod event_create;
+mod event_delete;
+mod event_edit;
+mod event_rsvp;
+mod event_unrsvp;
+mod events_fetch;
 mod invites_fetch;
+mod lockdown_end;
+mod lockdown_start;
 mod member_edit;
 mod member_experimental_query;
 mod member_fetch;
 mod member_fetch_all;
 mod member_remove;
+mod message_search;
+mod ownership_accept;
+mod ownership_cancel;
+mod ownership_transfer;
 mod permissions_set;
 mod permissions_set_default;
+mod raid_protection_edit;
+mod raid_protection_fetch;
+mod role_job_create;
+mod role_job_fetch;
+mod role_members_fetch;
 mod roles_create;
 mod roles_delete;
 mod roles_edit;
+mod screening_approve;
+mod screening_edit;
+mod screening_fetch;
+mod screening_queue;
+mod screening_reject;
+mod screening_submit;
 mod server_ack;
 mod server_create;
 mod server_delete;
 mod server_edit;
 mod server_fetch;
+mod template_create;
+mod template_delete;
+mod templates_fetch;
+mod vanity_fetch;
+mod vanity_remove;
+mod vanity_set;
 
 pub fn routes() -> (Vec<Route>, OpenApi) {
     openapi_get_routes_spec![
@@ -30,19 +71,60 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         server_fetch::req,
         server_edit::req,
         server_ack::req,
+        ownership_transfer::req,
+        ownership_accept::req,
+        ownership_cancel::req,
         channel_create::req,
+        channel_move::req,
+        category_reorder::req,
+        category_permissions_set::req,
+        category_permissions_set_default::req,
         member_fetch_all::req,
         member_remove::req,
         member_fetch::req,
         member_edit::req,
         member_experimental_query::member_experimental_query,
+        message_search::req,
+        automod_list::req,
+        automod_create::req,
+        automod_edit::req,
+        automod_delete::req,
+        audit_log_fetch::req,
+        raid_protection_fetch::req,
+        raid_protection_edit::req,
+        lockdown_start::req,
+        lockdown_end::req,
+        screening_fetch::req,
+        screening_edit::req,
+        screening_submit::req,
+        screening_queue::req,
+        screening_approve::req,
+        screening_reject::req,
         ban_create::req,
         ban_remove::req,
         ban_list::req,
         invites_fetch::req,
+        vanity_fetch::req,
+        vanity_set::req,
+        vanity_remove::req,
+        template_create::req,
+        templates_fetch::req,
+        template_delete::req,
+        event_create::req,
+        events_fetch::req,
+        event_edit::req,
+        event_delete::req,
+        event_rsvp::req,
+        event_unrsvp::req,
         roles_create::req,
         roles_edit::req,
         roles_delete::req,
+        role_members_fetch::req,
+        role_job_create::req,
+        role_job_fetch::req,
+        auto_roles_fetch::req,
+        auto_role_create::req,
+        auto_role_delete::req,
         permissions_set::req,
         permissions_set_default::req,
         emoji_list::list_emoji
@@ -0,0 +1,29 @@
+use revolt_database::{util::reference::Reference, AuditLogEntry, Database, User};
+use revolt_models::v0;
+use revolt_result::Result;
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Accept Ownership
+///
+/// Accept a pending ownership transfer and become the owner of a server.
+#[openapi(tag = "Server Information")]
+#[put("/<target>/ownership/accept")]
+pub async fn req(db: &State<Database>, user: User, target: Reference) -> Result<EmptyResponse> {
+    let mut server = target.as_server(db).await?;
+    let previous_owner = server.owner.clone();
+    server.accept_ownership_transfer(db, &user.id).await?;
+
+    AuditLogEntry::record(
+        db,
+        server.id,
+        previous_owner.clone(),
+        v0::AuditLogAction::OwnershipTransfer,
+        Some(user.id.clone()),
+        None,
+        AuditLogEntry::diff(&previous_owner, &user.id),
+    )
+    .await;
+
+    Ok(EmptyResponse)
+}
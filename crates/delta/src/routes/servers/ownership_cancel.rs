@@ -0,0 +1,21 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Cancel Ownership Transfer
+///
+/// Cancel a pending ownership transfer as the owner, or decline it as the requested member.
+#[openapi(tag = "Server Information")]
+#[delete("/<target>/ownership")]
+pub async fn req(db: &State<Database>, user: User, target: Reference) -> Result<EmptyResponse> {
+    let mut server = target.as_server(db).await?;
+    if server.owner != user.id && server.pending_owner.as_deref() != Some(user.id.as_str()) {
+        return Err(create_error!(NotFound));
+    }
+
+    server
+        .cancel_ownership_transfer(db)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -0,0 +1,125 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_quark::authifier::models::ValidatedTicket;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use rocket_empty::EmptyResponse;
+
+/// # Transfer Ownership
+///
+/// Offer ownership of a server to one of its members.
+///
+/// Requires a validated MFA ticket, ownership only changes once the member accepts.
+#[openapi(tag = "Server Information")]
+#[put("/<target>/ownership", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    ticket: ValidatedTicket,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataTransferServerOwnership>,
+) -> Result<EmptyResponse> {
+    if ticket.0.account_id != user.id {
+        return Err(create_error!(InvalidCredentials));
+    }
+
+    let mut server = target.as_server(db).await?;
+    if server.owner != user.id {
+        return Err(create_error!(NotOwner));
+    }
+
+    let new_owner = Reference::from_unchecked(data.into_inner().user)
+        .as_user(db)
+        .await?;
+
+    if new_owner.bot.is_some() {
+        return Err(create_error!(IsBot));
+    }
+
+    server
+        .request_ownership_transfer(db, &new_owner.id)
+        .await
+        .map(|_| EmptyResponse)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::Member;
+    use revolt_quark::authifier::{
+        models::{MFATicket, Totp},
+        Authifier,
+    };
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn transfer_requires_ticket() {
+        let harness = TestHarness::new().await;
+        let (mut account, session, owner) = harness.new_user().await;
+        let (_, _, member) = harness.new_user().await;
+
+        let server = harness.new_server(&owner).await;
+        Member::create(&harness.db, &server, &member).await.unwrap();
+
+        let authifier = harness
+            .client
+            .rocket()
+            .state::<Authifier>()
+            .expect("`Authifier`");
+
+        // Owners without MFA still have to re-authenticate
+        let response = harness
+            .client
+            .put(format!("/servers/{}/ownership", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "user": member.id }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Unauthorized);
+
+        for mfa in [false, true] {
+            if mfa {
+                account.mfa.totp_token = Totp::Enabled {
+                    secret: TestHarness::rand_string(),
+                };
+
+                account.save(authifier).await.unwrap();
+            }
+
+            let response = harness
+                .client
+                .put(format!("/servers/{}/ownership", server.id))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .json(&json!({ "user": member.id }))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::Unauthorized);
+
+            let ticket = MFATicket::new(account.id.to_string(), true);
+            ticket.save(authifier).await.unwrap();
+
+            let response = harness
+                .client
+                .put(format!("/servers/{}/ownership", server.id))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .header(Header::new("x-mfa-ticket", ticket.token.to_string()))
+                .json(&json!({ "user": member.id }))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::NoContent);
+
+            let response = harness
+                .client
+                .delete(format!("/servers/{}/ownership", server.id))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::NoContent);
+        }
+    }
+}
@@ -1,4 +1,6 @@
-use rocket::serde::json::Json;
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
+use rocket::{serde::json::Json, State};
 use serde::Deserialize;
 
 use revolt_quark::{
@@ -20,10 +22,12 @@ pub struct DataSetServerRolePermission {
 #[put("/<target>/permissions/<role_id>", data = "<data>", rank = 2)]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     role_id: String,
     data: Json<DataSetServerRolePermission>,
+    reason: AuditLogReason,
 ) -> Result<Json<Server>> {
     let data = data.into_inner();
 
@@ -40,6 +44,7 @@ pub async fn req(
             return Err(Error::NotElevated);
         }
 
+        let changes = AuditLogEntry::diff(&current_value, &data.permissions.into());
         let current_value: Override = current_value.into();
         permissions
             .throw_permission_override(db, current_value, data.permissions)
@@ -49,6 +54,17 @@ pub async fn req(
             .set_role_permission(db, &role_id, data.permissions.into())
             .await?;
 
+        AuditLogEntry::record(
+            database,
+            server.id.clone(),
+            user.id,
+            AuditLogAction::ServerPermissionsUpdate,
+            Some(role_id),
+            reason.into_inner(),
+            changes,
+        )
+        .await;
+
         Ok(Json(server))
     } else {
         Err(Error::NotFound)
@@ -1,9 +1,12 @@
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
 use revolt_permissions::DataPermissionsValue;
 use revolt_quark::{
     models::{server::PartialServer, Server, User},
     perms, Db, Permission, Ref, Result,
 };
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
+
 /// # Set Default Permission
 ///
 /// Sets permissions for the default role in this server.
@@ -11,9 +14,11 @@ use rocket::serde::json::Json;
 #[put("/<target>/permissions/default", data = "<data>", rank = 1)]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     data: Json<DataPermissionsValue>,
+    reason: AuditLogReason,
 ) -> Result<Json<Server>> {
     let data = data.into_inner();
 
@@ -28,6 +33,8 @@ pub async fn req(
         .throw_permission_value(db, data.permissions)
         .await?;
 
+    let before = server.default_permissions;
+
     server
         .update(
             db,
@@ -39,5 +46,16 @@ pub async fn req(
         )
         .await?;
 
+    AuditLogEntry::record(
+        database,
+        server.id.clone(),
+        user.id,
+        AuditLogAction::ServerPermissionsUpdate,
+        None,
+        reason.into_inner(),
+        AuditLogEntry::diff(&before, &server.default_permissions),
+    )
+    .await;
+
     Ok(Json(server))
 }
@@ -0,0 +1,70 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database,
+    PartialRaidProtection, RaidProtection, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Raid Protection
+///
+/// Change join rate limits and join requirements of a server.
+#[openapi(tag = "Server Raid Protection")]
+#[patch("/<target>/raid_protection", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataEditRaidProtection>,
+) -> Result<Json<v0::RaidProtection>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let v0::DataEditRaidProtection {
+        join_threshold,
+        join_window,
+        lockdown_duration,
+        min_account_age,
+        require_verified_email,
+        alert_channel,
+        remove,
+    } = data;
+
+    // Alerts may only be sent to channels in this server
+    if let Some(channel) = &alert_channel {
+        if !server.channels.contains(channel) {
+            return Err(create_error!(InvalidOperation));
+        }
+    }
+
+    let mut protection = RaidProtection::fetch(db, &server.id).await?;
+    protection
+        .update(
+            db,
+            PartialRaidProtection {
+                join_threshold,
+                join_window,
+                lockdown_duration,
+                min_account_age,
+                require_verified_email,
+                alert_channel,
+                ..Default::default()
+            },
+            remove.unwrap_or_default(),
+        )
+        .await?;
+
+    Ok(Json(protection.into()))
+}
@@ -0,0 +1,28 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database,
+    RaidProtection, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Raid Protection
+///
+/// Fetch a server's raid protection settings and lockdown state.
+#[openapi(tag = "Server Raid Protection")]
+#[get("/<target>/raid_protection")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<v0::RaidProtection>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let protection = RaidProtection::fetch(db, &server.id).await?;
+    Ok(Json(protection.into()))
+}
@@ -0,0 +1,48 @@
+use revolt_database::{Database, RoleJob};
+use revolt_models::v0;
+use revolt_quark::{models::User, perms, Db, Error, Permission, Ref, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Create Role Job
+///
+/// Add or remove a role for many members at once.
+///
+/// Members are updated in the background, progress is
+/// reported through `RoleJobUpdate` events. A server may
+/// only have a few jobs waiting or running at once.
+#[openapi(tag = "Server Members")]
+#[post("/<target>/roles/<role_id>/jobs", data = "<data>")]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    role_id: String,
+    data: Json<v0::DataCreateRoleJob>,
+) -> Result<Json<v0::RoleJob>> {
+    let data = data.into_inner();
+
+    let server = target.as_server(db).await?;
+    let role = server.roles.get(&role_id).ok_or(Error::NotFound)?;
+    let mut permissions = perms(&user).server(&server);
+
+    permissions
+        .throw_permission(db, Permission::AssignRoles)
+        .await?;
+
+    if role.rank <= permissions.get_member_rank().unwrap_or(i64::MIN) {
+        return Err(Error::NotElevated);
+    }
+
+    RoleJob::create(
+        database,
+        server.id,
+        user.id,
+        role_id,
+        data.action,
+        data.selection,
+    )
+    .await
+    .map(|job| Json(job.into()))
+    .map_err(Error::from_core)
+}
@@ -0,0 +1,24 @@
+use revolt_database::{util::reference::Reference, Database, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Role Job
+///
+/// Fetch the progress of a role job you started.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/roles/<role_id>/jobs/<job_id>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    role_id: String,
+    job_id: String,
+) -> Result<Json<v0::RoleJob>> {
+    let job = db.fetch_role_job(&job_id).await?;
+    if job.server != target.id || job.role != role_id || job.creator != user.id {
+        return Err(create_error!(NotFound));
+    }
+
+    Ok(Json(job.into()))
+}
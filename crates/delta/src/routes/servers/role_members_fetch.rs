@@ -0,0 +1,59 @@
+use revolt_database::{
+    util::{permissions::DatabasePermissionQuery, reference::Reference},
+    Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+/// # Query Parameters
+#[derive(Validate, Serialize, Deserialize, JsonSchema, FromForm)]
+pub struct OptionsFetchRoleMembers {
+    /// Maximum number of members to fetch
+    #[validate(range(min = 1, max = 1000))]
+    limit: Option<i64>,
+    /// User id after which members should be fetched
+    #[validate(length(min = 26, max = 26))]
+    after: Option<String>,
+}
+
+/// # Fetch Role Members
+///
+/// Fetch members of a server who have a given role, sorted by user id.
+#[openapi(tag = "Server Members")]
+#[get("/<target>/roles/<role_id>/members?<options..>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    role_id: String,
+    options: OptionsFetchRoleMembers,
+) -> Result<Json<Vec<v0::Member>>> {
+    options.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    if !server.roles.contains_key(&role_id) {
+        return Err(create_error!(NotFound));
+    }
+
+    db.fetch_members_with_role(
+        &server.id,
+        &role_id,
+        options.after,
+        Some(options.limit.unwrap_or(100)),
+    )
+    .await
+    .map(|members| Json(members.into_iter().map(Into::into).collect()))
+}
@@ -1,9 +1,11 @@
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
 use revolt_quark::{
     models::{server::Role, User},
     perms, Db, Error, Permission, Ref, Result, variables::delta::MAX_ROLE_COUNT,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -35,9 +37,11 @@ pub struct NewRoleResponse {
 #[post("/<target>/roles", data = "<data>")]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     data: Json<DataCreateRole>,
+    reason: AuditLogReason,
 ) -> Result<Json<NewRoleResponse>> {
     let data = data.into_inner();
     data.validate()
@@ -71,8 +75,18 @@ pub async fn req(
         ..Default::default()
     };
 
-    Ok(Json(NewRoleResponse {
-        id: role.create(db, &server.id).await?,
-        role,
-    }))
+    let id = role.create(db, &server.id).await?;
+
+    AuditLogEntry::record(
+        database,
+        server.id,
+        user.id,
+        AuditLogAction::RoleCreate,
+        Some(id.clone()),
+        reason.into_inner(),
+        AuditLogEntry::diff(&None, &Some(&role)),
+    )
+    .await;
+
+    Ok(Json(NewRoleResponse { id, role }))
 }
@@ -1,11 +1,21 @@
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
 use revolt_quark::{models::User, perms, Db, EmptyResponse, Error, Permission, Ref, Result};
+use rocket::State;
 
 /// # Delete Role
 ///
 /// Delete a server role by its id.
 #[openapi(tag = "Server Permissions")]
 #[delete("/<target>/roles/<role_id>")]
-pub async fn req(db: &Db, user: User, target: Ref, role_id: String) -> Result<EmptyResponse> {
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    role_id: String,
+    reason: AuditLogReason,
+) -> Result<EmptyResponse> {
     let mut server = target.as_server(db).await?;
     let mut permissions = perms(&user).server(&server);
 
@@ -20,9 +30,21 @@ pub async fn req(db: &Db, user: User, target: Ref, role_id: String) -> Result<Em
             return Err(Error::NotElevated);
         }
 
-        role.delete(db, &server.id, &role_id)
-            .await
-            .map(|_| EmptyResponse)
+        let changes = AuditLogEntry::diff(&Some(&role), &None);
+        role.delete(db, &server.id, &role_id).await?;
+
+        AuditLogEntry::record(
+            database,
+            server.id,
+            user.id,
+            AuditLogAction::RoleDelete,
+            Some(role_id),
+            reason.into_inner(),
+            changes,
+        )
+        .await;
+
+        Ok(EmptyResponse)
     } else {
         Err(Error::NotFound)
     }
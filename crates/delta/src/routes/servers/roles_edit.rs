@@ -1,14 +1,16 @@
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database, Emoji};
+use revolt_models::v0::AuditLogAction;
 use revolt_quark::{
     models::{
         server::{FieldsRole, PartialRole, Role},
-        User,
+        File, User,
     },
     perms,
     util::regex::RE_COLOUR,
     Db, Error, Permission, Ref, Result,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -21,8 +23,16 @@ pub struct DataEditRole {
     /// Role colour
     #[validate(length(min = 1, max = 128), regex = "RE_COLOUR")]
     colour: Option<String>,
+    /// Attachment Id for the role icon
+    #[validate(length(min = 1, max = 128))]
+    icon: Option<String>,
+    /// Unicode emoji shown next to members with this role
+    #[validate(length(min = 1, max = 32))]
+    emoji: Option<String>,
     /// Whether this role should be displayed separately
     hoist: Option<bool>,
+    /// Whether anyone can mention this role
+    mentionable: Option<bool>,
     /// Ranking position
     ///
     /// Smaller values take priority.
@@ -39,10 +49,12 @@ pub struct DataEditRole {
 #[patch("/<target>/roles/<role_id>", data = "<data>")]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     role_id: String,
     data: Json<DataEditRole>,
+    reason: AuditLogReason,
 ) -> Result<Json<Role>> {
     let data = data.into_inner();
     data.validate()
@@ -61,7 +73,10 @@ pub async fn req(
         let DataEditRole {
             name,
             colour,
+            icon,
+            emoji,
             hoist,
+            mentionable,
             rank,
             remove,
         } = data;
@@ -72,25 +87,130 @@ pub async fn req(
             }
         }
 
+        // A role shows either an icon or an emoji
+        if icon.is_some() && emoji.is_some() {
+            return Err(Error::InvalidOperation);
+        }
+
+        if let Some(emoji) = &emoji {
+            if !Emoji::is_unicode(emoji) {
+                return Err(Error::InvalidProperty);
+            }
+        }
+
+        let mut remove = remove.unwrap_or_default();
+        if icon.is_some() {
+            remove.push(FieldsRole::Emoji);
+        }
+
+        if emoji.is_some() {
+            remove.push(FieldsRole::Icon);
+        }
+
+        // Claim the new icon first so a bad attachment leaves the old one intact
+        let icon = if let Some(icon_id) = icon {
+            Some(File::use_icon(db, &icon_id, &role_id).await?)
+        } else {
+            None
+        };
+
+        let before = role.clone();
         let partial = PartialRole {
             name,
             colour,
+            icon,
+            emoji,
             hoist,
+            mentionable,
             rank,
             ..Default::default()
         };
 
-        role.update(
-            db,
-            &server.id,
-            &role_id,
-            partial,
-            remove.unwrap_or_default(),
+        let replaced_icon = partial.icon.is_some() || remove.contains(&FieldsRole::Icon);
+
+        role.update(db, &server.id, &role_id, partial, remove)
+            .await?;
+
+        // Only let go of the old icon once the role no longer points at it
+        if replaced_icon {
+            if let Some(icon) = &before.icon {
+                db.mark_attachment_as_deleted(&icon.id).await?;
+            }
+        }
+
+        AuditLogEntry::record(
+            database,
+            server.id,
+            user.id,
+            AuditLogAction::RoleUpdate,
+            Some(role_id),
+            reason.into_inner(),
+            AuditLogEntry::diff(&before, &role),
         )
-        .await?;
+        .await;
 
         Ok(Json(role))
     } else {
         Err(Error::NotFound)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::Role;
+    use revolt_models::v0;
+    use revolt_permissions::OverrideField;
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn edit_role_emoji() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+
+        let server = harness
+            .new_server_with(&owner, |server| {
+                server.roles = HashMap::from([(
+                    "role".to_string(),
+                    Role {
+                        name: "Role".to_string(),
+                        permissions: OverrideField::default(),
+                        colour: None,
+                        icon: None,
+                        emoji: None,
+                        hoist: false,
+                        mentionable: false,
+                        rank: 1,
+                    },
+                )])
+            })
+            .await;
+
+        for invalid in ["not an emoji", "👍👍"] {
+            let response = harness
+                .client
+                .patch(format!("/servers/{}/roles/role", server.id))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .json(&json!({ "emoji": invalid }))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::BadRequest);
+        }
+
+        let response = harness
+            .client
+            .patch(format!("/servers/{}/roles/role", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "emoji": "👍" }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        let role = response.into_json::<v0::Role>().await.unwrap();
+        assert_eq!(role.emoji.as_deref(), Some("👍"));
+    }
+}
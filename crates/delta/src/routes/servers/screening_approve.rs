@@ -0,0 +1,36 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database, User,
+};
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Approve Screening
+///
+/// Let a member whose screening is waiting for approval into the server.
+#[openapi(tag = "Server Screening")]
+#[put("/<target>/screening/queue/<member>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    member: Reference,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::KickMembers)?;
+
+    let mut member = db.fetch_member(&server.id, &member.id).await?;
+    if !member
+        .pending
+        .as_ref()
+        .is_some_and(|pending| pending.submitted_at.is_some())
+    {
+        return Err(create_error!(InvalidOperation));
+    }
+
+    member.approve_screening(db).await.map(|_| EmptyResponse)
+}
@@ -0,0 +1,57 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database,
+    PartialServerScreening, ServerScreening, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Screening
+///
+/// Change the rules and questions new members must go through.
+#[openapi(tag = "Server Screening")]
+#[patch("/<target>/screening", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataEditScreening>,
+) -> Result<Json<v0::ServerScreening>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let v0::DataEditScreening {
+        enabled,
+        rules,
+        questions,
+        require_approval,
+    } = data;
+
+    let mut screening = ServerScreening::fetch(db, &server.id).await?;
+    screening
+        .update(
+            db,
+            PartialServerScreening {
+                enabled,
+                rules,
+                questions,
+                require_approval,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    Ok(Json(screening.into()))
+}
@@ -0,0 +1,41 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Screening Queue
+///
+/// Fetch members whose screening is waiting for approval, oldest first.
+#[openapi(tag = "Server Screening")]
+#[get("/<target>/screening/queue")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+) -> Result<Json<Vec<v0::ScreeningSubmission>>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::KickMembers)?;
+
+    let mut submissions: Vec<v0::ScreeningSubmission> = db
+        .fetch_pending_members(&server.id)
+        .await?
+        .into_iter()
+        .filter_map(|member| {
+            let pending = member.pending?;
+            Some(v0::ScreeningSubmission {
+                user: member.id.user,
+                answers: pending.answers,
+                submitted_at: pending.submitted_at?,
+            })
+        })
+        .collect();
+
+    submissions.sort_by_key(|submission| *submission.submitted_at);
+    Ok(Json(submissions))
+}
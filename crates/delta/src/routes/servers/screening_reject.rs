@@ -0,0 +1,54 @@
+use revolt_database::{util::audit_log_reason::AuditLogReason, AuditLogEntry, Database};
+use revolt_models::v0::AuditLogAction;
+use revolt_quark::{
+    models::{server_member::RemovalIntention, User},
+    perms, Db, EmptyResponse, Error, Permission, Ref, Result,
+};
+use rocket::State;
+
+/// # Reject Screening
+///
+/// Remove a member whose screening is waiting for approval from the server.
+#[openapi(tag = "Server Screening")]
+#[delete("/<target>/screening/queue/<member>")]
+pub async fn req(
+    db: &Db,
+    database: &State<Database>,
+    user: User,
+    target: Ref,
+    member: Ref,
+    reason: AuditLogReason,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    perms(&user)
+        .server(&server)
+        .throw_permission(db, Permission::KickMembers)
+        .await?;
+
+    let member = member.as_member(db, &server.id).await?;
+    if !member
+        .pending
+        .as_ref()
+        .is_some_and(|pending| pending.submitted_at.is_some())
+    {
+        return Err(Error::InvalidOperation);
+    }
+
+    let member_id = member.id.user.clone();
+    server
+        .remove_member(db, member, RemovalIntention::Kick, false)
+        .await?;
+
+    AuditLogEntry::record(
+        database,
+        server.id,
+        user.id,
+        AuditLogAction::MemberKick,
+        Some(member_id),
+        reason.into_inner(),
+        None,
+    )
+    .await;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,42 @@
+use revolt_database::{util::reference::Reference, Database, ServerScreening, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use rocket_empty::EmptyResponse;
+use validator::Validate;
+
+/// # Submit Screening
+///
+/// Accept a server's rules and answer its questions.
+///
+/// Members are let in immediately unless the server requires moderator approval.
+#[openapi(tag = "Server Screening")]
+#[post("/<target>/screening", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataSubmitScreening>,
+) -> Result<EmptyResponse> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    if !data.accept_rules {
+        return Err(create_error!(FailedValidation {
+            error: "rules must be accepted".to_string()
+        }));
+    }
+
+    let server = target.as_server(db).await?;
+    let mut member = db.fetch_member(&server.id, &user.id).await?;
+    let screening = ServerScreening::fetch(db, &server.id).await?;
+
+    member
+        .submit_screening(db, &screening, data.answers)
+        .await
+        .map(|_| EmptyResponse)
+}
@@ -6,7 +6,7 @@ use revolt_quark::{
     Db, Error, Result, DEFAULT_PERMISSION_SERVER,
 };
 
-use rocket::serde::json::Json;
+use rocket::{serde::json::Json, State};
 use serde::{Deserialize, Serialize};
 use ulid::Ulid;
 use validator::Validate;
@@ -23,6 +23,9 @@ pub struct DataCreateServer {
     /// Whether this server is age-restricted
     #[serde(skip_serializing_if = "Option::is_none")]
     nsfw: Option<bool>,
+    /// Code of a template to copy channels, roles and permissions from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    template: Option<String>,
 }
 
 /// # Create Server Response
@@ -41,6 +44,7 @@ pub struct CreateServerResponse {
 #[post("/create", data = "<info>")]
 pub async fn req(
     db: &Db,
+    database: &State<revolt_database::Database>,
     user: User,
     info: Json<DataCreateServer>,
 ) -> Result<Json<CreateServerResponse>> {
@@ -62,8 +66,47 @@ pub async fn req(
         name,
         description,
         nsfw,
+        template,
     } = info;
 
+    if let Some(code) = template {
+        let template = database
+            .fetch_server_template(&code)
+            .await
+            .map_err(|_| Error::NotFound)?;
+
+        let mut server = revolt_database::Server {
+            id: Ulid::new().to_string(),
+            owner: user.id.clone(),
+            name,
+            description,
+            nsfw: nsfw.unwrap_or(false),
+            ..Default::default()
+        };
+
+        let channels = template.snapshot.apply(&mut server);
+        server.create(database).await.map_err(Error::from_core)?;
+
+        // Don't leave a half-built server behind if any channel fails to save
+        for (index, channel) in channels.iter().enumerate() {
+            if let Err(error) = channel.create(database).await {
+                for channel in &channels[..index] {
+                    database.delete_channel(channel).await.ok();
+                }
+
+                database.delete_server(&server.id).await.ok();
+                return Err(Error::from_core(error));
+            }
+        }
+
+        let server = db.fetch_server(&server.id).await?;
+        let channels = db.fetch_channels(&server.channels).await?;
+        let channels = server
+            .create_member(db, user, Some(channels), Default::default())
+            .await?;
+        return Ok(Json(CreateServerResponse { server, channels }));
+    }
+
     let channel_id = Ulid::new().to_string();
     let server_id = Ulid::new().to_string();
 
@@ -81,6 +124,8 @@ pub async fn req(
         role_permissions: HashMap::new(),
 
         nsfw: nsfw.unwrap_or(false),
+        slowmode_seconds: 0,
+        announcement: false,
     };
 
     db.insert_channel(&channel).await?;
@@ -97,6 +142,8 @@ pub async fn req(
     };
 
     server.create(db).await?;
-    let channels = server.create_member(db, user, Some(vec![channel])).await?;
+    let channels = server
+        .create_member(db, user, Some(vec![channel]), Default::default())
+        .await?;
     Ok(Json(CreateServerResponse { server, channels }))
 }
@@ -1,7 +1,9 @@
+use revolt_database::{ChannelFollower, Database};
 use revolt_quark::{
     models::{server_member::RemovalIntention, User},
-    Db, EmptyResponse, Ref, Result,
+    Db, EmptyResponse, Error, Ref, Result,
 };
+use rocket::State;
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -19,6 +21,7 @@ pub struct OptionsServerDelete {
 #[delete("/<target>?<options..>")]
 pub async fn req(
     db: &Db,
+    database: &State<Database>,
     user: User,
     target: Ref,
     options: OptionsServerDelete,
@@ -27,7 +30,13 @@ pub async fn req(
     let member = db.fetch_member(&target.id, &user.id).await?;
 
     if server.owner == user.id {
-        server.delete(db).await
+        let channels = server.channels.clone();
+        server.delete(db).await?;
+
+        // Other servers stop following announcement channels that no longer exist
+        ChannelFollower::delete_for_channels(database, &channels)
+            .await
+            .map_err(Error::from_core)
     } else {
         server
             .remove_member(
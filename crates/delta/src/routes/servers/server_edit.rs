@@ -139,6 +139,11 @@ pub async fn req(
 
     // 1. Remove fields from object
     if let Some(fields) = &remove {
+        // Pending ownership transfers are only cleared by cancelling them
+        if fields.contains(&FieldsServer::PendingOwner) {
+            return Err(Error::InvalidOperation);
+        }
+
         if fields.contains(&FieldsServer::Banner) {
             if let Some(banner) = &server.banner {
                 db.mark_attachment_as_deleted(&banner.id).await?;
@@ -163,7 +168,7 @@ pub async fn req(
 
     if let Some(categories) = &mut partial.categories {
         let mut channel_ids = HashSet::new();
-        for category in categories {
+        for category in categories.iter_mut() {
             for channel in &category.channels {
                 if channel_ids.contains(channel) {
                     return Err(Error::InvalidOperation);
@@ -175,6 +180,48 @@ pub async fn req(
             category
                 .channels
                 .retain(|item| server.channels.contains(item));
+
+            // Permissions and sync state are managed through their own routes
+            let existing = server
+                .categories
+                .iter()
+                .flatten()
+                .find(|existing| existing.id == category.id);
+
+            category.default_permissions =
+                existing.and_then(|existing| existing.default_permissions);
+            category.role_permissions = existing
+                .map(|existing| existing.role_permissions.clone())
+                .unwrap_or_default();
+            category.synced = existing
+                .map(|existing| {
+                    existing
+                        .synced
+                        .iter()
+                        .filter(|id| category.channels.contains(id))
+                        .cloned()
+                        .collect()
+                })
+                .unwrap_or_default();
+        }
+
+        // Channels leaving the category they were synced to keep their current permissions
+        let desynced: Vec<String> = server
+            .categories
+            .iter()
+            .flatten()
+            .flat_map(|existing| existing.synced.iter())
+            .filter(|id| {
+                !categories
+                    .iter()
+                    .any(|category| category.synced.contains(id))
+            })
+            .cloned()
+            .collect();
+
+        for id in desynced {
+            let mut channel = db.fetch_channel(&id).await?;
+            server.desync_channel(db, &mut channel).await?;
         }
     }
 
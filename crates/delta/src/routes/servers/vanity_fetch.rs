@@ -0,0 +1,24 @@
+use revolt_database::{
+    util::permissions::DatabasePermissionQuery, util::reference::Reference, Database, Invite, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Vanity Invite
+///
+/// Fetch the custom invite code of a server.
+#[openapi(tag = "Invites")]
+#[get("/<target>/vanity")]
+pub async fn req(db: &State<Database>, user: User, target: Reference) -> Result<Json<v0::Invite>> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ViewChannel)?;
+
+    Invite::fetch_vanity(db, &server.id)
+        .await
+        .map(|invite| Json(invite.into()))
+}
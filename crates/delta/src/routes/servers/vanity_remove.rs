@@ -0,0 +1,46 @@
+use revolt_database::{
+    util::{
+        audit_log_reason::AuditLogReason, permissions::DatabasePermissionQuery,
+        reference::Reference,
+    },
+    AuditLogEntry, Database, Invite, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::Result;
+use rocket::State;
+use rocket_empty::EmptyResponse;
+
+/// # Remove Vanity Invite
+///
+/// Release the custom invite code of a server.
+#[openapi(tag = "Invites")]
+#[delete("/<target>/vanity")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    reason: AuditLogReason,
+) -> Result<EmptyResponse> {
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let invite = Invite::fetch_vanity(db, &server.id).await?;
+    db.delete_invite(invite.code()).await?;
+
+    AuditLogEntry::record(
+        db,
+        server.id,
+        user.id,
+        v0::AuditLogAction::VanityUpdate,
+        None,
+        reason.into_inner(),
+        AuditLogEntry::diff(&Some(invite.code().to_string()), &None),
+    )
+    .await;
+
+    Ok(EmptyResponse)
+}
@@ -0,0 +1,135 @@
+use revolt_database::{
+    util::{
+        audit_log_reason::AuditLogReason, permissions::DatabasePermissionQuery,
+        reference::Reference,
+    },
+    AuditLogEntry, Database, Invite, User,
+};
+use revolt_models::v0;
+use revolt_permissions::{calculate_server_permissions, ChannelPermission};
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Set Vanity Invite
+///
+/// Claim a custom invite code for a server, replacing any it had before.
+#[openapi(tag = "Invites")]
+#[put("/<target>/vanity", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    target: Reference,
+    data: Json<v0::DataEditVanity>,
+    reason: AuditLogReason,
+) -> Result<Json<v0::Invite>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let server = target.as_server(db).await?;
+    let mut query = DatabasePermissionQuery::new(db, &user).server(&server);
+    calculate_server_permissions(&mut query)
+        .await
+        .throw_if_lacking_channel_permission(ChannelPermission::ManageServer)?;
+
+    let channel = data
+        .channel
+        .or_else(|| server.channels.first().cloned())
+        .ok_or_else(|| create_error!(UnknownChannel))?;
+
+    let previous = Invite::fetch_vanity(db, &server.id)
+        .await
+        .ok()
+        .map(|invite| invite.code().to_string());
+
+    let invite = Invite::set_vanity(db, &server, user.id.clone(), data.code, channel).await?;
+
+    AuditLogEntry::record(
+        db,
+        server.id,
+        user.id,
+        v0::AuditLogAction::VanityUpdate,
+        None,
+        reason.into_inner(),
+        AuditLogEntry::diff(&previous, &Some(invite.code().to_string())),
+    )
+    .await;
+
+    Ok(Json(invite.into()))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rocket, util::test::TestHarness};
+    use revolt_database::Member;
+    use revolt_models::v0;
+    use rocket::http::{Header, Status};
+    use serde_json::json;
+
+    #[rocket::async_test]
+    async fn set_vanity() {
+        let harness = TestHarness::new().await;
+        let (_, session, owner) = harness.new_user().await;
+        let (_, other_session, other) = harness.new_user().await;
+
+        let server = harness
+            .new_server_with(&owner, |server| {
+                server.channels = vec![ulid::Ulid::new().to_string()]
+            })
+            .await;
+        Member::create(&harness.db, &server, &other).await.unwrap();
+
+        let code = TestHarness::rand_string().to_lowercase();
+
+        // Members need permission to manage the server
+        let response = harness
+            .client
+            .put(format!("/servers/{}/vanity", server.id))
+            .header(Header::new(
+                "x-session-token",
+                other_session.token.to_string(),
+            ))
+            .json(&json!({ "code": code }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+
+        for invalid in ["ab", "Upper", "with space"] {
+            let response = harness
+                .client
+                .put(format!("/servers/{}/vanity", server.id))
+                .header(Header::new("x-session-token", session.token.to_string()))
+                .json(&json!({ "code": invalid }))
+                .dispatch()
+                .await;
+
+            assert_eq!(response.status(), Status::BadRequest);
+        }
+
+        let response = harness
+            .client
+            .put(format!("/servers/{}/vanity", server.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .json(&json!({ "code": code }))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+        match response.into_json::<v0::Invite>().await.unwrap() {
+            v0::Invite::Server {
+                code: invite_code,
+                channel,
+                ..
+            } => {
+                assert_eq!(invite_code, code);
+                assert_eq!(channel, server.channels[0]);
+            }
+            _ => unreachable!(),
+        }
+    }
+}
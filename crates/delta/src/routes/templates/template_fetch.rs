@@ -0,0 +1,15 @@
+use revolt_database::Database;
+use revolt_models::v0;
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Template
+///
+/// Fetch a server template by its code.
+#[openapi(tag = "Server Templates")]
+#[get("/<code>")]
+pub async fn req(db: &State<Database>, code: String) -> Result<Json<v0::ServerTemplate>> {
+    db.fetch_server_template(&code)
+        .await
+        .map(|template| Json(template.into()))
+}
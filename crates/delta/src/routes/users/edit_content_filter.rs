@@ -0,0 +1,44 @@
+use revolt_database::{ContentFilter, Database, PartialContentFilter, User};
+use revolt_models::v0;
+use revolt_result::{create_error, Result};
+use rocket::{serde::json::Json, State};
+use validator::Validate;
+
+/// # Edit Content Filter
+///
+/// Change which messages and attachments are hidden in your direct messages and groups.
+#[openapi(tag = "User Information")]
+#[patch("/@me/content_filter", data = "<data>")]
+pub async fn req(
+    db: &State<Database>,
+    user: User,
+    data: Json<v0::DataEditContentFilter>,
+) -> Result<Json<v0::ContentFilter>> {
+    let data = data.into_inner();
+    data.validate().map_err(|error| {
+        create_error!(FailedValidation {
+            error: error.to_string()
+        })
+    })?;
+
+    let v0::DataEditContentFilter {
+        words,
+        hide_stranger_attachments,
+        direct_messages,
+    } = data;
+
+    let mut filter = ContentFilter::fetch(db, &user.id).await?;
+    filter
+        .update(
+            db,
+            PartialContentFilter {
+                words,
+                hide_stranger_attachments,
+                direct_messages,
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    Ok(Json(filter.into()))
+}
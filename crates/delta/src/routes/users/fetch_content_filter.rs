@@ -0,0 +1,13 @@
+use revolt_database::{ContentFilter, Database, User};
+use revolt_models::v0;
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Content Filter
+///
+/// Retrieve the filter applied to your direct messages and groups.
+#[openapi(tag = "User Information")]
+#[get("/@me/content_filter")]
+pub async fn req(db: &State<Database>, user: User) -> Result<Json<v0::ContentFilter>> {
+    Ok(Json(ContentFilter::fetch(db, &user.id).await?.into()))
+}
@@ -0,0 +1,21 @@
+use revolt_database::{Database, User};
+use revolt_models::v0;
+use revolt_result::Result;
+use rocket::{serde::json::Json, State};
+
+/// # Fetch Message Requests
+///
+/// Fetch direct messages started by users who are not your friends and still awaiting a response.
+#[openapi(tag = "Direct Messaging")]
+#[get("/message_requests")]
+pub async fn req(db: &State<Database>, user: User) -> Result<Json<Vec<v0::Channel>>> {
+    db.find_direct_messages(&user.id).await.map(|channels| {
+        Json(
+            channels
+                .into_iter()
+                .filter(|channel| channel.is_message_request_for(&user.id))
+                .map(Into::into)
+                .collect(),
+        )
+    })
+}
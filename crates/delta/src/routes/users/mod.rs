@@ -4,8 +4,11 @@ use rocket::Route;
 mod add_friend;
 mod block_user;
 mod change_username;
+mod edit_content_filter;
 mod edit_user;
+mod fetch_content_filter;
 mod fetch_dms;
+mod fetch_message_requests;
 mod fetch_profile;
 mod fetch_self;
 mod fetch_user;
@@ -27,9 +30,12 @@ pub fn routes() -> (Vec<Route>, OpenApi) {
         change_username::req,
         get_default_avatar::req,
         fetch_profile::req,
+        fetch_content_filter::req,
+        edit_content_filter::req,
         // Direct Messaging
         fetch_dms::req,
         open_dm::req,
+        fetch_message_requests::req,
         // Relationships
         find_mutual::req,
         add_friend::req,
@@ -1,6 +1,8 @@
+use revolt_database::ContentFilter;
 use revolt_quark::{
-    models::{Channel, User},
-    Database, Ref, Result,
+    get_relationship,
+    models::{user::RelationshipStatus, Channel, User},
+    Database, Error, Ref, Result,
 };
 
 use rocket::{serde::json::Json, State};
@@ -13,7 +15,12 @@ use ulid::Ulid;
 /// If the target is oneself, a saved messages channel is returned.
 #[openapi(tag = "Direct Messaging")]
 #[get("/<target>/dm")]
-pub async fn req(db: &State<Database>, user: User, target: Ref) -> Result<Json<Channel>> {
+pub async fn req(
+    db: &State<Database>,
+    database: &State<revolt_database::Database>,
+    user: User,
+    target: Ref,
+) -> Result<Json<Channel>> {
     let target = target.as_user(db).await?;
 
     // If the target is oneself, open saved messages.
@@ -36,11 +43,39 @@ pub async fn req(db: &State<Database>, user: User, target: Ref) -> Result<Json<C
         return Ok(Json(channel));
     }
 
+    // Respect the target's wish not to hear from strangers
+    let is_friend = matches!(
+        get_relationship(&user, &target.id),
+        RelationshipStatus::Friend
+    );
+
+    let filter = ContentFilter::fetch(database, &target.id)
+        .await
+        .map_err(Error::from_core)?;
+
+    if !filter.accepts_request_from(is_friend) {
+        return Err(Error::NotFriends);
+    }
+
+    // Requests are for strangers met through a server, bots have no inbox to review them from
+    let requested_by = if target.bot.is_none()
+        && filter.requires_request_from(is_friend)
+        && !db
+            .fetch_mutual_server_ids(&user.id, &target.id)
+            .await?
+            .is_empty()
+    {
+        Some(user.id.clone())
+    } else {
+        None
+    };
+
     let new_channel = Channel::DirectMessage {
         id: Ulid::new().to_string(),
         active: false,
         recipients: vec![user.id, target.id],
         last_message_id: None,
+        requested_by,
     };
 
     new_channel.create(db).await?;
@@ -49,7 +84,8 @@ pub async fn req(db: &State<Database>, user: User, target: Ref) -> Result<Json<C
 
 #[cfg(test)]
 mod tests {
-    use revolt_database::Bot;
+    use revolt_database::{Bot, ContentFilter, Member, PartialContentFilter, Server};
+    use revolt_models::v0::DirectMessagePrivacy;
     use revolt_quark::models::Channel;
     use rocket::http::{Header, Status};
 
@@ -87,4 +123,92 @@ mod tests {
             _ => unreachable!(),
         }
     }
+
+    #[rocket::async_test]
+    async fn stranger_blocked_by_content_filter() {
+        let harness = TestHarness::new().await;
+        let (_, session, _) = harness.new_user().await;
+        let (_, _, target) = harness.new_user().await;
+
+        let mut filter = ContentFilter::fetch(&harness.db, &target.id)
+            .await
+            .expect("`ContentFilter`");
+
+        filter
+            .update(
+                &harness.db,
+                PartialContentFilter {
+                    direct_messages: Some(DirectMessagePrivacy::Friends),
+                    ..Default::default()
+                },
+            )
+            .await
+            .expect("`ContentFilter` update");
+
+        let response = harness
+            .client
+            .get(format!("/users/{}/dm", target.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Forbidden);
+    }
+
+    #[rocket::async_test]
+    async fn stranger_starts_message_request() {
+        let harness = TestHarness::new().await;
+        let (_, session, user) = harness.new_user().await;
+        let (_, _, target) = harness.new_user().await;
+
+        let server = Server {
+            id: ulid::Ulid::new().to_string(),
+            owner: target.id.to_string(),
+            name: TestHarness::rand_string(),
+            ..Default::default()
+        };
+
+        server.create(&harness.db).await.unwrap();
+        Member::create(&harness.db, &server, &user).await.unwrap();
+        Member::create(&harness.db, &server, &target).await.unwrap();
+
+        let response = harness
+            .client
+            .get(format!("/users/{}/dm", target.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let channel = response.into_json::<Channel>().await.unwrap();
+        match channel {
+            Channel::DirectMessage { requested_by, .. } => {
+                assert_eq!(requested_by, Some(user.id))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    #[rocket::async_test]
+    async fn no_message_request_without_mutual_server() {
+        let harness = TestHarness::new().await;
+        let (_, session, _) = harness.new_user().await;
+        let (_, _, target) = harness.new_user().await;
+
+        let response = harness
+            .client
+            .get(format!("/users/{}/dm", target.id))
+            .header(Header::new("x-session-token", session.token.to_string()))
+            .dispatch()
+            .await;
+
+        assert_eq!(response.status(), Status::Ok);
+
+        let channel = response.into_json::<Channel>().await.unwrap();
+        match channel {
+            Channel::DirectMessage { requested_by, .. } => assert!(requested_by.is_none()),
+            _ => unreachable!(),
+        }
+    }
 }
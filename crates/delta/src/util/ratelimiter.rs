@@ -110,8 +110,10 @@ fn resolve_bucket<'r>(request: &'r rocket::Request<'_>) -> (&'r str, Option<&'r
             ("bots", _, _) => ("bots", None),
             ("channels", Some(id), _) => {
                 if request.method() == Method::Post {
-                    if let Some("messages") = request.routed_segment(2) {
-                        return ("messaging", Some(id));
+                    match request.routed_segment(2) {
+                        Some("messages") => return ("messaging", Some(id)),
+                        Some("exports") => return ("channel_exports", Some(id)),
+                        _ => {}
                     }
                 }
 
@@ -143,6 +145,7 @@ fn resolve_bucket_limit(bucket: &str) -> u8 {
         "bots" => 10,
         "messaging" => 10,
         "channels" => 15,
+        "channel_exports" => 2,
         "servers" => 5,
         "auth" => 15,
         "auth_delete" => 255,
@@ -1,8 +1,9 @@
 use futures::StreamExt;
 use rand::Rng;
 use redis_kiss::redis::aio::PubSub;
-use revolt_database::{events::client::EventV1, Database, User};
+use revolt_database::{events::client::EventV1, Database, Server, User};
 use revolt_models::v0;
+use revolt_permissions::DEFAULT_PERMISSION_SERVER;
 use revolt_quark::authifier::{
     models::{Account, Session},
     Authifier,
@@ -88,6 +89,27 @@ impl TestHarness {
         (account, session, user)
     }
 
+    pub async fn new_server(&self, owner: &User) -> Server {
+        self.new_server_with(owner, |_| {}).await
+    }
+
+    pub async fn new_server_with<F>(&self, owner: &User, setup: F) -> Server
+    where
+        F: FnOnce(&mut Server),
+    {
+        let mut server = Server {
+            id: ulid::Ulid::new().to_string(),
+            owner: owner.id.to_string(),
+            name: TestHarness::rand_string(),
+            default_permissions: *DEFAULT_PERMISSION_SERVER as i64,
+            ..Default::default()
+        };
+
+        setup(&mut server);
+        server.create(&self.db).await.expect("`Server`");
+        server
+    }
+
     pub async fn new_account_session(&self) -> (Account, Session) {
         let account = Account::new(
             &self.authifier,
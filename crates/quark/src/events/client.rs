@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::models::channel::{FieldsChannel, PartialChannel};
 use crate::models::message::{AppendMessage, PartialMessage};
+use crate::models::report::{FieldsReport, PartialReport, ReportStatus};
 use crate::models::server::{FieldsRole, FieldsServer, PartialRole, PartialServer};
 use crate::models::server_member::{FieldsMember, MemberCompositeKey, PartialMember};
 use crate::models::user::{FieldsUser, PartialUser, RelationshipStatus};
@@ -78,6 +79,8 @@ pub enum EventV1 {
         id: String,
         channel: String,
         data: PartialMessage,
+        #[serde(default)]
+        clear: Vec<v0::FieldsMessage>,
     },
 
     /// Append information to existing message
@@ -148,6 +151,23 @@ pub enum EventV1 {
         message_id: String,
     },
 
+    /// Channel export progressed
+    ChannelExportUpdate {
+        id: String,
+        channel: String,
+        status: v0::ExportStatus,
+        processed: u32,
+    },
+
+    /// Bulk role assignment job progressed
+    RoleJobUpdate {
+        id: String,
+        server: String,
+        status: v0::RoleJobStatus,
+        processed: u32,
+        affected: u32,
+    },
+
     /// New server
     ServerCreate {
         id: String,
@@ -178,6 +198,12 @@ pub enum EventV1 {
     /// User left server
     ServerMemberLeave { id: String, user: String },
 
+    /// User ban lifted
+    ServerMemberUnban { id: String, user: String },
+
+    /// Member submitted screening which awaits moderator approval
+    ServerMemberScreeningSubmit { id: String, user: String },
+
     /// Server role created or updated
     ServerRoleUpdate {
         id: String,
@@ -189,6 +215,28 @@ pub enum EventV1 {
     /// Server role deleted
     ServerRoleDelete { id: String, role_id: String },
 
+    /// Server event scheduled
+    ServerEventCreate(v0::ServerEvent),
+
+    /// Server event updated or started
+    ServerEventUpdate {
+        id: String,
+        event_id: String,
+        data: v0::PartialServerEvent,
+        clear: Vec<v0::FieldsServerEvent>,
+    },
+
+    /// Member changed whether they are attending a server event
+    ServerEventRsvp {
+        id: String,
+        event_id: String,
+        user: String,
+        attending: bool,
+    },
+
+    /// Server event deleted
+    ServerEventDelete { id: String, event_id: String },
+
     /// Update existing user
     UserUpdate {
         id: String,
@@ -208,6 +256,12 @@ pub enum EventV1 {
     /// Settings updated remotely
     UserSettingsUpdate { id: String, update: UserSettings },
 
+    /// Content filter updated
+    UserContentFilterUpdate {
+        id: String,
+        filter: v0::ContentFilter,
+    },
+
     /// User has been platform banned or deleted their account
     ///
     /// Clients should remove the following associated data:
@@ -241,6 +295,16 @@ pub enum EventV1 {
     /// New report
     ReportCreate(Report),
 
+    /// Report updated by a moderator
+    ReportUpdate {
+        id: String,
+        data: PartialReport,
+        clear: Vec<FieldsReport>,
+    },
+
+    /// Report the user created has been closed
+    ReportClose { id: String, status: ReportStatus },
+
     /// Auth events
     Auth(AuthifierEvent),
 }
@@ -10,6 +10,7 @@ use crate::{
     perms, Database, Permission, Result,
 };
 
+use revolt_database::ContentFilter;
 use revolt_presence::filter_online;
 
 use super::{
@@ -22,7 +23,10 @@ impl Cache {
     /// Check whether the current user can view a channel
     pub async fn can_view_channel(&self, db: &Database, channel: &Channel) -> bool {
         match &channel {
-            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+            Channel::TextChannel { server, .. }
+            | Channel::VoiceChannel { server, .. }
+            | Channel::ForumChannel { server, .. }
+            | Channel::ForumPost { server, .. } => {
                 let member = self.members.get(server);
                 let server = self.servers.get(server);
                 let mut perms = perms(self.users.get(&self.user_id).unwrap()).channel(channel);
@@ -60,6 +64,15 @@ impl Cache {
         viewable_channels
     }
 
+    /// Check whether the current user's content filter applies in a channel
+    pub fn is_filtered_channel(&self, channel_id: &str) -> bool {
+        self.content_filter.is_some()
+            && matches!(
+                self.channels.get(channel_id),
+                Some(Channel::DirectMessage { .. } | Channel::Group { .. })
+            )
+    }
+
     /// Check whether we can subscribe to another user
     pub fn can_subscribe_to_user(&self, user_id: &str) -> bool {
         if let Some(user) = self.users.get(&self.user_id) {
@@ -110,6 +123,11 @@ impl State {
 
         // Fetch all memberships with their corresponding servers.
         let members: Vec<Member> = db.fetch_all_memberships(&user.id).await?;
+
+        // Fetch the filter applied to direct messages and groups.
+        self.cache.content_filter = ContentFilter::fetch(&db.clone().into(), &user.id)
+            .await
+            .ok();
         self.cache.members = members
             .iter()
             .cloned()
@@ -227,7 +245,10 @@ impl State {
             let id = &id.to_string();
             for (channel_id, channel) in &self.cache.channels {
                 match channel {
-                    Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
+                    Channel::TextChannel { server, .. }
+                    | Channel::VoiceChannel { server, .. }
+                    | Channel::ForumChannel { server, .. }
+                    | Channel::ForumPost { server, .. } => {
                         if server == id {
                             channel_ids.insert(channel_id.clone());
 
@@ -524,6 +545,42 @@ impl State {
 
                 *event_id = None;
             }
+            EventV1::UserContentFilterUpdate { .. } => {
+                self.cache.content_filter =
+                    ContentFilter::fetch(&db.clone().into(), &self.cache.user_id)
+                        .await
+                        .ok();
+            }
+            EventV1::Message(message) => {
+                if self.cache.is_filtered_channel(&message.channel) {
+                    if let (Some(filter), Some(user)) = (
+                        &self.cache.content_filter,
+                        self.cache.users.get(&self.cache.user_id),
+                    ) {
+                        if !message.apply_content_filter(filter, user) {
+                            return false;
+                        }
+                    }
+                }
+            }
+            EventV1::MessageUpdate {
+                id, channel, data, ..
+            } => {
+                if self.cache.is_filtered_channel(channel)
+                    && self
+                        .cache
+                        .content_filter
+                        .as_ref()
+                        .zip(data.content.as_deref())
+                        .is_some_and(|(filter, content)| filter.hides_content(content))
+                {
+                    // Edited into something we filter, make it disappear instead
+                    *event = EventV1::MessageDelete {
+                        id: id.clone(),
+                        channel: channel.clone(),
+                    };
+                }
+            }
             EventV1::UserRelationship { id, user, .. } => {
                 self.cache.users.insert(id.clone(), user.clone());
 
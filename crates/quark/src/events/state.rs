@@ -1,6 +1,7 @@
 use std::collections::{HashMap, HashSet};
 
 use lru::LruCache;
+use revolt_database::ContentFilter;
 
 use crate::models::{Channel, Member, Server, User};
 
@@ -37,6 +38,7 @@ pub struct Cache {
     pub members: HashMap<String, Member>,
     pub servers: HashMap<String, Server>,
 
+    pub content_filter: Option<ContentFilter>,
     pub seen_events: LruCache<String, ()>,
 }
 
@@ -50,6 +52,7 @@ impl Default for Cache {
             members: Default::default(),
             servers: Default::default(),
 
+            content_filter: None,
             seen_events: LruCache::new(20),
         }
     }
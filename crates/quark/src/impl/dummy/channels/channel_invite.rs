@@ -11,6 +11,12 @@ impl AbstractChannelInvite for DummyDb {
             server: "server".into(),
             creator: "creator".into(),
             channel: "channel".into(),
+            expires_at: None,
+            max_uses: None,
+            uses: 0,
+            temporary: false,
+            roles: vec![],
+            vanity: false,
         })
     }
 
@@ -45,6 +45,19 @@ impl AbstractMessage for DummyDb {
         Ok(vec![])
     }
 
+    async fn fetch_forwarded_messages(&self, _ids: &[String]) -> Result<Vec<Message>> {
+        Ok(vec![])
+    }
+
+    async fn fetch_forwards_from_channels(&self, _channels: &[String]) -> Result<Vec<Message>> {
+        Ok(vec![])
+    }
+
+    async fn mark_forwards_as_deleted(&self, ids: &[String]) -> Result<()> {
+        info!("Mark forwards of {ids:?} as deleted");
+        Ok(())
+    }
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()> {
         info!("Add to {id} with {emoji} and {user}");
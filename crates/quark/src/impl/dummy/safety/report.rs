@@ -1,6 +1,8 @@
-use crate::models::report::PartialReport;
+use crate::models::report::{
+    FieldsReport, PartialReport, ReportComment, ReportQuery, ReportStatus,
+};
 use crate::models::Report;
-use crate::{AbstractReport, Result};
+use crate::{AbstractReport, Error, Result};
 
 use super::super::DummyDb;
 
@@ -11,15 +13,32 @@ impl AbstractReport for DummyDb {
         Ok(())
     }
 
-    async fn update_report(&self, _id: &str, _report: &PartialReport) -> Result<()> {
-        todo!()
+    async fn update_report(
+        &self,
+        id: &str,
+        report: &PartialReport,
+        remove: Vec<FieldsReport>,
+    ) -> Result<()> {
+        info!("Update {id} with {report:?} and remove {remove:?}");
+        Ok(())
+    }
+
+    async fn add_report_comment(&self, id: &str, comment: &ReportComment) -> Result<()> {
+        info!("Comment {comment:?} on {id}");
+        Ok(())
+    }
+
+    async fn close_report(&self, id: &str, status: &ReportStatus) -> Result<()> {
+        info!("Close {id} with {status:?}");
+        Ok(())
     }
 
-    async fn fetch_report(&self, _report_id: &str) -> Result<Report> {
-        todo!()
+    async fn fetch_report(&self, report_id: &str) -> Result<Report> {
+        info!("Fetch {report_id}");
+        Err(Error::NotFound)
     }
 
-    async fn fetch_reports(&self) -> Result<Vec<Report>> {
+    async fn fetch_reports(&self, _query: ReportQuery) -> Result<Vec<Report>> {
         Ok(vec![])
     }
 }
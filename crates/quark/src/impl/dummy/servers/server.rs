@@ -28,6 +28,8 @@ impl AbstractServer for DummyDb {
             nsfw: false,
             analytics: true,
             discoverable: true,
+
+            pending_owner: None,
         })
     }
 
@@ -13,6 +13,7 @@ impl AbstractServerBan for DummyDb {
                 user: user.into(),
             },
             reason: Some("ban reason".into()),
+            expires: None,
         })
     }
 
@@ -24,7 +24,9 @@ impl Channel {
             | Channel::Group { id, .. }
             | Channel::SavedMessages { id, .. }
             | Channel::TextChannel { id, .. }
-            | Channel::VoiceChannel { id, .. } => id,
+            | Channel::VoiceChannel { id, .. }
+            | Channel::ForumChannel { id, .. }
+            | Channel::ForumPost { id, .. } => id,
         }
     }
 
@@ -35,7 +37,9 @@ impl Channel {
             | Channel::Group { id, .. }
             | Channel::SavedMessages { id, .. }
             | Channel::TextChannel { id, .. }
-            | Channel::VoiceChannel { id, .. } => id,
+            | Channel::VoiceChannel { id, .. }
+            | Channel::ForumChannel { id, .. }
+            | Channel::ForumPost { id, .. } => id,
         }
     }
 
@@ -56,7 +60,10 @@ impl Channel {
                     event.clone().private(recipient.clone()).await;
                 }
             }
-            Self::TextChannel { server, .. } | Self::VoiceChannel { server, .. } => {
+            Self::TextChannel { server, .. }
+            | Self::VoiceChannel { server, .. }
+            | Self::ForumChannel { server, .. }
+            | Self::ForumPost { server, .. } => {
                 event.p(server.clone()).await;
             }
         }
@@ -86,7 +93,10 @@ impl Channel {
             clear: remove,
         }
         .p(match self {
-            Self::TextChannel { server, .. } | Self::VoiceChannel { server, .. } => server.clone(),
+            Self::TextChannel { server, .. }
+            | Self::VoiceChannel { server, .. }
+            | Self::ForumChannel { server, .. }
+            | Self::ForumPost { server, .. } => server.clone(),
             _ => id,
         })
         .await;
@@ -94,11 +104,33 @@ impl Channel {
         Ok(())
     }
 
+    /// Throw an error if the given user is timed out in this channel's server
+    pub async fn throw_if_timed_out(&self, db: &Database, user_id: &str) -> Result<()> {
+        if let Channel::TextChannel { server, .. }
+        | Channel::VoiceChannel { server, .. }
+        | Channel::ForumChannel { server, .. }
+        | Channel::ForumPost { server, .. } = self
+        {
+            if let Ok(member) = db.fetch_member(server, user_id).await {
+                if let Some(timeout) = member.timeout.filter(|_| member.in_timeout()) {
+                    return Err(Error::InTimeout {
+                        until: timeout.format().to_string(),
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Delete a channel
     pub async fn delete(self, db: &Database) -> Result<()> {
         let id = self.id().to_string();
-        EventV1::ChannelDelete { id: id.clone() }.p(id).await;
-        db.delete_channel(&self).await
+        EventV1::ChannelDelete { id: id.clone() }
+            .p(id.clone())
+            .await;
+        db.delete_channel(&self).await?;
+        Message::detach_channel_forwards(db, &[id]).await
     }
 
     /// Remove a field from Channel object
@@ -107,7 +139,8 @@ impl Channel {
             FieldsChannel::Description => match self {
                 Self::Group { description, .. }
                 | Self::TextChannel { description, .. }
-                | Self::VoiceChannel { description, .. } => {
+                | Self::VoiceChannel { description, .. }
+                | Self::ForumChannel { description, .. } => {
                     description.take();
                 }
                 _ => {}
@@ -115,7 +148,8 @@ impl Channel {
             FieldsChannel::Icon => match self {
                 Self::Group { icon, .. }
                 | Self::TextChannel { icon, .. }
-                | Self::VoiceChannel { icon, .. } => {
+                | Self::VoiceChannel { icon, .. }
+                | Self::ForumChannel { icon, .. } => {
                     icon.take();
                 }
                 _ => {}
@@ -128,16 +162,55 @@ impl Channel {
                 | Self::VoiceChannel {
                     default_permissions,
                     ..
+                }
+                | Self::ForumChannel {
+                    default_permissions,
+                    ..
                 } => {
                     default_permissions.take();
                 }
                 _ => {}
             },
+            FieldsChannel::RequestedBy => {
+                if let Self::DirectMessage { requested_by, .. } = self {
+                    requested_by.take();
+                }
+            }
         }
     }
 
     /// Apply partial channel to channel
     pub fn apply_options(&mut self, partial: PartialChannel) {
+        if let Self::TextChannel {
+            slowmode_seconds,
+            announcement,
+            ..
+        } = self
+        {
+            if let Some(v) = partial.slowmode_seconds {
+                *slowmode_seconds = v;
+            }
+
+            if let Some(v) = partial.announcement {
+                *announcement = v;
+            }
+        }
+
+        if let Self::ForumChannel {
+            available_tags,
+            default_sort_order,
+            ..
+        } = self
+        {
+            if let Some(v) = partial.available_tags {
+                *available_tags = v;
+            }
+
+            if let Some(v) = partial.default_sort_order {
+                *default_sort_order = v;
+            }
+        }
+
         // ! FIXME: maybe flatten channel object?
         match self {
             Self::DirectMessage { active, .. } => {
@@ -195,6 +268,15 @@ impl Channel {
                 default_permissions,
                 role_permissions,
                 ..
+            }
+            | Self::ForumChannel {
+                name,
+                description,
+                icon,
+                nsfw,
+                default_permissions,
+                role_permissions,
+                ..
             } => {
                 if let Some(v) = partial.name {
                     *name = v;
@@ -220,6 +302,15 @@ impl Channel {
                     default_permissions.replace(v);
                 }
             }
+            Self::ForumPost { name, tags, .. } => {
+                if let Some(v) = partial.name {
+                    *name = v;
+                }
+
+                if let Some(v) = partial.tags {
+                    *tags = v;
+                }
+            }
             _ => {}
         }
     }
@@ -324,6 +415,7 @@ impl Channel {
                         .ok();
                     } else {
                         db.delete_channel(self).await?;
+                        Message::detach_channel_forwards(db, &[id.to_string()]).await?;
                         return Ok(());
                     }
                 }
@@ -379,6 +471,12 @@ impl Channel {
                 server,
                 role_permissions,
                 ..
+            }
+            | Channel::ForumChannel {
+                id,
+                server,
+                role_permissions,
+                ..
             } => {
                 db.set_channel_role_permission(id, role, permissions)
                     .await?;
@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use nanoid::nanoid;
 
 use crate::{
@@ -26,6 +27,15 @@ impl Invite {
         }
     }
 
+    /// Check whether this invite has run its course
+    pub fn is_expired(&self) -> bool {
+        match self {
+            Invite::Server { expires_at, .. } | Invite::Group { expires_at, .. } => {
+                expires_at.is_some_and(|expires_at| *expires_at <= *Timestamp::now_utc())
+            }
+        }
+    }
+
     /// Create a new invite from given information
     pub async fn create(db: &Database, creator: &User, target: &Channel) -> Result<Invite> {
         let code = nanoid!(8, &ALPHABET);
@@ -34,13 +44,24 @@ impl Invite {
                 code,
                 creator: creator.id.clone(),
                 channel: id.clone(),
+                expires_at: None,
+                max_uses: None,
+                uses: 0,
             }),
-            Channel::TextChannel { id, server, .. } | Channel::VoiceChannel { id, server, .. } => {
+            Channel::TextChannel { id, server, .. }
+            | Channel::VoiceChannel { id, server, .. }
+            | Channel::ForumChannel { id, server, .. } => {
                 Ok(Invite::Server {
                     code,
                     creator: creator.id.clone(),
                     server: server.clone(),
                     channel: id.clone(),
+                    expires_at: None,
+                    max_uses: None,
+                    uses: 0,
+                    temporary: false,
+                    roles: vec![],
+                    vanity: false,
                 })
             }
             _ => Err(Error::InvalidOperation),
@@ -52,7 +73,12 @@ impl Invite {
 
     /// Resolve an invite by its ID or by a public server ID
     pub async fn find(db: &Database, code: &str) -> Result<Invite> {
-        if let Ok(invite) = db.fetch_invite(code).await {
+        if let Some(invite) = db
+            .fetch_invite(code)
+            .await
+            .ok()
+            .filter(|invite| !invite.is_expired())
+        {
             return Ok(invite);
         } else if let Ok(server) = db.fetch_server(code).await {
             if server.discoverable {
@@ -62,6 +88,12 @@ impl Invite {
                         server: server.id,
                         creator: server.owner,
                         channel,
+                        expires_at: None,
+                        max_uses: None,
+                        uses: 0,
+                        temporary: false,
+                        roles: vec![],
+                        vanity: false,
                     });
                 }
             }
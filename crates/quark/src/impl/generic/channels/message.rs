@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use revolt_database::ContentFilter;
+use revolt_models::v0;
 use revolt_presence::filter_online;
 use serde_json::json;
 use ulid::Ulid;
@@ -9,12 +11,14 @@ use crate::{
     events::client::EventV1,
     models::{
         message::{
-            AppendMessage, BulkMessageResponse, DataMessageSend, Interactions, PartialMessage,
-            SendableEmbed, SystemMessage,
+            AppendMessage, BulkMessageResponse, DataMessageSend, Interactions, MessageFilter,
+            MessageQuery, MessageSort, MessageTimePeriod, PartialMessage, SendableEmbed,
+            SystemMessage,
         },
+        user::RelationshipStatus,
         Channel, Emoji, Message, User,
     },
-    permissions::PermissionCalculator,
+    permissions::{get_relationship, PermissionCalculator},
     tasks::ack::AckEvent,
     types::{
         january::{Embed, Text},
@@ -76,6 +80,11 @@ impl Message {
             {
                 let mut target_ids = vec![];
                 match &channel {
+                    // Message requests stay quiet until they are accepted
+                    Channel::DirectMessage {
+                        requested_by: Some(_),
+                        ..
+                    } => {}
                     Channel::DirectMessage { recipients, .. }
                     | Channel::Group { recipients, .. } => {
                         target_ids = (&recipients.iter().cloned().collect::<HashSet<String>>()
@@ -83,7 +92,7 @@ impl Message {
                             .into_iter()
                             .collect::<Vec<String>>();
                     }
-                    Channel::TextChannel { .. } => {
+                    Channel::TextChannel { .. } | Channel::ForumPost { .. } => {
                         if let Some(mentions) = &self.mentions {
                             target_ids.append(&mut mentions.clone());
                         }
@@ -108,6 +117,7 @@ impl Message {
             id: self.id.clone(),
             channel: self.channel.clone(),
             data: partial,
+            clear: vec![],
         }
         .p(self.channel.clone())
         .await;
@@ -137,16 +147,20 @@ impl Message {
 
     /// Delete a message
     pub async fn delete(self, db: &Database) -> Result<()> {
-        let file_ids: Vec<String> = self
-            .attachments
-            .map(|files| files.iter().map(|file| file.id.to_string()).collect())
-            .unwrap_or_default();
-
-        if !file_ids.is_empty() {
-            db.mark_attachments_as_deleted(&file_ids).await?;
+        // Forwards share their attachments with the original message
+        if self.forwarded.is_none() {
+            let file_ids: Vec<String> = self
+                .attachments
+                .map(|files| files.iter().map(|file| file.id.to_string()).collect())
+                .unwrap_or_default();
+
+            if !file_ids.is_empty() {
+                db.mark_attachments_as_deleted(&file_ids).await?;
+            }
         }
 
         db.delete_message(&self.id).await?;
+        Message::detach_forwards(db, &[self.id.clone()]).await?;
 
         EventV1::MessageDelete {
             id: self.id,
@@ -160,6 +174,8 @@ impl Message {
     /// Bulk delete messages
     pub async fn bulk_delete(db: &Database, channel: &str, ids: Vec<String>) -> Result<()> {
         db.delete_messages(channel, ids.clone()).await?;
+        Message::detach_forwards(db, &ids).await?;
+
         EventV1::BulkMessageDelete {
             channel: channel.to_string(),
             ids,
@@ -169,6 +185,101 @@ impl Message {
         Ok(())
     }
 
+    /// Bulk delete every message a user sent in the given channels after a message id
+    pub async fn bulk_delete_by_author(
+        db: &Database,
+        channels: &[String],
+        author: &str,
+        after: &str,
+    ) -> Result<()> {
+        for channel in channels {
+            loop {
+                let ids: Vec<String> = db
+                    .fetch_messages(MessageQuery {
+                        limit: Some(100),
+                        filter: MessageFilter {
+                            channel: Some(channel.to_string()),
+                            author: Some(author.to_string()),
+                            query: None,
+                        },
+                        time_period: MessageTimePeriod::Absolute {
+                            before: None,
+                            after: Some(after.to_string()),
+                            sort: Some(MessageSort::Oldest),
+                        },
+                    })
+                    .await?
+                    .into_iter()
+                    .map(|message| message.id)
+                    .collect();
+
+                let exhausted = ids.len() < 100;
+                if !ids.is_empty() {
+                    Message::bulk_delete(db, channel, ids).await?;
+                }
+
+                if exhausted {
+                    break;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Replace forwards of deleted messages with a placeholder
+    async fn detach_forwards(db: &Database, ids: &[String]) -> Result<()> {
+        let forwards = db.fetch_forwarded_messages(ids).await?;
+        Message::mark_detached(db, ids, forwards).await
+    }
+
+    /// Replace forwards of any message sent in the given channels with a placeholder
+    pub async fn detach_channel_forwards(db: &Database, channels: &[String]) -> Result<()> {
+        let forwards = db.fetch_forwards_from_channels(channels).await?;
+        let ids: Vec<String> = forwards
+            .iter()
+            .filter_map(|message| message.forwarded.as_ref())
+            .map(|forwarded| forwarded.id.clone())
+            .collect::<HashSet<String>>()
+            .into_iter()
+            .collect();
+
+        Message::mark_detached(db, &ids, forwards).await
+    }
+
+    /// Mark the given forwards of deleted messages as detached and notify clients
+    async fn mark_detached(db: &Database, ids: &[String], forwards: Vec<Message>) -> Result<()> {
+        if forwards.is_empty() {
+            return Ok(());
+        }
+
+        db.mark_forwards_as_deleted(ids).await?;
+
+        for message in forwards {
+            if let Some(mut forwarded) = message.forwarded {
+                forwarded.deleted = true;
+
+                EventV1::MessageUpdate {
+                    id: message.id,
+                    channel: message.channel.clone(),
+                    data: PartialMessage {
+                        forwarded: Some(forwarded),
+                        ..Default::default()
+                    },
+                    clear: vec![
+                        v0::FieldsMessage::Content,
+                        v0::FieldsMessage::Attachments,
+                        v0::FieldsMessage::Embeds,
+                    ],
+                }
+                .p(message.channel)
+                .await;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate the sum of content of a message is under threshold
     pub fn validate_sum(content: &Option<String>, embeds: &[SendableEmbed]) -> Result<()> {
         let mut running_total = 0;
@@ -270,6 +381,34 @@ impl Message {
     pub fn is_webhook(&self) -> bool {
         self.webhook.is_some()
     }
+
+    /// Apply a user's content filter to this message from their perspective
+    ///
+    /// Returns false if the message should be hidden entirely.
+    pub fn apply_content_filter(&mut self, filter: &ContentFilter, perspective: &User) -> bool {
+        if self.author == perspective.id {
+            return true;
+        }
+
+        if self
+            .content
+            .as_deref()
+            .is_some_and(|content| filter.hides_content(content))
+        {
+            return false;
+        }
+
+        let is_friend = matches!(
+            get_relationship(perspective, &self.author),
+            RelationshipStatus::Friend
+        );
+
+        if filter.hides_attachments_from(is_friend) {
+            self.attachments = None;
+        }
+
+        true
+    }
 }
 
 pub trait IntoUsers {
@@ -390,7 +529,8 @@ impl BulkMessageResponse {
 
             Ok(match channel {
                 Some(Channel::TextChannel { server, .. })
-                | Some(Channel::VoiceChannel { server, .. }) => {
+                | Some(Channel::VoiceChannel { server, .. })
+                | Some(Channel::ForumPost { server, .. }) => {
                     BulkMessageResponse::MessagesAndUsers {
                         messages,
                         users,
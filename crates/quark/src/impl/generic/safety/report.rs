@@ -1,14 +1,25 @@
 use iso8601_timestamp::Timestamp;
+use ulid::Ulid;
 
 use crate::{
-    models::report::PartialReport,
+    events::client::EventV1,
+    models::report::{FieldsReport, PartialReport, ReportComment},
     models::{report::ReportStatus, Report},
     Database, Result,
 };
 
 impl Report {
     /// Update report data
-    pub async fn update(&mut self, db: &Database, partial: PartialReport) -> Result<()> {
+    pub async fn update(
+        &mut self,
+        db: &Database,
+        partial: PartialReport,
+        remove: Vec<FieldsReport>,
+    ) -> Result<()> {
+        for field in &remove {
+            self.remove_field(field);
+        }
+
         self.apply_options(partial.clone());
 
         match &mut self.status {
@@ -25,6 +36,109 @@ impl Report {
             }
         }
 
-        db.update_report(&self.id, &partial).await
+        db.update_report(&self.id, &partial, remove.clone()).await?;
+
+        EventV1::ReportUpdate {
+            id: self.id.clone(),
+            data: partial,
+            clear: remove,
+        }
+        .global()
+        .await;
+
+        Ok(())
+    }
+
+    /// Leave a comment on this report
+    pub async fn add_comment(
+        &mut self,
+        db: &Database,
+        author_id: String,
+        content: String,
+    ) -> Result<ReportComment> {
+        let comment = ReportComment {
+            id: Ulid::new().to_string(),
+            author_id,
+            content,
+        };
+
+        db.add_report_comment(&self.id, &comment).await?;
+        self.comments.push(comment.clone());
+
+        EventV1::ReportUpdate {
+            id: self.id.clone(),
+            data: PartialReport {
+                comments: Some(self.comments.clone()),
+                ..Default::default()
+            },
+            clear: vec![],
+        }
+        .global()
+        .await;
+
+        Ok(comment)
+    }
+
+    /// Close this report, fails if someone else has already closed it
+    pub async fn close(&mut self, db: &Database, status: ReportStatus) -> Result<()> {
+        self.claim(db, status).await?;
+        self.announce_close().await;
+        Ok(())
+    }
+
+    /// Close this report without telling anyone yet, fails if someone else has already closed it
+    ///
+    /// Follow up with either [`Report::announce_close`] or [`Report::release`].
+    pub async fn claim(&mut self, db: &Database, mut status: ReportStatus) -> Result<()> {
+        match &mut status {
+            ReportStatus::Created {} => {}
+            ReportStatus::Rejected { closed_at, .. } | ReportStatus::Resolved { closed_at } => {
+                closed_at.replace(Timestamp::now_utc());
+            }
+        }
+
+        db.close_report(&self.id, &status).await?;
+        self.status = status;
+        Ok(())
+    }
+
+    /// Let moderators and the reporter know this report has been closed
+    pub async fn announce_close(&self) {
+        EventV1::ReportUpdate {
+            id: self.id.clone(),
+            data: PartialReport {
+                status: Some(self.status.clone()),
+                ..Default::default()
+            },
+            clear: vec![],
+        }
+        .global()
+        .await;
+
+        EventV1::ReportClose {
+            id: self.id.clone(),
+            status: self.status.clone(),
+        }
+        .private(self.author_id.clone())
+        .await;
+    }
+
+    /// Put a claimed report back into the queue before its closure was announced
+    pub async fn release(&mut self, db: &Database) -> Result<()> {
+        let partial = PartialReport {
+            status: Some(ReportStatus::Created {}),
+            ..Default::default()
+        };
+
+        db.update_report(&self.id, &partial, vec![]).await?;
+        self.apply_options(partial);
+        Ok(())
+    }
+
+    /// Remove a field from this object
+    pub fn remove_field(&mut self, field: &FieldsReport) {
+        match field {
+            FieldsReport::Assignee => self.assignee_id = None,
+        }
     }
 }
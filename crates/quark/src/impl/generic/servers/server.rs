@@ -1,16 +1,19 @@
 use std::collections::HashSet;
 
+use iso8601_timestamp::Timestamp;
 use ulid::Ulid;
 
 use crate::{
     events::client::EventV1,
     models::{
+        channel::{FieldsChannel, PartialChannel},
         message::SystemMessage,
         server::{
-            FieldsRole, FieldsServer, PartialRole, PartialServer, Role, SystemMessageChannels,
+            Category, FieldsRole, FieldsServer, PartialRole, PartialServer, Role,
+            SystemMessageChannels,
         },
-        server_member::{MemberCompositeKey, RemovalIntention},
-        Channel, Member, Server, ServerBan, User,
+        server_member::{MemberCompositeKey, PartialMember, RemovalIntention},
+        Channel, Member, Message, Server, ServerBan, User,
     },
     perms, Database, Error, OverrideField, Permission, Result,
 };
@@ -22,7 +25,10 @@ impl Role {
             name: Some(self.name),
             permissions: Some(self.permissions),
             colour: self.colour,
+            icon: self.icon,
+            emoji: self.emoji,
             hoist: Some(self.hoist),
+            mentionable: Some(self.mentionable),
             rank: Some(self.rank),
         }
     }
@@ -66,7 +72,7 @@ impl Role {
             id: server_id.to_string(),
             role_id: role_id.to_string(),
             data: partial,
-            clear: vec![],
+            clear: remove,
         }
         .p(server_id.to_string())
         .await;
@@ -90,6 +96,8 @@ impl Role {
     pub fn remove(&mut self, field: &FieldsRole) {
         match field {
             FieldsRole::Colour => self.colour = None,
+            FieldsRole::Icon => self.icon = None,
+            FieldsRole::Emoji => self.emoji = None,
         }
     }
 }
@@ -134,7 +142,8 @@ impl Server {
         .p(self.id.clone())
         .await;
 
-        db.delete_server(&self).await
+        db.delete_server(&self).await?;
+        Message::detach_channel_forwards(db, &self.channels).await
     }
 
     /// Remove a field from Server
@@ -145,7 +154,59 @@ impl Server {
             FieldsServer::SystemMessages => self.system_messages = None,
             FieldsServer::Icon => self.icon = None,
             FieldsServer::Banner => self.banner = None,
+            FieldsServer::PendingOwner => self.pending_owner = None,
+        }
+    }
+
+    /// Find the category whose permissions a channel uses in place of its own
+    pub fn synced_category(&self, channel_id: &str) -> Option<&Category> {
+        self.categories
+            .as_ref()?
+            .iter()
+            .find(|category| category.synced.iter().any(|id| id == channel_id))
+    }
+
+    /// Stop a channel from using its category's permissions
+    ///
+    /// The category's overrides are copied onto the channel first so that
+    /// nobody gains or loses access as a result.
+    pub async fn desync_channel(&mut self, db: &Database, channel: &mut Channel) -> Result<()> {
+        let category = if let Some(category) = self.synced_category(channel.id()) {
+            category.clone()
+        } else {
+            return Ok(());
+        };
+
+        channel
+            .update(
+                db,
+                PartialChannel {
+                    default_permissions: category.default_permissions,
+                    role_permissions: Some(category.role_permissions),
+                    ..Default::default()
+                },
+                if category.default_permissions.is_none() {
+                    vec![FieldsChannel::DefaultPermissions]
+                } else {
+                    vec![]
+                },
+            )
+            .await?;
+
+        let mut categories = self.categories.clone().unwrap_or_default();
+        for category in &mut categories {
+            category.synced.retain(|id| id != channel.id());
         }
+
+        self.update(
+            db,
+            PartialServer {
+                categories: Some(categories),
+                ..Default::default()
+            },
+            vec![],
+        )
+        .await
     }
 
     /// Set role permission on a server
@@ -175,17 +236,27 @@ impl Server {
     }
 
     /// Create a new member in a server
+    ///
+    /// Any initial member data, such as screening state or roles
+    /// given by an invite, is applied before the member is inserted.
     pub async fn create_member(
         &self,
         db: &Database,
         user: User,
         channels: Option<Vec<Channel>>,
+        partial: PartialMember,
     ) -> Result<Vec<Channel>> {
-        if db.fetch_ban(&self.id, &user.id).await.is_ok() {
+        if db
+            .fetch_ban(&self.id, &user.id)
+            .await
+            .is_ok_and(|ban| !ban.is_expired())
+        {
             return Err(Error::Banned);
         }
 
-        let member = Member::new(self.id.clone(), user.id.clone());
+        let mut member = Member::new(self.id.clone(), user.id.clone());
+        member.apply_options(partial);
+
         db.insert_member(&member).await?;
 
         let should_fetch = channels.is_none();
@@ -282,8 +353,13 @@ impl Server {
         db: &Database,
         id: MemberCompositeKey,
         reason: Option<String>,
+        expires: Option<Timestamp>,
     ) -> Result<ServerBan> {
-        let ban = ServerBan { id, reason };
+        let ban = ServerBan {
+            id,
+            reason,
+            expires,
+        };
         db.insert_ban(&ban).await?;
         Ok(ban)
     }
@@ -294,11 +370,12 @@ impl Server {
         db: &Database,
         member: Member,
         reason: Option<String>,
+        expires: Option<Timestamp>,
     ) -> Result<ServerBan> {
         self.remove_member(db, member.clone(), RemovalIntention::Ban, false)
             .await?;
 
-        self.ban_user(db, member.id, reason).await
+        self.ban_user(db, member.id, reason, expires).await
     }
 }
 
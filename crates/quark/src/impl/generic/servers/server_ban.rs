@@ -1 +1,14 @@
+use iso8601_timestamp::Timestamp;
 
+use crate::models::ServerBan;
+
+impl ServerBan {
+    /// Check whether this ban has run its course
+    pub fn is_expired(&self) -> bool {
+        if let Some(expires) = self.expires {
+            *expires <= *Timestamp::now_utc()
+        } else {
+            false
+        }
+    }
+}
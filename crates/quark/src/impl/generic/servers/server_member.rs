@@ -3,7 +3,7 @@ use iso8601_timestamp::Timestamp;
 use crate::{
     events::client::EventV1,
     models::{
-        server_member::{FieldsMember, MemberCompositeKey, PartialMember},
+        server_member::{FieldsMember, MemberCompositeKey, PartialMember, RemovalIntention},
         Member, Server,
     },
     Database, Result,
@@ -21,9 +21,26 @@ impl Member {
             avatar: None,
             roles: vec![],
             timeout: None,
+            pending: None,
+            temporary: false,
         }
     }
 
+    /// Remove a user from servers they joined through a temporary invite,
+    /// keeping any membership where they have since been given a role
+    pub async fn remove_temporary_memberships(db: &Database, user_id: &str) -> Result<()> {
+        for member in db.fetch_all_memberships(user_id).await? {
+            if member.temporary && member.roles.is_empty() {
+                let server = db.fetch_server(&member.id.server).await?;
+                server
+                    .remove_member(db, member, RemovalIntention::Kick, true)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Update member data
     pub async fn update<'a>(
         &mut self,
@@ -73,12 +90,18 @@ impl Member {
         }
     }
 
+    /// Check whether this member has yet to pass membership screening
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
     pub fn remove(&mut self, field: &FieldsMember) {
         match field {
             FieldsMember::Avatar => self.avatar = None,
             FieldsMember::Nickname => self.nickname = None,
             FieldsMember::Roles => self.roles.clear(),
             FieldsMember::Timeout => self.timeout = None,
+            FieldsMember::Pending => self.pending = None,
         }
     }
 }
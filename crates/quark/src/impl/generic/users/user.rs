@@ -1,6 +1,6 @@
 use crate::events::client::EventV1;
 use crate::models::user::{
-    Badges, FieldsUser, PartialUser, Presence, RelationshipStatus, User, UserHint,
+    Badges, FieldsUser, Flags, PartialUser, Presence, RelationshipStatus, User, UserHint,
 };
 use crate::permissions::defn::UserPerms;
 use crate::permissions::r#impl::user::get_relationship;
@@ -470,7 +470,7 @@ impl User {
     /// Find a user from a given token and hint
     #[async_recursion]
     pub async fn from_token(db: &Database, token: &str, hint: UserHint) -> Result<User> {
-        match hint {
+        let user = match hint {
             UserHint::Bot => {
                 let rvdb: revolt_database::Database = db.clone().into();
                 db.fetch_user(
@@ -484,12 +484,31 @@ impl User {
             }
             UserHint::User => db.fetch_user_by_token(token).await,
             UserHint::Any => {
-                if let Ok(user) = User::from_token(db, token, UserHint::User).await {
+                if let Ok(user) = db.fetch_user_by_token(token).await {
                     Ok(user)
                 } else {
                     User::from_token(db, token, UserHint::Bot).await
                 }
             }
+        }?;
+
+        user.throw_if_restricted()?;
+        Ok(user)
+    }
+
+    /// Throw an error if this account has been suspended or banned from the platform
+    pub fn throw_if_restricted(&self) -> Result<()> {
+        User::check_flags(self.flags.unwrap_or_default())
+    }
+
+    /// Check whether the given flags lock an account out of the platform
+    pub fn check_flags(flags: i32) -> Result<()> {
+        if flags & Flags::Banned as i32 != 0 {
+            Err(Error::AccountBanned)
+        } else if flags & Flags::Suspended as i32 != 0 {
+            Err(Error::AccountSuspended)
+        } else {
+            Ok(())
         }
     }
 }
@@ -100,9 +100,9 @@ impl AbstractChannel for MongoDb {
     async fn delete_channel(&self, channel: &Channel) -> Result<()> {
         let id = channel.id().to_string();
         let server_id = match channel {
-            Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => {
-                Some(server)
-            }
+            Channel::TextChannel { server, .. }
+            | Channel::VoiceChannel { server, .. }
+            | Channel::ForumChannel { server, .. } => Some(server),
             _ => None,
         };
 
@@ -312,6 +312,7 @@ impl IntoDocumentPath for FieldsChannel {
             FieldsChannel::DefaultPermissions => "default_permissions",
             FieldsChannel::Description => "description",
             FieldsChannel::Icon => "icon",
+            FieldsChannel::RequestedBy => "requested_by",
         })
     }
 }
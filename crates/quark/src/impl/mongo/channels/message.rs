@@ -275,6 +275,61 @@ impl AbstractMessage for MongoDb {
         }
     }
 
+    async fn fetch_forwarded_messages(&self, ids: &[String]) -> Result<Vec<Message>> {
+        self.find(
+            COL,
+            doc! {
+                "forwarded.id": {
+                    "$in": ids
+                }
+            },
+        )
+        .await
+    }
+
+    async fn fetch_forwards_from_channels(&self, channels: &[String]) -> Result<Vec<Message>> {
+        self.find(
+            COL,
+            doc! {
+                "forwarded.channel": {
+                    "$in": channels
+                },
+                "forwarded.deleted": {
+                    "$ne": true
+                }
+            },
+        )
+        .await
+    }
+
+    async fn mark_forwards_as_deleted(&self, ids: &[String]) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_many(
+                doc! {
+                    "forwarded.id": {
+                        "$in": ids
+                    }
+                },
+                doc! {
+                    "$set": {
+                        "forwarded.deleted": true
+                    },
+                    "$unset": {
+                        "content": 1_i32,
+                        "attachments": 1_i32,
+                        "embeds": 1_i32
+                    }
+                },
+                None,
+            )
+            .await
+            .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_many",
+                with: "messages",
+            })
+    }
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()> {
         self.col::<Document>(COL)
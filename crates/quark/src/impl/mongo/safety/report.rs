@@ -1,6 +1,12 @@
-use crate::models::report::PartialReport;
+use bson::{to_bson, Document};
+use mongodb::options::FindOptions;
+
+use crate::models::report::{
+    FieldsReport, PartialReport, ReportComment, ReportQuery, ReportStatus,
+};
 use crate::models::Report;
-use crate::{AbstractReport, Result};
+use crate::r#impl::mongo::IntoDocumentPath;
+use crate::{AbstractReport, Error, Result};
 
 use super::super::MongoDb;
 
@@ -12,17 +18,124 @@ impl AbstractReport for MongoDb {
         self.insert_one(COL, report).await.map(|_| ())
     }
 
-    async fn update_report(&self, id: &str, report: &PartialReport) -> Result<()> {
-        self.update_one_by_id(COL, id, report, vec![], None)
+    async fn update_report(
+        &self,
+        id: &str,
+        report: &PartialReport,
+        remove: Vec<FieldsReport>,
+    ) -> Result<()> {
+        self.update_one_by_id(
+            COL,
+            id,
+            report,
+            remove.iter().map(|x| x as &dyn IntoDocumentPath).collect(),
+            None,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    async fn add_report_comment(&self, id: &str, comment: &ReportComment) -> Result<()> {
+        self.col::<Document>(COL)
+            .update_one(
+                doc! {
+                    "_id": id
+                },
+                doc! {
+                    "$push": {
+                        "comments": to_bson(comment).map_err(|_| Error::DatabaseError {
+                            operation: "to_bson",
+                            with: "report_comment",
+                        })?
+                    }
+                },
+                None,
+            )
             .await
             .map(|_| ())
+            .map_err(|_| Error::DatabaseError {
+                operation: "update_one",
+                with: "report",
+            })
+    }
+
+    async fn close_report(&self, id: &str, status: &ReportStatus) -> Result<()> {
+        let result = self
+            .update_one(
+                COL,
+                doc! {
+                    "_id": id,
+                    "status": "Created"
+                },
+                status,
+                vec![],
+                None,
+            )
+            .await?;
+
+        if result.matched_count == 0 {
+            Err(Error::InvalidOperation)
+        } else {
+            Ok(())
+        }
     }
 
     async fn fetch_report(&self, report_id: &str) -> Result<Report> {
         self.find_one_by_id(COL, report_id).await
     }
 
-    async fn fetch_reports(&self) -> Result<Vec<Report>> {
-        self.find(COL, doc! {}).await
+    async fn fetch_reports(&self, query: ReportQuery) -> Result<Vec<Report>> {
+        let mut filter = doc! {};
+
+        if let Some(status) = query.status {
+            filter.insert(
+                "status",
+                to_bson(&status).map_err(|_| Error::DatabaseError {
+                    operation: "to_bson",
+                    with: "report_status",
+                })?,
+            );
+        }
+
+        if let Some(author_id) = query.author_id {
+            filter.insert("author_id", author_id);
+        }
+
+        if let Some(content_id) = query.content_id {
+            filter.insert("content.id", content_id);
+        }
+
+        if let Some(assignee_id) = query.assignee_id {
+            filter.insert("assignee_id", assignee_id);
+        }
+
+        if let Some(before) = query.before {
+            filter.insert(
+                "_id",
+                doc! {
+                    "$lt": before
+                },
+            );
+        }
+
+        self.find_with_options(
+            COL,
+            filter,
+            FindOptions::builder()
+                .limit(query.limit)
+                .sort(doc! {
+                    "_id": -1_i32
+                })
+                .build(),
+        )
+        .await
+    }
+}
+
+impl IntoDocumentPath for FieldsReport {
+    fn as_path(&self) -> Option<&'static str> {
+        Some(match self {
+            FieldsReport::Assignee => "assignee_id",
+        })
     }
 }
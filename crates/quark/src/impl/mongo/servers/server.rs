@@ -66,6 +66,48 @@ impl MongoDb {
                 })?;
         }
 
+        // Delete raid protection settings.
+        self.col::<Document>("server_raid_protection")
+            .delete_one(
+                doc! {
+                    "_id": &server.id
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_one",
+                with: "server_raid_protection",
+            })?;
+
+        // Delete membership screening settings.
+        self.col::<Document>("server_screening")
+            .delete_one(
+                doc! {
+                    "_id": &server.id
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_one",
+                with: "server_screening",
+            })?;
+
+        // Delete auto role rules.
+        self.col::<Document>("server_auto_roles")
+            .delete_many(
+                doc! {
+                    "server": &server.id
+                },
+                None,
+            )
+            .await
+            .map_err(|_| Error::DatabaseError {
+                operation: "delete_many",
+                with: "server_auto_roles",
+            })?;
+
         // Update many attachments with parent id.
         self.delete_many_attachments(doc! {
             "object_id": &server.id
@@ -228,6 +270,7 @@ impl IntoDocumentPath for FieldsServer {
             FieldsServer::Categories => "categories",
             FieldsServer::Description => "description",
             FieldsServer::Icon => "icon",
+            FieldsServer::PendingOwner => "pending_owner",
             FieldsServer::SystemMessages => "system_messages",
         })
     }
@@ -237,6 +280,8 @@ impl IntoDocumentPath for FieldsRole {
     fn as_path(&self) -> Option<&'static str> {
         Some(match self {
             FieldsRole::Colour => "colour",
+            FieldsRole::Icon => "icon",
+            FieldsRole::Emoji => "emoji",
         })
     }
 }
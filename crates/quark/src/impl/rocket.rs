@@ -33,8 +33,10 @@ impl<'r> FromRequest<'r> for User {
                     .map(|x| x.to_string());
 
                 if let Some(bot_token) = header_bot_token {
-                    if let Ok(user) = revolt_database::User::from_token(db, &bot_token).await {
-                        return Some(user);
+                    if let Ok(bot) = db.fetch_bot_by_token(&bot_token).await {
+                        if let Ok(user) = db.fetch_user(&bot.id).await {
+                            return Some(user);
+                        }
                     }
                 } else if let Outcome::Success(session) = request.guard::<Session>().await {
                     // This uses a guard so can't really easily be refactored into from_token at this stage.
@@ -48,6 +50,10 @@ impl<'r> FromRequest<'r> for User {
             .await;
 
         if let Some(user) = user {
+            if user.throw_if_restricted().is_err() {
+                return Outcome::Failure((Status::Forbidden, authifier::Error::InvalidSession));
+            }
+
             return Outcome::Success(old_db.fetch_user(&user.id).await.unwrap());
         }
         // else {
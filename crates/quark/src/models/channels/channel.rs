@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 
+use revolt_models::v0::{ForumSortOrder, ForumTag};
 use serde::{Deserialize, Serialize};
 
 use crate::{models::attachment::File, OverrideField};
@@ -9,6 +10,11 @@ pub fn if_false(t: &bool) -> bool {
     !t
 }
 
+/// Utility function to check if an u32 is zero
+pub fn if_zero_u32(t: &u32) -> bool {
+    t == &0
+}
+
 /// Representation of a channel on Revolt
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(tag = "channel_type")]
@@ -34,6 +40,9 @@ pub enum Channel {
         /// Id of the last message sent in this channel
         #[serde(skip_serializing_if = "Option::is_none")]
         last_message_id: Option<String>,
+        /// Id of the user who started this conversation, present while it is a message request
+        #[serde(skip_serializing_if = "Option::is_none")]
+        requested_by: Option<String>,
     },
     /// Group channel between 1 or more participants
     Group {
@@ -101,6 +110,14 @@ pub enum Channel {
         /// Whether this channel is marked as not safe for work
         #[serde(skip_serializing_if = "if_false", default)]
         nsfw: bool,
+
+        /// Seconds a member has to wait between sending messages
+        #[serde(skip_serializing_if = "if_zero_u32", default)]
+        slowmode_seconds: u32,
+
+        /// Whether messages in this channel can be published to followers
+        #[serde(skip_serializing_if = "if_false", default)]
+        announcement: bool,
     },
     /// Voice channel belonging to a server
     VoiceChannel {
@@ -133,6 +150,65 @@ pub enum Channel {
         #[serde(skip_serializing_if = "if_false", default)]
         nsfw: bool,
     },
+    /// Forum channel belonging to a server
+    ForumChannel {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        id: String,
+        /// Id of the server this channel belongs to
+        server: String,
+
+        /// Display name of the channel
+        name: String,
+        /// Channel description
+        #[serde(skip_serializing_if = "Option::is_none")]
+        description: Option<String>,
+        /// Custom icon attachment
+        #[serde(skip_serializing_if = "Option::is_none")]
+        icon: Option<File>,
+
+        /// Tags which can be applied to posts
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        available_tags: Vec<ForumTag>,
+        /// Order posts are listed in by default
+        #[serde(default)]
+        default_sort_order: ForumSortOrder,
+
+        /// Default permissions assigned to users in this channel
+        #[serde(skip_serializing_if = "Option::is_none")]
+        default_permissions: Option<OverrideField>,
+        /// Permissions assigned based on role to this channel
+        #[serde(
+            default = "HashMap::<String, OverrideField>::new",
+            skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+        )]
+        role_permissions: HashMap<String, OverrideField>,
+
+        /// Whether this channel is marked as not safe for work
+        #[serde(skip_serializing_if = "if_false", default)]
+        nsfw: bool,
+    },
+    /// Post within a forum channel
+    ForumPost {
+        /// Unique Id
+        #[serde(rename = "_id")]
+        id: String,
+        /// Id of the server this post belongs to
+        server: String,
+        /// Id of the forum channel this post belongs to
+        forum: String,
+        /// Id of the user who created this post
+        author: String,
+
+        /// Title of the post
+        name: String,
+        /// Ids of the forum tags applied to this post
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        tags: Vec<String>,
+        /// Id of the last message sent in this post
+        #[serde(skip_serializing_if = "Option::is_none")]
+        last_message_id: Option<String>,
+    },
 }
 
 /// Partial values of [Channel]
@@ -158,6 +234,16 @@ pub struct PartialChannel {
     pub default_permissions: Option<OverrideField>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub last_message_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slowmode_seconds: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub announcement: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub available_tags: Option<Vec<ForumTag>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default_sort_order: Option<ForumSortOrder>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
 }
 
 /// Optional fields on channel object
@@ -166,4 +252,5 @@ pub enum FieldsChannel {
     Description,
     Icon,
     DefaultPermissions,
+    RequestedBy,
 }
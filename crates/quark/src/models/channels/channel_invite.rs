@@ -1,5 +1,8 @@
+use iso8601_timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
+use crate::models::channel::{if_false, if_zero_u32};
+
 /// Representation of an invite to a channel on Revolt
 #[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
 #[serde(tag = "type")]
@@ -15,6 +18,25 @@ pub enum Invite {
         creator: String,
         /// Id of the server channel this invite points to
         channel: String,
+        /// Time at which this invite stops working
+        #[serde(skip_serializing_if = "Option::is_none")]
+        expires_at: Option<Timestamp>,
+        /// Maximum number of times this invite may be used
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_uses: Option<u32>,
+        /// Number of times this invite has been used
+        #[serde(skip_serializing_if = "if_zero_u32", default)]
+        uses: u32,
+        /// Whether members who join through this invite are removed
+        /// once they go offline, unless they have since been given a role
+        #[serde(skip_serializing_if = "if_false", default)]
+        temporary: bool,
+        /// Roles given to members who join through this invite
+        #[serde(skip_serializing_if = "Vec::is_empty", default)]
+        roles: Vec<String>,
+        /// Whether this is the server's custom vanity invite
+        #[serde(skip_serializing_if = "if_false", default)]
+        vanity: bool,
     },
     /// Invite to a group channel
     Group {
@@ -25,6 +47,15 @@ pub enum Invite {
         creator: String,
         /// Id of the group channel this invite points to
         channel: String,
+        /// Time at which this invite stops working
+        #[serde(skip_serializing_if = "Option::is_none")]
+        expires_at: Option<Timestamp>,
+        /// Maximum number of times this invite may be used
+        #[serde(skip_serializing_if = "Option::is_none")]
+        max_uses: Option<u32>,
+        /// Number of times this invite has been used
+        #[serde(skip_serializing_if = "if_zero_u32", default)]
+        uses: u32,
     }, /* User {
            code: String,
            user: String
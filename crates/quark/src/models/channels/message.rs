@@ -4,7 +4,7 @@ use indexmap::{IndexMap, IndexSet};
 use iso8601_timestamp::Timestamp;
 use once_cell::sync::Lazy;
 use regex::Regex;
-use revolt_models::v0::{Component, MessageWebhook};
+use revolt_models::v0::{Component, MessageForward, MessageWebhook};
 use serde::{Deserialize, Serialize};
 use validator::Validate;
 
@@ -137,6 +137,18 @@ pub struct Message {
     /// Session ID of the message which created by bot's developer
     #[serde(skip_serializing_if = "Option::is_none")]
     pub session_id: Option<String>,
+    /// Id of the only user this message is visible to
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ephemeral: Option<String>,
+    /// Original message this message was forwarded from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub forwarded: Option<MessageForward>,
+    /// Whether this message is pinned in its channel
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub pinned: bool,
+    /// Whether this message has been published to channel followers
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub published: bool,
     /// The webhook that sent this message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub webhook: Option<MessageWebhook>,
@@ -158,6 +170,9 @@ pub struct Message {
     /// Array of user ids mentioned in this message
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mentions: Option<Vec<String>>,
+    /// Array of role ids mentioned in this message
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_mentions: Option<Vec<String>>,
     /// Array of message ids this message is replying to
     #[serde(skip_serializing_if = "Option::is_none")]
     pub replies: Option<Vec<String>>,
@@ -332,6 +347,10 @@ mod tests {
             author: "01H6ZWPCCKQ4J46D088HBY5ZP4".into(),
             session_id: None,
             is_stream: None,
+            ephemeral: None,
+            forwarded: None,
+            pinned: false,
+            published: false,
             components: Some(vec![
                 Component::Button {
                     label: "继续".into(),
@@ -355,6 +374,7 @@ mod tests {
             edited: None,
             embeds: None,
             mentions: None,
+            role_mentions: None,
             replies: None,
             interactions: Interactions {
                 reactions: None,
@@ -133,11 +133,24 @@ pub enum ReportStatusString {
     Resolved,
 }
 
+/// Comment left on a report by a moderator
+#[derive(Serialize, Deserialize, JsonSchema, Debug, Clone)]
+pub struct ReportComment {
+    /// Unique Id
+    #[serde(rename = "_id")]
+    pub id: String,
+    /// Id of the moderator who left this comment
+    pub author_id: String,
+    /// Comment content
+    pub content: String,
+}
+
 /// User-generated platform moderation report.
 #[derive(Serialize, Deserialize, JsonSchema, Debug, OptionalStruct, Clone)]
 #[optional_derive(Serialize, Deserialize, JsonSchema, Debug, Default, Clone)]
 #[optional_name = "PartialReport"]
 #[opt_skip_serializing_none]
+#[opt_some_priority]
 pub struct Report {
     /// Unique Id
     #[serde(rename = "_id")]
@@ -155,4 +168,33 @@ pub struct Report {
     /// Additional notes included on the report
     #[serde(default)]
     pub notes: String,
+    /// Id of the moderator handling this report
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assignee_id: Option<String>,
+    /// Discussion between moderators handling this report
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub comments: Vec<ReportComment>,
+}
+
+/// Optional fields on report object
+#[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
+pub enum FieldsReport {
+    Assignee,
+}
+
+/// Filter used when fetching reports
+#[derive(Default)]
+pub struct ReportQuery {
+    /// Only include reports with this status
+    pub status: Option<ReportStatusString>,
+    /// Only include reports created by this user
+    pub author_id: Option<String>,
+    /// Only include reports against this piece of content
+    pub content_id: Option<String>,
+    /// Only include reports assigned to this moderator
+    pub assignee_id: Option<String>,
+    /// Report id before which reports should be fetched
+    pub before: Option<String>,
+    /// Maximum number of reports to fetch
+    pub limit: i64,
 }
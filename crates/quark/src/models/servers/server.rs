@@ -27,9 +27,18 @@ pub struct Role {
     /// This can be any valid CSS colour
     #[serde(skip_serializing_if = "Option::is_none")]
     pub colour: Option<String>,
+    /// Icon shown next to members with this role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<File>,
+    /// Unicode emoji shown next to members with this role
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<String>,
     /// Whether this role should be shown separately on the member sidebar
     #[serde(skip_serializing_if = "if_false", default)]
     pub hoist: bool,
+    /// Whether anyone can mention this role
+    #[serde(skip_serializing_if = "if_false", default)]
+    pub mentionable: bool,
     /// Ranking of this role
     #[serde(default)]
     pub rank: i64,
@@ -46,6 +55,19 @@ pub struct Category {
     pub title: String,
     /// Channels in this category
     pub channels: Vec<String>,
+
+    /// Default permissions assigned to users in this category
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub default_permissions: Option<OverrideField>,
+    /// Permissions assigned based on role to this category
+    #[serde(
+        default = "HashMap::<String, OverrideField>::new",
+        skip_serializing_if = "HashMap::<String, OverrideField>::is_empty"
+    )]
+    pub role_permissions: HashMap<String, OverrideField>,
+    /// Channels which use this category's permissions in place of their own
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub synced: Vec<String>,
 }
 
 /// System message channel assignments
@@ -131,6 +153,10 @@ pub struct Server {
     /// Whether this server should be publicly discoverable
     #[serde(skip_serializing_if = "if_false", default)]
     pub discoverable: bool,
+
+    /// Id of the member an ownership transfer is awaiting acceptance from
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_owner: Option<String>,
 }
 
 /// Optional fields on server object
@@ -141,10 +167,13 @@ pub enum FieldsServer {
     SystemMessages,
     Icon,
     Banner,
+    PendingOwner,
 }
 
 /// Optional fields on server object
 #[derive(Serialize, Deserialize, JsonSchema, Debug, PartialEq, Eq, Clone)]
 pub enum FieldsRole {
     Colour,
+    Icon,
+    Emoji,
 }
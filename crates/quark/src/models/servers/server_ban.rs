@@ -1,3 +1,4 @@
+use iso8601_timestamp::Timestamp;
 use serde::{Deserialize, Serialize};
 
 use super::server_member::MemberCompositeKey;
@@ -10,4 +11,7 @@ pub struct ServerBan {
     pub id: MemberCompositeKey,
     /// Reason for ban creation
     pub reason: Option<String>,
+    /// Time at which this ban is lifted
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires: Option<Timestamp>,
 }
@@ -1,4 +1,5 @@
 use iso8601_timestamp::Timestamp;
+use revolt_models::v0::MemberScreening;
 use serde::{Deserialize, Serialize};
 
 use crate::models::attachment::File;
@@ -39,6 +40,12 @@ pub struct Member {
     /// Timestamp this member is timed out until
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timeout: Option<Timestamp>,
+    /// Screening this member has yet to pass before they may participate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending: Option<MemberScreening>,
+    /// Whether this member is removed once they go offline, unless given a role
+    #[serde(skip_serializing_if = "crate::models::user::if_false", default)]
+    pub temporary: bool,
 }
 
 /// Optional fields on server member object
@@ -48,6 +55,7 @@ pub enum FieldsMember {
     Avatar,
     Roles,
     Timeout,
+    Pending,
 }
 
 /// Member removal intention
@@ -24,8 +24,8 @@ pub enum Permission {
     ManageRole = 1 << 3,
     /// Manage server customisation (includes emoji)
     ManageCustomisation = 1 << 4,
-
-    // % 1 bit reserved
+    /// View the server audit log
+    ViewAuditLog = 1 << 5,
 
     // * Member permissions
     /// Kick other members below their ranking
@@ -123,6 +123,7 @@ bitfield! {
     pub can_manage_permissions, _: 61;
     pub can_manage_roles, _: 60;
     pub can_manage_customisation, _: 59;
+    pub can_view_audit_log, _: 58;
 
     // * Member permissions
     pub can_kick_members, _: 57;
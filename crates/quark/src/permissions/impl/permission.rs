@@ -76,8 +76,8 @@ async fn calculate_server_permission(
         }
     }
 
-    // 5. Revoke permissions if member is timed out.
-    if member.in_timeout() {
+    // 5. Revoke permissions if member is timed out or has yet to pass screening.
+    if member.in_timeout() || member.is_pending() {
         permissions.restrict(*ALLOW_IN_TIMEOUT);
     }
 
@@ -89,10 +89,19 @@ async fn calculate_channel_permission(
     data: &mut PermissionCalculator<'_>,
     db: &crate::Database,
 ) -> Result<PermissionValue> {
+    // Forum posts inherit the permissions of their forum.
+    if let Some(Channel::ForumPost { forum, .. }) = data.channel.get() {
+        let forum = db.fetch_channel(forum).await?;
+        data.channel.set(forum);
+    }
+
     // Pre-calculate server permissions if applicable.
     // We do this to satisfy the borrow checker.
     let server_id = match data.channel.get().unwrap() {
-        Channel::TextChannel { server, .. } | Channel::VoiceChannel { server, .. } => Some(server),
+        Channel::TextChannel { server, .. }
+        | Channel::VoiceChannel { server, .. }
+        | Channel::ForumChannel { server, .. }
+        | Channel::ForumPost { server, .. } => Some(server),
         _ => None,
     };
 
@@ -166,11 +175,19 @@ async fn calculate_channel_permission(
             }
         }
         Channel::TextChannel {
+            id,
             default_permissions,
             role_permissions,
             ..
         }
         | Channel::VoiceChannel {
+            id,
+            default_permissions,
+            role_permissions,
+            ..
+        }
+        | Channel::ForumChannel {
+            id,
             default_permissions,
             role_permissions,
             ..
@@ -188,6 +205,13 @@ async fn calculate_channel_permission(
                     return Ok((Permission::GrantAllSafe as u64).into());
                 }
 
+                // Synced channels take their overrides from their category.
+                let (default_permissions, role_permissions) = server
+                    .synced_category(id)
+                    .map_or((default_permissions, role_permissions), |category| {
+                        (&category.default_permissions, &category.role_permissions)
+                    });
+
                 // 3. Apply default allows and denies for channel.
                 if let Some(default) = default_permissions {
                     permissions.apply((*default).into());
@@ -216,8 +240,8 @@ async fn calculate_channel_permission(
                     }
                 }
 
-                // 5. Revoke permissions if member is timed out.
-                if member.in_timeout() {
+                // 5. Revoke permissions if member is timed out or has yet to pass screening.
+                if member.in_timeout() || member.is_pending() {
                     permissions.restrict(*ALLOW_IN_TIMEOUT);
                 }
 
@@ -226,6 +250,8 @@ async fn calculate_channel_permission(
                 (Permission::GrantAllSafe as u64).into()
             }
         }
+        // Posts were resolved to their forum above.
+        Channel::ForumPost { .. } => 0_u64.into(),
     };
 
     Ok(value)
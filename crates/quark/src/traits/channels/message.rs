@@ -24,6 +24,15 @@ pub trait AbstractMessage: Sync + Send {
     /// Fetch multiple messages by given query
     async fn fetch_messages(&self, query: MessageQuery) -> Result<Vec<Message>>;
 
+    /// Fetch all messages forwarded from any of the given messages
+    async fn fetch_forwarded_messages(&self, ids: &[String]) -> Result<Vec<Message>>;
+
+    /// Fetch all forwards of messages originally sent in any of the given channels
+    async fn fetch_forwards_from_channels(&self, channels: &[String]) -> Result<Vec<Message>>;
+
+    /// Strip content from forwards of the given messages and mark their original as deleted
+    async fn mark_forwards_as_deleted(&self, ids: &[String]) -> Result<()>;
+
     /// Add a new reaction to a message
     async fn add_reaction(&self, id: &str, emoji: &str, user: &str) -> Result<()>;
 
@@ -1,4 +1,6 @@
-use crate::models::report::PartialReport;
+use crate::models::report::{
+    FieldsReport, PartialReport, ReportComment, ReportQuery, ReportStatus,
+};
 use crate::models::Report;
 use crate::Result;
 
@@ -8,11 +10,22 @@ pub trait AbstractReport: Sync + Send {
     async fn insert_report(&self, report: &Report) -> Result<()>;
 
     /// Update a given report with new information
-    async fn update_report(&self, id: &str, message: &PartialReport) -> Result<()>;
+    async fn update_report(
+        &self,
+        id: &str,
+        report: &PartialReport,
+        remove: Vec<FieldsReport>,
+    ) -> Result<()>;
+
+    /// Append a moderator comment to a report
+    async fn add_report_comment(&self, id: &str, comment: &ReportComment) -> Result<()>;
+
+    /// Close a report, failing if it has already been closed
+    async fn close_report(&self, id: &str, status: &ReportStatus) -> Result<()>;
 
     /// Fetch report
     async fn fetch_report(&self, report_id: &str) -> Result<Report>;
 
-    /// Fetch reports
-    async fn fetch_reports(&self) -> Result<Vec<Report>>;
+    /// Fetch reports matching the given query, newest first
+    async fn fetch_reports(&self, query: ReportQuery) -> Result<Vec<Report>>;
 }
@@ -70,6 +70,13 @@ pub enum Error {
     UnknownServer,
     InvalidRole,
     Banned,
+    AlreadyInServer,
+    InTimeout {
+        until: String,
+    },
+    ServerLockedDown,
+    AccountTooNew,
+    VerifiedEmailRequired,
     TooManyServers {
         max: usize,
     },
@@ -87,6 +94,8 @@ pub enum Error {
 
     // ? User safety related errors
     CannotReportYourself,
+    AccountSuspended,
+    AccountBanned,
 
     // ? Permission errors
     MissingPermission {
@@ -157,10 +166,10 @@ pub type Result<T, E = Error> = std::result::Result<T, E>;
 
 /// HTTP response builder for Error enum
 impl<'r> Responder<'r, 'static> for Error {
-    fn respond_to(self, _: &'r Request<'_>) -> response::Result<'static> {
+    fn respond_to(self, req: &'r Request<'_>) -> response::Result<'static> {
         let status = match self {
             Error::LabelMe => Status::InternalServerError,
-            Error::Core { .. } => Status::InternalServerError,
+            Error::Core { error } => return error.respond_to(req),
 
             Error::AlreadyOnboarded => Status::Forbidden,
 
@@ -191,6 +200,11 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::UnknownServer => Status::NotFound,
             Error::InvalidRole => Status::NotFound,
             Error::Banned => Status::Forbidden,
+            Error::AlreadyInServer => Status::Conflict,
+            Error::InTimeout { .. } => Status::Forbidden,
+            Error::ServerLockedDown => Status::Forbidden,
+            Error::AccountTooNew => Status::Forbidden,
+            Error::VerifiedEmailRequired => Status::Forbidden,
 
             Error::TooManyServers { .. } => Status::BadRequest,
             Error::TooManyEmoji { .. } => Status::BadRequest,
@@ -203,6 +217,8 @@ impl<'r> Responder<'r, 'static> for Error {
             Error::BotIsPrivate => Status::Forbidden,
 
             Error::CannotReportYourself => Status::BadRequest,
+            Error::AccountSuspended => Status::Forbidden,
+            Error::AccountBanned => Status::Forbidden,
 
             Error::MissingPermission { .. } => Status::Forbidden,
             Error::MissingUserPermission { .. } => Status::Forbidden,